@@ -410,6 +410,10 @@ export class Compiler {
     const wasmSource = fs.readFileSync(modulePath);
     const wrapImports: Record<keyof WrapImports, () => void> = {
       __wrap_subinvoke: () => {},
+      __wrap_subinvoke_with_options: () => {},
+      __wrap_subinvoke_batch: () => {},
+      __wrap_subinvoke_batch_result_len: () => {},
+      __wrap_subinvoke_batch_result: () => {},
       __wrap_subinvoke_result_len: () => {},
       __wrap_subinvoke_result: () => {},
       __wrap_subinvoke_error_len: () => {},
@@ -422,10 +426,13 @@ export class Compiler {
       __wrap_invoke_args: () => {},
       __wrap_invoke_result: () => {},
       __wrap_invoke_error: () => {},
+      __wrap_invoke_caller_len: () => {},
+      __wrap_invoke_caller: () => {},
       __wrap_getImplementations: () => {},
       __wrap_getImplementations_result_len: () => {},
       __wrap_getImplementations_result: () => {},
       __wrap_abort: () => {},
+      __wrap_abort_report: () => {},
       __wrap_debug_log: () => {},
       __wrap_load_env: () => {},
     };
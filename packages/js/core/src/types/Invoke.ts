@@ -26,6 +26,11 @@ export interface InvokeOptions<
    * Invoke id used to track query context data set internally.
    */
   contextId?: string;
+
+  /**
+   * URI of the wrapper making this invocation, if it's a subinvocation.
+   */
+  caller?: string;
 }
 
 /**
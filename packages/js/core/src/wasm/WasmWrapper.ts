@@ -45,9 +45,13 @@ export interface State {
     error?: string;
     args: unknown[];
   };
+  subinvokeBatch: {
+    result?: Uint8Array;
+  };
   invokeResult: InvokeResult;
   getImplementationsResult?: Uint8Array;
   env: Uint8Array;
+  caller?: string;
 }
 
 export class WasmWrapper extends Wrapper {
@@ -161,10 +165,12 @@ export class WasmWrapper extends Wrapper {
         subinvokeImplementation: {
           args: [],
         },
+        subinvokeBatch: {},
         invokeResult: {} as InvokeResult,
         method,
         args: isBuffer(args) ? args : msgpackEncode(args),
         env: msgpackEncode(this._getClientEnv()),
+        caller: options.caller,
       };
 
       const abort = (message: string) => {
@@ -179,6 +185,7 @@ export class WasmWrapper extends Wrapper {
       const instance = await AsyncWasmInstance.createInstance({
         module: wasm,
         imports: createImports({
+          uri: this._uri.uri,
           state,
           client,
           memory,
@@ -2,18 +2,95 @@
 
 import { u32, WrapImports } from "./types";
 import { readBytes, readString, writeBytes, writeString } from "./buffer";
-import { Client } from "../.";
+import { Client, ClientConfig, InvokeResult, Uri } from "../.";
 import { State } from "./WasmWrapper";
 
-import { msgpackEncode } from "@polywrap/msgpack-js";
+import { msgpackDecode, msgpackEncode } from "@polywrap/msgpack-js";
+
+interface AbortReport {
+  message: string;
+  source: string[];
+  contextDescription?: string;
+  context: string[];
+  method?: string;
+  code?: u32;
+  file?: string;
+  line: u32;
+  column: u32;
+}
+
+interface SubinvokeOptions {
+  env?: Uint8Array | null;
+}
+
+interface SubinvokeRequest {
+  uri: string;
+  method: string;
+  args: Uint8Array;
+  options: SubinvokeOptions;
+}
 
 export const createImports = (config: {
+  uri: string;
   client: Client;
   memory: WebAssembly.Memory;
   state: State;
   abort: (message: string) => never;
 }): WrapImports => {
-  const { memory, state, client, abort } = config;
+  const { uri: wrapperUri, memory, state, client, abort } = config;
+
+  // Replace the env the client has for `uri`, for one invocation
+  const envConfig = (
+    uri: string,
+    env: Uint8Array
+  ): Partial<ClientConfig> => {
+    const target = new Uri(uri);
+    return {
+      envs: [
+        ...client
+          .getEnvs()
+          .filter((other) => !Uri.equals(other.uri, target))
+          .map((other) => ({ uri: other.uri.uri, env: other.env })),
+        { uri: target.uri, env: msgpackDecode(env) as Record<string, unknown> },
+      ],
+    };
+  };
+
+  const invoke = (
+    uri: string,
+    method: string,
+    args: ArrayBuffer,
+    options: SubinvokeOptions = {}
+  ): Promise<InvokeResult<Uint8Array>> =>
+    client.invoke<Uint8Array>({
+      uri: uri,
+      method: method,
+      args: new Uint8Array(args),
+      encodeResult: true,
+      caller: wrapperUri,
+      config: options.env ? envConfig(uri, options.env) : undefined,
+    });
+
+  const subinvoke = async (
+    uri: string,
+    method: string,
+    args: ArrayBuffer,
+    options?: SubinvokeOptions
+  ): Promise<boolean> => {
+    // Reset our state
+    state.subinvoke.result = undefined;
+    state.subinvoke.error = undefined;
+
+    const { data, error } = await invoke(uri, method, args, options);
+
+    if (!error) {
+      state.subinvoke.result = data;
+    } else {
+      state.subinvoke.error = `${error.name}: ${error.message}`;
+    }
+
+    return !error;
+  };
 
   return {
     wrap: {
@@ -25,28 +102,70 @@ export const createImports = (config: {
         argsPtr: u32,
         argsLen: u32
       ): Promise<boolean> => {
-        // Reset our state
-        state.subinvoke.result = undefined;
-        state.subinvoke.error = undefined;
+        const uri = readString(memory.buffer, uriPtr, uriLen);
+        const method = readString(memory.buffer, methodPtr, methodLen);
+        const args = readBytes(memory.buffer, argsPtr, argsLen);
 
+        return subinvoke(uri, method, args);
+      },
+      // Subinvoke with msgpack encoded options overriding the client's config
+      __wrap_subinvoke_with_options: async (
+        uriPtr: u32,
+        uriLen: u32,
+        methodPtr: u32,
+        methodLen: u32,
+        argsPtr: u32,
+        argsLen: u32,
+        optionsPtr: u32,
+        optionsLen: u32
+      ): Promise<boolean> => {
         const uri = readString(memory.buffer, uriPtr, uriLen);
         const method = readString(memory.buffer, methodPtr, methodLen);
         const args = readBytes(memory.buffer, argsPtr, argsLen);
+        const options = msgpackDecode(
+          readBytes(memory.buffer, optionsPtr, optionsLen)
+        ) as SubinvokeOptions;
 
-        const { data, error } = await client.invoke<Uint8Array>({
-          uri: uri,
-          method: method,
-          args: new Uint8Array(args),
-          encodeResult: true,
-        });
+        return subinvoke(uri, method, args, options);
+      },
+      // Run a msgpack encoded list of subinvokes concurrently
+      __wrap_subinvoke_batch: async (
+        requestsPtr: u32,
+        requestsLen: u32
+      ): Promise<void> => {
+        state.subinvokeBatch.result = undefined;
 
-        if (!error) {
-          state.subinvoke.result = data;
-        } else {
-          state.subinvoke.error = `${error.name}: ${error.message}`;
-        }
+        const requests = msgpackDecode(
+          readBytes(memory.buffer, requestsPtr, requestsLen)
+        ) as SubinvokeRequest[];
 
-        return !error;
+        const results = await Promise.all(
+          requests.map(async ({ uri, method, args, options }) => {
+            const { data, error } = await invoke(uri, method, args, options);
+            return error
+              ? { error: `${error.name}: ${error.message}` }
+              : { result: data };
+          })
+        );
+        state.subinvokeBatch.result = msgpackEncode(results);
+      },
+      // Give WASM the size of the batch's results
+      __wrap_subinvoke_batch_result_len: (): u32 => {
+        if (!state.subinvokeBatch.result) {
+          abort(
+            "__wrap_subinvoke_batch_result_len: subinvokeBatch.result is not set"
+          );
+          return 0;
+        }
+        return state.subinvokeBatch.result.byteLength;
+      },
+      // Copy the batch's results into WASM
+      __wrap_subinvoke_batch_result: (ptr: u32): void => {
+        if (!state.subinvokeBatch.result) {
+          abort("__wrap_subinvoke_batch_result: subinvokeBatch.result is not set");
+          return;
+        }
+        writeBytes(state.subinvokeBatch.result, memory.buffer, ptr);
       },
       // Give WASM the size of the result
       __wrap_subinvoke_result_len: (): u32 => {
@@ -104,6 +223,7 @@ export const createImports = (config: {
           method: method,
           args: new Uint8Array(args),
           encodeResult: true,
+          caller: wrapperUri,
         });
 
         if (!error) {
@@ -173,6 +293,21 @@ export const createImports = (config: {
       __wrap_invoke_error: (ptr: u32, len: u32): void => {
         state.invoke.error = readString(memory.buffer, ptr, len);
       },
+      // Give WASM the size of the caller's URI, 0 if there's no caller
+      __wrap_invoke_caller_len: (): u32 => {
+        if (!state.caller) {
+          return 0;
+        }
+        return new TextEncoder().encode(state.caller).byteLength;
+      },
+      // Copy the caller's URI into WASM
+      __wrap_invoke_caller: (ptr: u32): void => {
+        if (!state.caller) {
+          abort("__wrap_invoke_caller: caller is not set");
+          return;
+        }
+        writeString(state.caller, memory.buffer, ptr);
+      },
       __wrap_getImplementations: (uriPtr: u32, uriLen: u32): boolean => {
         const uri = readString(memory.buffer, uriPtr, uriLen);
         const result = client.getImplementations(uri, {});
@@ -211,6 +346,33 @@ export const createImports = (config: {
           `__wrap_abort: ${msg}\nFile: ${file}\nLocation: [${line},${column}]`
         );
       },
+      __wrap_abort_report: (reportPtr: u32, reportLen: u32): void => {
+        const report = msgpackDecode(
+          readBytes(memory.buffer, reportPtr, reportLen)
+        ) as AbortReport;
+
+        let msg = `__wrap_abort: ${report.message}`;
+        if (report.method) {
+          msg += `\nMethod: ${report.method}`;
+        }
+        if (report.code !== undefined && report.code !== null) {
+          msg += `\nCode: ${report.code}`;
+        }
+        for (const source of report.source) {
+          msg += `\nCaused by: ${source}`;
+        }
+        if (report.context.length > 0) {
+          msg += `\nContext: ${report.contextDescription}`;
+          for (const frame of report.context) {
+            msg += `\n  ${frame}`;
+          }
+        }
+        if (report.file) {
+          msg += `\nFile: ${report.file}\nLocation: [${report.line},${report.column}]`;
+        }
+
+        abort(msg);
+      },
       __wrap_debug_log: (ptr: u32, len: u32): void => {
         const msg = readString(memory.buffer, ptr, len);
         console.debug(`__wrap_debug_log: ${msg}`);
@@ -17,6 +17,22 @@ export interface WrapImports extends WebAssembly.Imports {
       argsPtr: u32,
       argsLen: u32
     ) => Promise<boolean>;
+    __wrap_subinvoke_with_options: (
+      uriPtr: u32,
+      uriLen: u32,
+      methodPtr: u32,
+      methodLen: u32,
+      argsPtr: u32,
+      argsLen: u32,
+      optionsPtr: u32,
+      optionsLen: u32
+    ) => Promise<boolean>;
+    __wrap_subinvoke_batch: (
+      requestsPtr: u32,
+      requestsLen: u32
+    ) => Promise<void>;
+    __wrap_subinvoke_batch_result_len: () => u32;
+    __wrap_subinvoke_batch_result: (ptr: u32) => void;
     __wrap_subinvoke_result_len: () => u32;
     __wrap_subinvoke_result: (ptr: u32) => void;
     __wrap_subinvoke_error_len: () => u32;
@@ -38,6 +54,8 @@ export interface WrapImports extends WebAssembly.Imports {
     __wrap_invoke_args: (methodPtr: u32, argsPtr: u32) => void;
     __wrap_invoke_result: (ptr: u32, len: u32) => void;
     __wrap_invoke_error: (ptr: u32, len: u32) => void;
+    __wrap_invoke_caller_len: () => u32;
+    __wrap_invoke_caller: (ptr: u32) => void;
     __wrap_getImplementations: (uriPtr: u32, uriLen: u32) => boolean;
     __wrap_getImplementations_result_len: () => u32;
     __wrap_getImplementations_result: (ptr: u32) => void;
@@ -49,6 +67,7 @@ export interface WrapImports extends WebAssembly.Imports {
       line: u32,
       column: u32
     ) => void;
+    __wrap_abort_report: (reportPtr: u32, reportLen: u32) => void;
     __wrap_debug_log: (ptr: u32, len: u32) => void;
     __wrap_load_env: (ptr: u32) => void;
   };
@@ -0,0 +1,37 @@
+import * as functions from "../bindings/rust/functions";
+import { MustacheFn } from "../bindings/types";
+
+import { GetPathToFunctionsTestFiles } from "@polywrap/test-cases";
+
+import fs from "fs";
+import path from "path";
+
+// Cases the Rust bindgen's functions are tested against too
+type FunctionCase = {
+  function: string;
+  value: string;
+  result?: string;
+  error?: string;
+};
+
+describe("Rust Binding Functions", () => {
+  const cases: FunctionCase[] = JSON.parse(
+    fs.readFileSync(
+      path.join(GetPathToFunctionsTestFiles(), "wasm-rs.json"),
+      "utf-8"
+    )
+  );
+
+  for (const testCase of cases) {
+    it(`${testCase.function}: ${testCase.value}`, () => {
+      const fn = (functions as Record<string, MustacheFn>)[testCase.function];
+      const apply = () => fn()(testCase.value, (str: string) => str);
+
+      if (testCase.error !== undefined) {
+        expect(apply).toThrow(testCase.error);
+      } else {
+        expect(apply()).toBe(testCase.result);
+      }
+    });
+  }
+});
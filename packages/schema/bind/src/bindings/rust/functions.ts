@@ -304,6 +304,14 @@ export const detectKeyword: MustacheFn = () => {
   };
 };
 
+// remove the `Enum_` prefix from enum types,
+// leaving the type as it was written in the schema
+export const toSchemaType: MustacheFn = () => {
+  return (value: string, render: (template: string) => string): string => {
+    return render(value).replace(/Enum_/g, "");
+  };
+};
+
 const toWasmArray = (type: string, optional: boolean): string => {
   const result = type.match(/(\[)([[\]A-Za-z1-9_.!]+)(\])/);
 
@@ -0,0 +1,50 @@
+import { WrapAbi } from "@polywrap/wrap-manifest-types-js";
+import { msgpackEncode } from "@polywrap/msgpack-js";
+
+// The ABI embedded into wrappers, for their `_wrap_abi` export. It's
+// msgpack encoded like in wrap.info, but with the keys of maps sorted and
+// without empty values (nulls and empty arrays), so the Rust bindgen
+// (`polywrap-bindgen`) encodes the same ABI into the same bytes.
+
+const isEmpty = (value: unknown): boolean =>
+  value === undefined ||
+  value === null ||
+  (Array.isArray(value) && value.length === 0);
+
+// Sort the keys of `value`'s objects and drop their empty values
+function canonicalize(value: unknown): unknown {
+  if (Array.isArray(value)) {
+    return value.map(canonicalize);
+  } else if (typeof value === "number") {
+    if (!Number.isInteger(value) || value < 0) {
+      throw Error(`Unsupported ABI number: ${value}`);
+    }
+    return value;
+  } else if (value !== null && typeof value === "object") {
+    const fields = Object.entries(value as Record<string, unknown>)
+      .filter(([, field]) => !isEmpty(field))
+      .sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0));
+
+    const object: Record<string, unknown> = {};
+    for (const [key, field] of fields) {
+      object[key] = canonicalize(field);
+    }
+    return object;
+  }
+  return value;
+}
+
+// The encoded ABI, as the lines of a byte array literal
+export function toAbiBuffer(abi: WrapAbi): string {
+  const bytes = msgpackEncode(canonicalize(abi));
+
+  const lines: string[] = [];
+  for (let i = 0; i < bytes.length; i += 16) {
+    lines.push(
+      Array.from(bytes.slice(i, i + 16))
+        .map((byte) => `0x${byte.toString(16).padStart(2, "0")},`)
+        .join(" ")
+    );
+  }
+  return lines.join("\n    ");
+}
@@ -1,4 +1,5 @@
 import * as Transforms from "./transforms";
+import { toAbiBuffer } from "./abi";
 import { Functions } from "../";
 import { GenerateBindingFn, renderTemplates } from "../..";
 import { loadSubTemplates } from "../../utils";
@@ -33,6 +34,8 @@ export const generateBinding: GenerateBindingFn = (
     outputDirAbs: options.outputDirAbs,
   };
   const output = result.output;
+  // Encoded before the transforms extend the ABI
+  const abiBuffer = toAbiBuffer(options.abi);
   const abi = applyTransforms(options.abi);
 
   // Generate object type folders
@@ -186,7 +189,9 @@ export const generateBinding: GenerateBindingFn = (
   }
 
   // Generate root entry file
-  output.entries.push(...renderTemplates(templatePath(""), abi, subTemplates));
+  output.entries.push(
+    ...renderTemplates(templatePath(""), { ...abi, abiBuffer }, subTemplates)
+  );
 
   return result;
 };
@@ -1,9 +1,13 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn sample_method(args: ArgsSampleMethod) -> SampleResult {
-    return SampleResult {
-        value: args.arg
-    };
-}
+#[derive(Default)]
+pub struct Wrapper;
 
+impl Module for Wrapper {
+    fn sample_method(&self, args: ArgsSampleMethod) -> SampleResult {
+        return SampleResult {
+            value: args.arg
+        };
+    }
+}
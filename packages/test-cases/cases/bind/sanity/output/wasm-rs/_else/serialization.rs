@@ -55,10 +55,15 @@ pub fn read_else<R: Read>(reader: &mut R) -> Result<Else, DecodeError> {
                 _else_set = true;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_else_set {
+        reader.context().push("else", "String", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("else: String.".to_string()));
     }
 
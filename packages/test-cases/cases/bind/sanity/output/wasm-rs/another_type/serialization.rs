@@ -85,7 +85,10 @@ pub fn read_another_type<R: Read>(reader: &mut R) -> Result<AnotherType, DecodeE
                 _const = reader.read_optional_string()?;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
 
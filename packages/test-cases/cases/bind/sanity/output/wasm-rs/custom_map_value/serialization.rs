@@ -55,10 +55,15 @@ pub fn read_custom_map_value<R: Read>(reader: &mut R) -> Result<CustomMapValue,
                 _foo_set = true;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_foo_set {
+        reader.context().push("foo", "String", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("foo: String.".to_string()));
     }
 
@@ -719,88 +719,145 @@ pub fn read_custom_type<R: Read>(reader: &mut R) -> Result<CustomType, DecodeErr
                 _map_custom_value_set = true;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_str_set {
+        reader.context().push("str", "String", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("str: String.".to_string()));
     }
     if !_u_set {
+        reader.context().push("u", "u32", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("u: UInt.".to_string()));
     }
     if !_u8_set {
+        reader.context().push("u8", "u8", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("u8: UInt8.".to_string()));
     }
     if !_u16_set {
+        reader.context().push("u16", "u16", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("u16: UInt16.".to_string()));
     }
     if !_u32_set {
+        reader.context().push("u32", "u32", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("u32: UInt32.".to_string()));
     }
     if !_i_set {
+        reader.context().push("i", "i32", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("i: Int.".to_string()));
     }
     if !_i8_set {
+        reader.context().push("i8", "i8", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("i8: Int8.".to_string()));
     }
     if !_i16_set {
+        reader.context().push("i16", "i16", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("i16: Int16.".to_string()));
     }
     if !_i32_set {
+        reader.context().push("i32", "i32", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("i32: Int32.".to_string()));
     }
     if !_bigint_set {
+        reader.context().push("bigint", "BigInt", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("bigint: BigInt.".to_string()));
     }
     if !_bignumber_set {
+        reader.context().push("bignumber", "BigNumber", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("bignumber: BigNumber.".to_string()));
     }
     if !_json_set {
+        reader.context().push("json", "JSON::Value", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("json: JSON.".to_string()));
     }
     if !_bytes_set {
+        reader.context().push("bytes", "Vec<u8>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("bytes: Bytes.".to_string()));
     }
     if !_boolean_set {
+        reader.context().push("boolean", "bool", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("boolean: Boolean.".to_string()));
     }
     if !_u_array_set {
+        reader.context().push("uArray", "Vec<u32>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("uArray: [UInt].".to_string()));
     }
     if !_u_array_array_set {
+        reader.context().push("uArrayArray", "Vec<Vec<u32>>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("uArrayArray: [[UInt]].".to_string()));
     }
     if !_u_opt_array_opt_array_set {
+        reader.context().push("uOptArrayOptArray", "Vec<Option<Vec<Option<u32>>>>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("uOptArrayOptArray: [[UInt32]].".to_string()));
     }
     if !_u_array_opt_array_array_set {
+        reader.context().push("uArrayOptArrayArray", "Vec<Option<Vec<Vec<u32>>>>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("uArrayOptArrayArray: [[[UInt32]]].".to_string()));
     }
     if !_object_set {
+        reader.context().push("object", "AnotherType", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("object: AnotherType.".to_string()));
     }
     if !_object_array_set {
+        reader.context().push("objectArray", "Vec<AnotherType>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("objectArray: [AnotherType].".to_string()));
     }
     if !_en_set {
+        reader.context().push("en", "CustomEnum", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("en: CustomEnum.".to_string()));
     }
     if !_enum_array_set {
+        reader.context().push("enumArray", "Vec<CustomEnum>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("enumArray: [CustomEnum].".to_string()));
     }
     if !_map_set {
+        reader.context().push("map", "Map<String, i32>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("map: Map<String, Int>.".to_string()));
     }
     if !_map_of_arr_set {
+        reader.context().push("mapOfArr", "Map<String, Vec<i32>>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapOfArr: Map<String, [Int]>.".to_string()));
     }
     if !_map_of_obj_set {
+        reader.context().push("mapOfObj", "Map<String, AnotherType>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapOfObj: Map<String, AnotherType>.".to_string()));
     }
     if !_map_of_arr_of_obj_set {
+        reader.context().push("mapOfArrOfObj", "Map<String, Vec<AnotherType>>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapOfArrOfObj: Map<String, [AnotherType]>.".to_string()));
     }
     if !_map_custom_value_set {
+        reader.context().push("mapCustomValue", "Map<String, Option<CustomMapValue>>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapCustomValue: Map<String, CustomMapValue>.".to_string()));
     }
 
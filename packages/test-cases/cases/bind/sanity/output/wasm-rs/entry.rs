@@ -1,15 +1,1318 @@
 use crate::{
+    Module,
+    Wrapper,
     module_method_wrapped,
     object_method_wrapped,
     optional_env_method_wrapped,
     if_wrapped
 };
+use polywrap_wasm_rs::Interceptor;
 use polywrap_wasm_rs::{
+    abi,
     abort,
+    interceptor,
     invoke,
+    ArgumentInfo,
+    EnvRequirement,
     InvokeArgs,
+    MethodInfo,
 };
 
+/// Methods exported by this wrapper, as declared in its schema
+pub const METHODS: &[MethodInfo] = &[
+    MethodInfo {
+        name: "moduleMethod",
+        arguments: &[
+            ArgumentInfo { name: "str", type_name: "String!" },
+            ArgumentInfo { name: "optStr", type_name: "String" },
+            ArgumentInfo { name: "en", type_name: "CustomEnum!" },
+            ArgumentInfo { name: "optEnum", type_name: "CustomEnum" },
+            ArgumentInfo { name: "enumArray", type_name: "[CustomEnum!]!" },
+            ArgumentInfo { name: "optEnumArray", type_name: "[CustomEnum]" },
+            ArgumentInfo { name: "map", type_name: "Map<String!, Int!>!" },
+            ArgumentInfo { name: "mapOfArr", type_name: "Map<String!, [Int!]!>!" },
+            ArgumentInfo { name: "mapOfMap", type_name: "Map<String!, Map<String!, Int!>!>!" },
+            ArgumentInfo { name: "mapOfObj", type_name: "Map<String!, AnotherType!>!" },
+            ArgumentInfo { name: "mapOfArrOfObj", type_name: "Map<String!, [AnotherType!]!>!" },
+        ],
+        return_type: "Int!",
+        env: EnvRequirement::None,
+    },
+    MethodInfo {
+        name: "objectMethod",
+        arguments: &[
+            ArgumentInfo { name: "object", type_name: "AnotherType!" },
+            ArgumentInfo { name: "optObject", type_name: "AnotherType" },
+            ArgumentInfo { name: "objectArray", type_name: "[AnotherType!]!" },
+            ArgumentInfo { name: "optObjectArray", type_name: "[AnotherType]" },
+        ],
+        return_type: "AnotherType",
+        env: EnvRequirement::Required,
+    },
+    MethodInfo {
+        name: "optionalEnvMethod",
+        arguments: &[
+            ArgumentInfo { name: "object", type_name: "AnotherType!" },
+            ArgumentInfo { name: "optObject", type_name: "AnotherType" },
+            ArgumentInfo { name: "objectArray", type_name: "[AnotherType!]!" },
+            ArgumentInfo { name: "optObjectArray", type_name: "[AnotherType]" },
+        ],
+        return_type: "AnotherType",
+        env: EnvRequirement::Optional,
+    },
+    MethodInfo {
+        name: "if",
+        arguments: &[
+            ArgumentInfo { name: "if", type_name: "else!" },
+        ],
+        return_type: "else!",
+        env: EnvRequirement::None,
+    },
+];
+
+pub fn methods() -> &'static [MethodInfo] {
+    METHODS
+}
+
+/// The msgpack encoded ABI of this wrapper, the `abi` of its wrap.info
+pub const ABI: &[u8] = &[
+    0x8a, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x54, 0x79, 0x70, 0x65, 0x73, 0x92, 0x83, 0xa9, 0x63, 0x6f,
+    0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x73, 0x92, 0xa6, 0x53, 0x54, 0x52, 0x49, 0x4e, 0x47, 0xa5,
+    0x42, 0x59, 0x54, 0x45, 0x53, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x08, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x83, 0xa9, 0x63, 0x6f, 0x6e,
+    0x73, 0x74, 0x61, 0x6e, 0x74, 0x73, 0x92, 0xa3, 0x66, 0x6f, 0x72, 0xa2, 0x69, 0x6e, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x08, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x77, 0x68, 0x69, 0x6c, 0x65, 0xa7,
+    0x65, 0x6e, 0x76, 0x54, 0x79, 0x70, 0x65, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x01,
+    0x00, 0x00, 0xaa, 0x70, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x69, 0x65, 0x73, 0x93, 0x85, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x70, 0x72, 0x6f, 0x70, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x70, 0x72, 0x6f,
+    0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7,
+    0x6f, 0x70, 0x74, 0x50, 0x72, 0x6f, 0x70, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x6f, 0x70, 0x74, 0x50, 0x72,
+    0x6f, 0x70, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x22, 0xa3, 0x6d, 0x61, 0x70, 0x86, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74, 0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74, 0x4d, 0x61, 0x70, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72,
+    0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74,
+    0x4d, 0x61, 0x70, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xb0, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e,
+    0x74, 0x3e, 0xa5, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74, 0x4d, 0x61, 0x70, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74, 0x4d, 0x61, 0x70,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xb0, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x2c, 0x20, 0x49, 0x6e, 0x74, 0x3e, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x45, 0x6e, 0x76, 0xb1,
+    0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x45, 0x6e, 0x75, 0x6d, 0x54, 0x79, 0x70, 0x65,
+    0x73, 0x91, 0x86, 0xa9, 0x63, 0x6f, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x73, 0x92, 0xa6, 0x53,
+    0x54, 0x52, 0x49, 0x4e, 0x47, 0xa5, 0x42, 0x59, 0x54, 0x45, 0x53, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x02, 0x08, 0xa9, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0xaa, 0x54, 0x65,
+    0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0xaa, 0x6e, 0x61, 0x74, 0x69, 0x76, 0x65, 0x54,
+    0x79, 0x70, 0x65, 0xa4, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65,
+    0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa3, 0x75, 0x72,
+    0x69, 0xb2, 0x74, 0x65, 0x73, 0x74, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x75, 0x72, 0x69,
+    0x2e, 0x65, 0x74, 0x68, 0xb0, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x45, 0x6e, 0x76,
+    0x54, 0x79, 0x70, 0x65, 0x73, 0x91, 0x86, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x08, 0x00,
+    0x00, 0xa9, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0xaa, 0x54, 0x65, 0x73, 0x74,
+    0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0xaa, 0x6e, 0x61, 0x74, 0x69, 0x76, 0x65, 0x54, 0x79, 0x70,
+    0x65, 0xa3, 0x45, 0x6e, 0x76, 0xaa, 0x70, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x69, 0x65, 0x73,
+    0x91, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x65, 0x6e,
+    0x76, 0x69, 0x72, 0x6f, 0x50, 0x72, 0x6f, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x65, 0x6e, 0x76, 0x69, 0x72, 0x6f, 0x50, 0x72, 0x6f, 0x70,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6,
+    0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xae, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f,
+    0x72, 0x74, 0x5f, 0x45, 0x6e, 0x76, 0xa3, 0x75, 0x72, 0x69, 0xb2, 0x74, 0x65, 0x73, 0x74, 0x69,
+    0x6d, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x75, 0x72, 0x69, 0x2e, 0x65, 0x74, 0x68, 0xb3, 0x69, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65,
+    0x73, 0x91, 0x87, 0xab, 0x69, 0x73, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x66, 0x61, 0x63, 0x65, 0xc3,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x01, 0x00, 0xa7, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x73,
+    0x92, 0x87, 0xa9, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x9d, 0x85, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x73, 0x74, 0x72, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x73, 0x74, 0x72, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74,
+    0x53, 0x74, 0x72, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53,
+    0x74, 0x72, 0x69, 0x6e, 0x67, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa1, 0x75, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa1, 0x75, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x6f, 0x70, 0x74,
+    0x55, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x6f, 0x70, 0x74, 0x55, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55,
+    0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0x85, 0xa5, 0x61,
+    0x72, 0x72, 0x61, 0x79, 0x86, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65,
+    0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55,
+    0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75,
+    0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61,
+    0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55,
+    0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0xa4,
+    0x69, 0x74, 0x65, 0x6d, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b,
+    0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x5b,
+    0x55, 0x49, 0x6e, 0x74, 0x5d, 0x5d, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f,
+    0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74,
+    0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70,
+    0x6f, 0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61,
+    0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb3, 0x5b, 0x54, 0x65, 0x73, 0x74,
+    0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+    0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xb3, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74,
+    0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0x84, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85,
+    0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f,
+    0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73,
+    0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xb3, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f,
+    0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb3, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f,
+    0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0x85, 0xa4, 0x65, 0x6e, 0x75, 0x6d,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x65,
+    0x6e, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x65, 0x6e, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65,
+    0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x65,
+    0x6e, 0x75, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa7, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54,
+    0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75,
+    0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+    0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x65,
+    0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49,
+    0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e,
+    0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+    0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x5b, 0x54, 0x65, 0x73, 0x74,
+    0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xb1, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75,
+    0x6d, 0x5d, 0x84, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x83,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70,
+    0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf,
+    0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4,
+    0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f,
+    0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac,
+    0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xb1, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e,
+    0x75, 0x6d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f,
+    0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xb1, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75,
+    0x6d, 0x5d, 0xa3, 0x65, 0x6e, 0x76, 0x81, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x40, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x69, 0x6d, 0x70,
+    0x6f, 0x72, 0x74, 0x65, 0x64, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65,
+    0x64, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x69, 0x6d, 0x70,
+    0x6f, 0x72, 0x74, 0x65, 0x64, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f,
+    0x72, 0x74, 0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x4d,
+    0x65, 0x74, 0x68, 0x6f, 0x64, 0x86, 0xa9, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73,
+    0x91, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x61, 0x72, 0x67, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3,
+    0x61, 0x72, 0x67, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa3, 0x61, 0x72, 0x67, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8,
+    0x5b, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa3, 0x61, 0x72, 0x67, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5d, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x40, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x61, 0x6e, 0x6f, 0x74, 0x68,
+    0x65, 0x72, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa6, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4d, 0x65, 0x74,
+    0x68, 0x6f, 0x64, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xad, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa9, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+    0x70, 0x61, 0x63, 0x65, 0xaa, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0xaa,
+    0x6e, 0x61, 0x74, 0x69, 0x76, 0x65, 0x54, 0x79, 0x70, 0x65, 0xa6, 0x4d, 0x6f, 0x64, 0x75, 0x6c,
+    0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+    0x74, 0x5f, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0xa3, 0x75, 0x72, 0x69, 0xb2, 0x74, 0x65, 0x73,
+    0x74, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x75, 0x72, 0x69, 0x2e, 0x65, 0x74, 0x68, 0xb3,
+    0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x54, 0x79,
+    0x70, 0x65, 0x73, 0x92, 0x86, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x04, 0x01, 0xa9, 0x6e, 0x61,
+    0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0xaa, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f,
+    0x72, 0x74, 0xaa, 0x6e, 0x61, 0x74, 0x69, 0x76, 0x65, 0x54, 0x79, 0x70, 0x65, 0xa6, 0x4f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0xaa, 0x70, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x69, 0x65, 0x73, 0x98,
+    0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54,
+    0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+    0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f,
+    0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+    0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+    0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63,
+    0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+    0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xba, 0x5b,
+    0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68,
+    0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xba,
+    0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74,
+    0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0x84, 0xa5, 0x61, 0x72, 0x72, 0x61,
+    0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70,
+    0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74,
+    0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xba, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f,
+    0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xba, 0x5b, 0x54,
+    0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5d, 0x85, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x65, 0x6e, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54,
+    0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x65, 0x6e, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74,
+    0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x65, 0x6e, 0x75,
+    0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7,
+    0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73,
+    0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f,
+    0x45, 0x6e, 0x75, 0x6d, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x65, 0x6e, 0x75,
+    0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9,
+    0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70,
+    0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d,
+    0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f,
+    0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9,
+    0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x5b,
+    0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0x5d,
+    0x84, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x83, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45,
+    0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65,
+    0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x69, 0x74,
+    0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xac, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e,
+    0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70,
+    0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1,
+    0x5b, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d,
+    0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74,
+    0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x5b,
+    0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0x5d,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74,
+    0x5f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa3, 0x75, 0x72, 0x69, 0xb2, 0x74, 0x65, 0x73, 0x74,
+    0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x75, 0x72, 0x69, 0x2e, 0x65, 0x74, 0x68, 0x86, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x04, 0x01, 0xa9, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+    0x65, 0xaa, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0xaa, 0x6e, 0x61, 0x74,
+    0x69, 0x76, 0x65, 0x54, 0x79, 0x70, 0x65, 0xad, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0xaa, 0x70, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x69, 0x65, 0x73,
+    0x91, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x70, 0x72,
+    0x6f, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61,
+    0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4,
+    0x70, 0x72, 0x6f, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6,
+    0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74,
+    0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0xa3, 0x75, 0x72, 0x69, 0xb2, 0x74, 0x65, 0x73, 0x74, 0x69, 0x6d, 0x70,
+    0x6f, 0x72, 0x74, 0x2e, 0x75, 0x72, 0x69, 0x2e, 0x65, 0x74, 0x68, 0xae, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x66, 0x61, 0x63, 0x65, 0x54, 0x79, 0x70, 0x65, 0x73, 0x91, 0x86, 0xac, 0x63, 0x61, 0x70,
+    0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x81, 0xb2, 0x67, 0x65, 0x74, 0x49, 0x6d,
+    0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x81, 0xa7, 0x65,
+    0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0xc3, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x80, 0x00, 0xa9,
+    0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0xaa, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0xaa, 0x6e, 0x61, 0x74, 0x69, 0x76, 0x65, 0x54, 0x79, 0x70, 0x65, 0xa9,
+    0x49, 0x6e, 0x74, 0x65, 0x72, 0x66, 0x61, 0x63, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x54,
+    0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0xa3, 0x75, 0x72, 0x69, 0xb2, 0x74, 0x65,
+    0x73, 0x74, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x75, 0x72, 0x69, 0x2e, 0x65, 0x74, 0x68,
+    0xaa, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x84, 0xa7, 0x69, 0x6d, 0x70,
+    0x6f, 0x72, 0x74, 0x73, 0x94, 0x81, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74,
+    0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x81, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xb1, 0x54, 0x65, 0x73, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x4f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0x81, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x54, 0x65, 0x73, 0x74,
+    0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x4f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0x81, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaf, 0x54, 0x65, 0x73, 0x74, 0x49,
+    0x6d, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcc,
+    0x80, 0xa7, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x73, 0x94, 0x86, 0xa9, 0x61, 0x72, 0x67, 0x75,
+    0x6d, 0x65, 0x6e, 0x74, 0x73, 0x9b, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa3, 0x73, 0x74, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa3, 0x73, 0x74, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0xa6, 0x73, 0x63, 0x61,
+    0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6,
+    0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x85, 0xa4,
+    0x65, 0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa2, 0x65, 0x6e, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x65, 0x6e, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73,
+    0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x83, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x6f, 0x70, 0x74, 0x45,
+    0x6e, 0x75, 0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45,
+    0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x6f,
+    0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x65,
+    0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f,
+    0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xac, 0x5b, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x5d, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xac, 0x5b, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x5d, 0x84, 0xa5,
+    0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75,
+    0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75,
+    0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xac, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xac, 0x5b, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x5d,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45,
+    0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xac, 0x5b, 0x43,
+    0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x5d, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3,
+    0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa3, 0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb0, 0x4d, 0x61, 0x70,
+    0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e, 0x74, 0x3e, 0xa5, 0x76, 0x61,
+    0x6c, 0x75, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3,
+    0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x6d, 0x61, 0x70, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb0, 0x4d,
+    0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e, 0x74, 0x3e, 0x85,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa5, 0x61, 0x72, 0x72, 0x61,
+    0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xa5, 0x5b, 0x49, 0x6e, 0x74, 0x5d, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6,
+    0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb2, 0x4d, 0x61,
+    0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x5b, 0x49, 0x6e, 0x74, 0x5d, 0x3e,
+    0xa5, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72,
+    0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8,
+    0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e,
+    0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x5b, 0x49, 0x6e, 0x74, 0x5d, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb2, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x5b, 0x49, 0x6e, 0x74, 0x5d, 0x3e, 0x85, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61,
+    0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00,
+    0x02, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53,
+    0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb0, 0x4d, 0x61, 0x70, 0x3c,
+    0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e, 0x74, 0x3e, 0xa5, 0x76, 0x61, 0x6c,
+    0x75, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d,
+    0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8,
+    0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xbd, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0x2c, 0x20, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20,
+    0x49, 0x6e, 0x74, 0x3e, 0x3e, 0xa5, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x87, 0xa3, 0x6b, 0x65, 0x79,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70,
+    0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xce, 0x00, 0x04, 0x00, 0x02, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xb0, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e,
+    0x74, 0x3e, 0xa5, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4d, 0x61, 0x70, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xbd, 0x4d, 0x61,
+    0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e, 0x74, 0x3e, 0x3e, 0x85, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62, 0x6a,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6,
+    0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa6, 0x6f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74,
+    0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e,
+    0x67, 0x2c, 0x20, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x3e, 0xa5,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74,
+    0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70,
+    0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xb8, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c,
+    0x20, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x3e, 0x85, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86,
+    0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62,
+    0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72,
+    0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54,
+    0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d,
+    0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62,
+    0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xba, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x5b, 0x41, 0x6e,
+    0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0x3e, 0xa5, 0x76, 0x61, 0x6c, 0x75,
+    0x65, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66,
+    0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41,
+    0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70,
+    0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70,
+    0x65, 0x5d, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72,
+    0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xba, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c,
+    0x20, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0x3e, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x40, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6d, 0x6f, 0x64, 0x75, 0x6c,
+    0x65, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa6, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x4d, 0x65, 0x74, 0x68, 0x6f,
+    0x64, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c,
+    0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6d,
+    0x6f, 0x64, 0x75, 0x6c, 0x65, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x4d, 0x65, 0x74,
+    0x68, 0x6f, 0x64, 0x87, 0xa9, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x94, 0x85,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd,
+    0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e,
+    0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x54, 0x79, 0x70, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63,
+    0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9,
+    0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41,
+    0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab,
+    0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x85, 0xa5, 0x61, 0x72, 0x72,
+    0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20,
+    0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+    0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad,
+    0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+    0x5d, 0x84, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74,
+    0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63,
+    0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab,
+    0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41,
+    0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0xa3, 0x65, 0x6e, 0x76, 0x81,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x40,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x4d, 0x65, 0x74, 0x68,
+    0x6f, 0x64, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x72, 0x65, 0x74,
+    0x75, 0x72, 0x6e, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac,
+    0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa6, 0x6f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xac, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x87, 0xa9, 0x61, 0x72,
+    0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x94, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79,
+    0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54,
+    0x79, 0x70, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x54, 0x79, 0x70, 0x65, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65,
+    0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab,
+    0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68,
+    0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e,
+    0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e,
+    0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0x84, 0xa5, 0x61, 0x72, 0x72, 0x61,
+    0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6,
+    0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x54, 0x79, 0x70, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68,
+    0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54,
+    0x79, 0x70, 0x65, 0x5d, 0xa3, 0x65, 0x6e, 0x76, 0x81, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc2, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x40, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x6f,
+    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x45, 0x6e, 0x76, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x72, 0x65, 0x74, 0x75, 0x72,
+    0x6e, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x6f, 0x70,
+    0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x45, 0x6e, 0x76, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa6,
+    0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x45, 0x6e, 0x76,
+    0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74,
+    0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f,
+    0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x4d, 0x65,
+    0x74, 0x68, 0x6f, 0x64, 0x86, 0xa9, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x91,
+    0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x69, 0x66, 0xa6,
+    0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x69, 0x66, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x65, 0x6c, 0x73, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x65, 0x6c, 0x73, 0x65, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x40, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x69, 0x66, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x85, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x69, 0x66, 0xa6, 0x6f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa2, 0x69, 0x66, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa4, 0x65, 0x6c, 0x73, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x65, 0x6c, 0x73, 0x65, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa6, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x4d,
+    0x6f, 0x64, 0x75, 0x6c, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x54, 0x79, 0x70, 0x65,
+    0x73, 0x94, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x01, 0xaa, 0x70, 0x72, 0x6f, 0x70, 0x65, 0x72,
+    0x74, 0x69, 0x65, 0x73, 0xdc, 0x00, 0x2a, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa3, 0x73, 0x74, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x73, 0x74, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa6, 0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x85,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa1, 0x75, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa1, 0x75, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x6f, 0x70, 0x74, 0x55, 0xa6, 0x73, 0x63, 0x61, 0x6c,
+    0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x6f,
+    0x70, 0x74, 0x55, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa2, 0x75, 0x38, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa2, 0x75, 0x38, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x55, 0x49, 0x6e, 0x74, 0x38, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa5, 0x55, 0x49, 0x6e, 0x74, 0x38, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa3, 0x75, 0x31, 0x36, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa3, 0x75, 0x31, 0x36, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x31, 0x36, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x31, 0x36, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x75, 0x33, 0x32, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x75, 0x33, 0x32, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x85, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa1, 0x69, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa1, 0x69, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xa3, 0x49, 0x6e, 0x74, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa2, 0x69, 0x38, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73,
+    0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa2, 0x69, 0x38, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa4, 0x49, 0x6e, 0x74, 0x38, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x49, 0x6e,
+    0x74, 0x38, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x69,
+    0x31, 0x36, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61,
+    0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3,
+    0x69, 0x31, 0x36, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa5, 0x49, 0x6e, 0x74, 0x31, 0x36, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x49, 0x6e,
+    0x74, 0x31, 0x36, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3,
+    0x69, 0x33, 0x32, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa3, 0x69, 0x33, 0x32, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa5, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x49,
+    0x6e, 0x74, 0x33, 0x32, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa6, 0x62, 0x69, 0x67, 0x69, 0x6e, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x62, 0x69, 0x67, 0x69, 0x6e, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x42, 0x69, 0x67, 0x49, 0x6e,
+    0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x42, 0x69, 0x67, 0x49, 0x6e, 0x74, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x42, 0x69, 0x67,
+    0x69, 0x6e, 0x74, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x42, 0x69, 0x67, 0x69, 0x6e, 0x74,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x42, 0x69, 0x67, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xa6, 0x42, 0x69, 0x67, 0x49, 0x6e, 0x74, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x62, 0x69, 0x67, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x62, 0x69, 0x67, 0x6e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa9, 0x42, 0x69, 0x67, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa9, 0x42, 0x69, 0x67, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x42, 0x69, 0x67,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x42, 0x69, 0x67,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa9, 0x42, 0x69, 0x67, 0x4e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa9, 0x42, 0x69, 0x67, 0x4e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa4, 0x6a, 0x73, 0x6f, 0x6e, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6,
+    0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa4, 0x6a, 0x73, 0x6f, 0x6e, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x4a, 0x53, 0x4f, 0x4e, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa4, 0x4a, 0x53, 0x4f, 0x4e, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa7, 0x6f, 0x70, 0x74, 0x4a, 0x73, 0x6f, 0x6e, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72,
+    0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x6f, 0x70, 0x74,
+    0x4a, 0x73, 0x6f, 0x6e, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x4a, 0x53, 0x4f, 0x4e, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa4, 0x4a, 0x53, 0x4f, 0x4e, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa5, 0x62, 0x79, 0x74, 0x65, 0x73, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa5, 0x62, 0x79, 0x74, 0x65, 0x73, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x42, 0x79, 0x74,
+    0x65, 0x73, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x42, 0x79, 0x74, 0x65, 0x73, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6f, 0x70, 0x74, 0x42, 0x79, 0x74,
+    0x65, 0x73, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6f, 0x70, 0x74, 0x42, 0x79, 0x74, 0x65, 0x73, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa5, 0x42, 0x79, 0x74, 0x65, 0x73, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x42,
+    0x79, 0x74, 0x65, 0x73, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa7, 0x62, 0x6f, 0x6f, 0x6c, 0x65, 0x61, 0x6e, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x62, 0x6f, 0x6f, 0x6c, 0x65, 0x61, 0x6e, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa7, 0x42, 0x6f, 0x6f,
+    0x6c, 0x65, 0x61, 0x6e, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa7, 0x42, 0x6f, 0x6f, 0x6c, 0x65, 0x61,
+    0x6e, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x6f, 0x70,
+    0x74, 0x42, 0x6f, 0x6f, 0x6c, 0x65, 0x61, 0x6e, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x6f, 0x70, 0x74, 0x42,
+    0x6f, 0x6f, 0x6c, 0x65, 0x61, 0x6e, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa7, 0x42, 0x6f, 0x6f, 0x6c,
+    0x65, 0x61, 0x6e, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa7, 0x42, 0x6f, 0x6f, 0x6c, 0x65, 0x61, 0x6e,
+    0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4,
+    0x55, 0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6,
+    0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa6, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e,
+    0x74, 0x5d, 0x84, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x75, 0x4f, 0x70, 0x74,
+    0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x73,
+    0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa9, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0x84, 0xa5, 0x61, 0x72,
+    0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x55, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x55, 0x4f, 0x70, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x55, 0x4f, 0x70, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x55, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0x84, 0xa5,
+    0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0x4f, 0x70,
+    0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70,
+    0x74, 0x53, 0x74, 0x72, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61,
+    0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae,
+    0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8,
+    0x5b, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74, 0x53, 0x74, 0x72, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5d,
+    0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4,
+    0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4,
+    0x55, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d,
+    0xa4, 0x69, 0x74, 0x65, 0x6d, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x55, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa6, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x5b,
+    0x55, 0x49, 0x6e, 0x74, 0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xab, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x5b, 0x55,
+    0x49, 0x6e, 0x74, 0x5d, 0x5d, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa5, 0x61, 0x72,
+    0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f,
+    0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x75,
+    0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xb1, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74,
+    0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33,
+    0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0xa4,
+    0x69, 0x74, 0x65, 0x6d, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49,
+    0x6e, 0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb1,
+    0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70,
+    0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74,
+    0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb1, 0x75, 0x4f, 0x70, 0x74,
+    0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x5b, 0x5b, 0x55,
+    0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xb1, 0x75, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xaa, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0x85, 0xa5,
+    0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa5, 0x61, 0x72,
+    0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55,
+    0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74,
+    0x33, 0x32, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49,
+    0x6e, 0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3,
+    0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xb3, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8,
+    0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x5b, 0x5b, 0x55,
+    0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x85, 0xa5, 0x61, 0x72,
+    0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55,
+    0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74,
+    0x33, 0x32, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49,
+    0x6e, 0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3,
+    0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xb3, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8,
+    0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x5b, 0x5b, 0x55,
+    0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xb3, 0x75, 0x41, 0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xac, 0x5b, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d,
+    0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xb3, 0x75, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0x4f, 0x70, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xac, 0x5b, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0x5d, 0x84, 0xa5, 0x61,
+    0x72, 0x72, 0x61, 0x79, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa5, 0x61, 0x72, 0x72,
+    0x61, 0x79, 0x86, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a,
+    0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6,
+    0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a,
+    0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72,
+    0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6,
+    0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa,
+    0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74,
+    0x33, 0x32, 0x5d, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x86, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79,
+    0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63,
+    0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61,
+    0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d,
+    0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63,
+    0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61,
+    0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xaa, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xac, 0x5b, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32,
+    0x5d, 0x5d, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86,
+    0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6,
+    0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32,
+    0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x85, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6,
+    0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e,
+    0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32,
+    0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61,
+    0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d,
+    0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x86, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69,
+    0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa,
+    0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a,
+    0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8,
+    0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x85, 0xa4, 0x69,
+    0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa,
+    0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a,
+    0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa6, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa8,
+    0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x5b, 0x5b,
+    0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xac, 0x5b, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0x5d,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a,
+    0x79, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xae, 0x5b, 0x5b, 0x5b, 0x5b,
+    0x55, 0x49, 0x6e, 0x74, 0x33, 0x32, 0x5d, 0x5d, 0x5d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0x63, 0x72, 0x61, 0x7a, 0x79, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xae, 0x5b, 0x5b, 0x5b, 0x5b, 0x55, 0x49, 0x6e, 0x74, 0x33, 0x32,
+    0x5d, 0x5d, 0x5d, 0x5d, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa6, 0x6f, 0x62, 0x6a,
+    0x65, 0x63, 0x74, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e,
+    0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa6,
+    0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+    0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79,
+    0x70, 0x65, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+    0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70,
+    0x65, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xab, 0x6f, 0x62,
+    0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0x84, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x69,
+    0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70,
+    0x65, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f, 0x70, 0x74,
+    0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa6, 0x6f, 0x62, 0x6a, 0x65,
+    0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xae, 0x6f, 0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79,
+    0x70, 0x65, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6f,
+    0x70, 0x74, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d,
+    0x85, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x65, 0x6e, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64,
+    0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75,
+    0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa2, 0x65, 0x6e, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43,
+    0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x83,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa7, 0x6f, 0x70,
+    0x74, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f,
+    0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa7, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75,
+    0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x85, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x86,
+    0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73,
+    0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41, 0x72,
+    0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xac, 0x5b, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x5d, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa9, 0x65, 0x6e, 0x75, 0x6d, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xac, 0x5b, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x5d,
+    0x84, 0xa5, 0x61, 0x72, 0x72, 0x61, 0x79, 0x85, 0xa4, 0x65, 0x6e, 0x75, 0x6d, 0x83, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45,
+    0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75,
+    0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x83, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0xcd, 0x40, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45,
+    0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75,
+    0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xac, 0x5b, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75,
+    0x6d, 0x5d, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xac, 0x6f, 0x70,
+    0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x72, 0x72, 0x61, 0x79, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xac,
+    0x5b, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x45, 0x6e, 0x75, 0x6d, 0x5d, 0x85, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69,
+    0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa3, 0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6,
+    0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa3, 0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb0, 0x4d,
+    0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e, 0x74, 0x3e, 0xa5,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa3, 0x6d, 0x61, 0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x6d, 0x61,
+    0x70, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xb0, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x49, 0x6e, 0x74,
+    0x3e, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa5, 0x61, 0x72,
+    0x72, 0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72,
+    0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e,
+    0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70,
+    0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6,
+    0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xa5, 0x5b, 0x49, 0x6e, 0x74, 0x5d, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41,
+    0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04,
+    0x00, 0x02, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb2,
+    0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x5b, 0x49, 0x6e, 0x74,
+    0x5d, 0x3e, 0xa5, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa3, 0x49, 0x6e, 0x74, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e,
+    0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72,
+    0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa3,
+    0x49, 0x6e, 0x74, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa5, 0x5b, 0x49, 0x6e, 0x74, 0x5d, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0xa8, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb2, 0x4d, 0x61, 0x70, 0x3c,
+    0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x5b, 0x49, 0x6e, 0x74, 0x5d, 0x3e, 0x85, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79,
+    0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00,
+    0x04, 0x00, 0x02, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62,
+    0x6a, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20,
+    0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41,
+    0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69,
+    0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70,
+    0x65, 0x3e, 0xa5, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20,
+    0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41,
+    0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8,
+    0x6d, 0x61, 0x70, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xb8, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0x2c, 0x20, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x3e,
+    0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa5, 0x61, 0x72, 0x72,
+    0x61, 0x79, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20,
+    0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f,
+    0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x84,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61,
+    0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68,
+    0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79,
+    0x70, 0x65, 0x5d, 0xa3, 0x6b, 0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e,
+    0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62,
+    0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00,
+    0x02, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f,
+    0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74,
+    0x79, 0x70, 0x65, 0xba, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20,
+    0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0x5d, 0x3e, 0xa5, 0x76,
+    0x61, 0x6c, 0x75, 0x65, 0x86, 0xa4, 0x69, 0x74, 0x65, 0x6d, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72,
+    0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3,
+    0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70,
+    0x65, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70,
+    0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63,
+    0x74, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad,
+    0x6d, 0x61, 0x70, 0x4f, 0x66, 0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f,
+    0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xad, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x54, 0x79, 0x70, 0x65, 0x5d, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xad, 0x6d, 0x61, 0x70, 0x4f, 0x66,
+    0x41, 0x72, 0x72, 0x4f, 0x66, 0x4f, 0x62, 0x6a, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xba, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0x2c, 0x20, 0x5b, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x54, 0x79, 0x70, 0x65,
+    0x5d, 0x3e, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa3, 0x6d, 0x61, 0x70, 0x87, 0xa3, 0x6b,
+    0x65, 0x79, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6d,
+    0x61, 0x70, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x56, 0x61, 0x6c, 0x75, 0x65, 0xa8, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xce, 0x00, 0x04, 0x00, 0x02, 0xa4, 0x6e, 0x61,
+    0x6d, 0x65, 0xae, 0x6d, 0x61, 0x70, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x56, 0x61, 0x6c, 0x75,
+    0x65, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0xcd, 0x20,
+    0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6d, 0x61, 0x70, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d,
+    0x56, 0x61, 0x6c, 0x75, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xae, 0x43, 0x75, 0x73, 0x74, 0x6f,
+    0x6d, 0x4d, 0x61, 0x70, 0x56, 0x61, 0x6c, 0x75, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xbb, 0x4d, 0x61, 0x70, 0x3c, 0x53, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x2c, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x4d, 0x61, 0x70, 0x56, 0x61,
+    0x6c, 0x75, 0x65, 0x3e, 0xa5, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xae, 0x6d, 0x61, 0x70, 0x43, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x56, 0x61, 0x6c, 0x75, 0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xae, 0x43, 0x75, 0x73,
+    0x74, 0x6f, 0x6d, 0x4d, 0x61, 0x70, 0x56, 0x61, 0x6c, 0x75, 0x65, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xae, 0x6d, 0x61, 0x70, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x56, 0x61, 0x6c, 0x75, 0x65, 0xa8,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xbb, 0x4d,
+    0x61, 0x70, 0x3c, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f,
+    0x6d, 0x4d, 0x61, 0x70, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x3e, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa,
+    0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x54, 0x79, 0x70, 0x65, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x01, 0xaa, 0x70, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x69, 0x65, 0x73, 0x93, 0x84, 0xa4, 0x6b,
+    0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x70, 0x72, 0x6f, 0x70, 0xa6, 0x73,
+    0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d,
+    0x65, 0xa4, 0x70, 0x72, 0x6f, 0x70, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x84, 0xa4,
+    0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x63, 0x69, 0x72, 0x63, 0x75,
+    0x6c, 0x61, 0x72, 0xa6, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0xcd, 0x20, 0x00, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa8, 0x63, 0x69, 0x72, 0x63, 0x75, 0x6c, 0x61,
+    0x72, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x54, 0x79, 0x70,
+    0x65, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xaa, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x54, 0x79, 0x70,
+    0x65, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa5, 0x63, 0x6f,
+    0x6e, 0x73, 0x74, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64,
+    0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa5, 0x63, 0x6f, 0x6e, 0x73, 0x74, 0xa4, 0x74, 0x79, 0x70,
+    0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xab, 0x41, 0x6e, 0x6f, 0x74, 0x68, 0x65,
+    0x72, 0x54, 0x79, 0x70, 0x65, 0x83, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x01, 0xaa, 0x70, 0x72, 0x6f,
+    0x70, 0x65, 0x72, 0x74, 0x69, 0x65, 0x73, 0x91, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4,
+    0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x66, 0x6f, 0x6f, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+    0x64, 0xc3, 0xa6, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04,
+    0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa3, 0x66, 0x6f, 0x6f, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72,
+    0x65, 0x64, 0xc3, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xae, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x4d, 0x61, 0x70, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x83,
+    0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x01, 0xaa, 0x70, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x69, 0x65,
+    0x73, 0x91, 0x85, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa4, 0x65,
+    0x6c, 0x73, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa6, 0x73, 0x63,
+    0x61, 0x6c, 0x61, 0x72, 0x84, 0xa4, 0x6b, 0x69, 0x6e, 0x64, 0x04, 0xa4, 0x6e, 0x61, 0x6d, 0x65,
+    0xa4, 0x65, 0x6c, 0x73, 0x65, 0xa8, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0xc3, 0xa4,
+    0x74, 0x79, 0x70, 0x65, 0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65,
+    0xa6, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0xa4, 0x74, 0x79, 0x70, 0x65, 0xa4, 0x65, 0x6c, 0x73,
+    0x65, 0xa7, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0xa3, 0x30, 0x2e, 0x31,
+];
+
+#[no_mangle]
+pub extern "C" fn _wrap_abi() -> u32 {
+    abi::wrap_abi(ABI)
+}
+
+#[no_mangle]
+pub extern "C" fn _wrap_abi_len() -> u32 {
+    ABI.len() as u32
+}
+
 #[no_mangle]
 pub extern "C" fn _wrap_invoke(method_size: u32, args_size: u32, env_size: u32) -> bool {
     // Ensure the abort handler is properly setup
@@ -18,10 +1321,22 @@ pub extern "C" fn _wrap_invoke(method_size: u32, args_size: u32, env_size: u32)
     let args: InvokeArgs = invoke::wrap_invoke_args(method_size, args_size);
 
     match args.method.as_str() {
-        "moduleMethod" => invoke::wrap_invoke(args, env_size, Some(module_method_wrapped)),
-        "objectMethod" => invoke::wrap_invoke(args, env_size, Some(object_method_wrapped)),
-        "optionalEnvMethod" => invoke::wrap_invoke(args, env_size, Some(optional_env_method_wrapped)),
-        "if" => invoke::wrap_invoke(args, env_size, Some(if_wrapped)),
+        "moduleMethod" => invoke::wrap_invoke(args, env_size, Some(|args, _env_size| dispatch("moduleMethod", args, module_method_wrapped))),
+        "objectMethod" => invoke::wrap_invoke(args, env_size, Some(|args, _env_size| dispatch("objectMethod", args, object_method_wrapped))),
+        "optionalEnvMethod" => invoke::wrap_invoke(args, env_size, Some(|args, _env_size| dispatch("optionalEnvMethod", args, optional_env_method_wrapped))),
+        "if" => invoke::wrap_invoke(args, env_size, Some(|args, _env_size| dispatch("if", args, if_wrapped))),
         _ => invoke::wrap_invoke(args, env_size, None),
     }
 }
+
+thread_local! {
+    /// The wrapper's interceptors, built on its first invocation
+    static INTERCEPTORS: Vec<Box<dyn Interceptor<crate::Env>>> = Wrapper.interceptors();
+}
+
+/// Run the wrapper's interceptors around the method `wrapped`
+fn dispatch(method: &str, args: &[u8], wrapped: fn(&Wrapper, &[u8]) -> Vec<u8>) -> Vec<u8> {
+    INTERCEPTORS.with(|interceptors| {
+        interceptor::wrap_intercept(interceptors, method, args, |args| wrapped(&Wrapper, args))
+    })
+}
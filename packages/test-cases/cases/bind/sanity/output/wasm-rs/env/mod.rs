@@ -8,6 +8,7 @@ use polywrap_wasm_rs::{
     EncodeError,
     Read,
     Write,
+    WrapEnv,
     JSON,
 };
 pub use serialization::{
@@ -49,3 +50,13 @@ impl Env {
         read_env(reader).map_err(|e| DecodeError::TypeReadError(e.to_string()))
     }
 }
+
+impl WrapEnv for Env {
+    fn read<R: Read>(reader: &mut R) -> Result<Env, DecodeError> {
+        read_env(reader)
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        write_env(self, writer)
+    }
+}
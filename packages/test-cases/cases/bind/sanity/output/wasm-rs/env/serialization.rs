@@ -83,10 +83,15 @@ pub fn read_env<R: Read>(reader: &mut R) -> Result<Env, DecodeError> {
                 })?;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_prop_set {
+        reader.context().push("prop", "String", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("prop: String.".to_string()));
     }
 
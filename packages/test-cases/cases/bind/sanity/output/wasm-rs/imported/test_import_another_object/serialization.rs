@@ -55,10 +55,15 @@ pub fn read_test_import_another_object<R: Read>(reader: &mut R) -> Result<TestIm
                 _prop_set = true;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_prop_set {
+        reader.context().push("prop", "String", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("prop: String.".to_string()));
     }
 
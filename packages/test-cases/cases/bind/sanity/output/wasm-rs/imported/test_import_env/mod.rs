@@ -8,6 +8,7 @@ use polywrap_wasm_rs::{
     EncodeError,
     Read,
     Write,
+    WrapEnv,
     JSON,
 };
 pub use serialization::{
@@ -47,3 +48,13 @@ impl TestImportEnv {
         read_test_import_env(reader).map_err(|e| DecodeError::TypeReadError(e.to_string()))
     }
 }
+
+impl WrapEnv for TestImportEnv {
+    fn read<R: Read>(reader: &mut R) -> Result<TestImportEnv, DecodeError> {
+        read_test_import_env(reader)
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        write_test_import_env(self, writer)
+    }
+}
@@ -55,10 +55,15 @@ pub fn read_test_import_env<R: Read>(reader: &mut R) -> Result<TestImportEnv, De
                 _enviro_prop_set = true;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_enviro_prop_set {
+        reader.context().push("enviroProp", "String", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("enviroProp: String.".to_string()));
     }
 
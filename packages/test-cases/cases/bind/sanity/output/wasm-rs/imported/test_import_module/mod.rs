@@ -6,6 +6,9 @@ use polywrap_wasm_rs::{
     Read,
     Write,
     JSON,
+    Retry,
+    SubinvokeOptions,
+    WrapEnv,
     subinvoke,
 };
 pub mod serialization;
@@ -21,35 +24,120 @@ pub use serialization::{
 use crate::TestImportObject;
 use crate::TestImportEnum;
 
+pub trait TestImportModuleApi {
+    fn imported_method(&self, args: &ArgsImportedMethod) -> Result<Option<TestImportObject>, String>;
+
+    /// `imported_method`, invoked with `env` instead of the env the client has for the module
+    fn imported_method_with_env<E: WrapEnv>(&self, args: &ArgsImportedMethod, env: &E) -> Result<Option<TestImportObject>, String>
+    where
+        Self: Sized;
+
+    fn another_method(&self, args: &ArgsAnotherMethod) -> Result<i32, String>;
+
+    /// `another_method`, invoked with `env` instead of the env the client has for the module
+    fn another_method_with_env<E: WrapEnv>(&self, args: &ArgsAnotherMethod, env: &E) -> Result<i32, String>
+    where
+        Self: Sized;
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct TestImportModule {}
+pub struct TestImportModule {
+    uri: String,
+    /// Methods whose results are reused within an invocation
+    memoized: Vec<String>,
+    #[serde(skip)]
+    retry: Option<Retry>,
+}
+
+impl Default for TestImportModule {
+    fn default() -> TestImportModule {
+        TestImportModule::with_uri(TestImportModule::URI)
+    }
+}
 
 impl TestImportModule {
     pub const URI: &'static str = "testimport.uri.eth";
 
     pub fn new() -> TestImportModule {
-        TestImportModule {}
+        TestImportModule::default()
+    }
+
+    pub fn with_uri(uri: &str) -> TestImportModule {
+        TestImportModule {
+            uri: uri.to_string(),
+            memoized: vec![],
+            retry: None,
+        }
+    }
+
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Whether the results of `method`, as named in the schema, are
+    /// reused within an invocation
+    pub fn memoizes(&self, method: &str) -> bool {
+        self.memoized.iter().any(|memoized| memoized == method)
+    }
+
+    /// Reuse the results of `imported_method` within an invocation, for calls with
+    /// the same args. Only for methods without side effects.
+    pub fn memoize_imported_method(mut self) -> Self {
+        self.memoized.push("importedMethod".to_string());
+        self
+    }
+
+    /// Reuse the results of `another_method` within an invocation, for calls with
+    /// the same args. Only for methods without side effects.
+    pub fn memoize_another_method(mut self) -> Self {
+        self.memoized.push("anotherMethod".to_string());
+        self
+    }
+
+    /// Retry failed invocations of the module's methods with `retry`
+    pub fn with_retry(mut self, retry: Retry) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Subinvoke `method`, memoized and retried as configured
+    fn invoke_method(&self, method: &str, args: Vec<u8>, options: Option<SubinvokeOptions>) -> Result<Vec<u8>, String> {
+        let attempt = |uri: &str| match &options {
+            Some(options) => subinvoke::wrap_subinvoke_with_options(uri, method, args.clone(), options),
+            None if self.memoizes(method) => subinvoke::wrap_subinvoke_memoized(uri, method, args.clone()),
+            None => subinvoke::wrap_subinvoke(uri, method, args.clone()),
+        };
+        match &self.retry {
+            Some(retry) => retry.run(&self.uri, method, attempt),
+            None => attempt(&self.uri),
+        }
+    }
+}
+
+impl TestImportModuleApi for TestImportModule {
+    fn imported_method(&self, args: &ArgsImportedMethod) -> Result<Option<TestImportObject>, String> {
+        let args = serialize_imported_method_args(args).map_err(|e| e.to_string())?;
+        let result = self.invoke_method("importedMethod", args, None)?;
+        deserialize_imported_method_result(result.as_slice()).map_err(|e| e.to_string())
     }
 
-    pub fn imported_method(args: &ArgsImportedMethod) -> Result<Option<TestImportObject>, String> {
-        let uri = TestImportModule::URI;
+    fn imported_method_with_env<E: WrapEnv>(&self, args: &ArgsImportedMethod, env: &E) -> Result<Option<TestImportObject>, String> {
         let args = serialize_imported_method_args(args).map_err(|e| e.to_string())?;
-        let result = subinvoke::wrap_subinvoke(
-            uri,
-            "importedMethod",
-            args,
-        )?;
+        let options = SubinvokeOptions::with_env(env).map_err(|e| e.to_string())?;
+        let result = self.invoke_method("importedMethod", args, Some(options))?;
         deserialize_imported_method_result(result.as_slice()).map_err(|e| e.to_string())
     }
 
-    pub fn another_method(args: &ArgsAnotherMethod) -> Result<i32, String> {
-        let uri = TestImportModule::URI;
+    fn another_method(&self, args: &ArgsAnotherMethod) -> Result<i32, String> {
+        let args = serialize_another_method_args(args).map_err(|e| e.to_string())?;
+        let result = self.invoke_method("anotherMethod", args, None)?;
+        deserialize_another_method_result(result.as_slice()).map_err(|e| e.to_string())
+    }
+
+    fn another_method_with_env<E: WrapEnv>(&self, args: &ArgsAnotherMethod, env: &E) -> Result<i32, String> {
         let args = serialize_another_method_args(args).map_err(|e| e.to_string())?;
-        let result = subinvoke::wrap_subinvoke(
-            uri,
-            "anotherMethod",
-            args,
-        )?;
+        let options = SubinvokeOptions::with_env(env).map_err(|e| e.to_string())?;
+        let result = self.invoke_method("anotherMethod", args, Some(options))?;
         deserialize_another_method_result(result.as_slice()).map_err(|e| e.to_string())
     }
 }
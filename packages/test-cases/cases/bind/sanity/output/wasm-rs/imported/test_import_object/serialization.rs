@@ -212,19 +212,30 @@ pub fn read_test_import_object<R: Read>(reader: &mut R) -> Result<TestImportObje
                 })?;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_object_set {
+        reader.context().push("object", "TestImportAnotherObject", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("object: TestImport_AnotherObject.".to_string()));
     }
     if !_object_array_set {
+        reader.context().push("objectArray", "Vec<TestImportAnotherObject>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("objectArray: [TestImport_AnotherObject].".to_string()));
     }
     if !_en_set {
+        reader.context().push("en", "TestImportEnum", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("en: TestImport_Enum.".to_string()));
     }
     if !_enum_array_set {
+        reader.context().push("enumArray", "Vec<TestImportEnum>", "missing required property");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("enumArray: [TestImport_Enum].".to_string()));
     }
 
@@ -33,11 +33,15 @@ pub use imported::test_import_enum::{
     TestImportEnum
 };
 pub use imported::test_import_env::TestImportEnv;
-pub use imported::test_import_module::TestImportModule;
+pub use imported::test_import_module::{
+    TestImportModule,
+    TestImportModuleApi
+};
 pub mod test_import;
 pub use test_import::TestImport;
 pub mod module;
 pub use module::{
+    Module,
     deserialize_module_method_args,
     serialize_module_method_result,
     module_method_wrapped,
@@ -0,0 +1,26 @@
+use crate::{
+    ArgsModuleMethod,
+    ArgsObjectMethod,
+    ArgsOptionalEnvMethod,
+    ArgsIf
+};
+
+use crate::CustomEnum;
+use crate::AnotherType;
+use crate::Else;
+use polywrap_wasm_rs::Interceptor;
+
+/// The methods of the wrapper's schema, implemented by the `Wrapper`
+/// unit struct at the crate root, which `entry.rs` dispatches into.
+pub trait Module {
+    fn module_method(&self, args: ArgsModuleMethod) -> i32;
+    fn object_method(&self, args: ArgsObjectMethod, env: crate::Env) -> Option<AnotherType>;
+    fn optional_env_method(&self, args: ArgsOptionalEnvMethod, env: Option<crate::Env>) -> Option<AnotherType>;
+    fn _if(&self, args: ArgsIf) -> Else;
+
+    /// Interceptors run around every method, outermost first. They're
+    /// built once, on the first invocation.
+    fn interceptors(&self) -> Vec<Box<dyn Interceptor<crate::Env>>> {
+        vec![]
+    }
+}
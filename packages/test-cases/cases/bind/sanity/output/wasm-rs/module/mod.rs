@@ -1,3 +1,5 @@
+pub mod methods;
+pub use methods::Module;
 pub mod wrapped;
 pub use wrapped::{
     module_method_wrapped,
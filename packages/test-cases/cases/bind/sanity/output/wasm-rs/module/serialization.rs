@@ -202,31 +202,50 @@ pub fn deserialize_module_method_args(args: &[u8]) -> Result<ArgsModuleMethod, D
                 _map_of_arr_of_obj_set = true;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_str_set {
+        reader.context().push("str", "String", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("str: String.".to_string()));
     }
     if !_en_set {
+        reader.context().push("en", "CustomEnum", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("en: CustomEnum.".to_string()));
     }
     if !_enum_array_set {
+        reader.context().push("enumArray", "Vec<CustomEnum>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("enumArray: [CustomEnum].".to_string()));
     }
     if !_map_set {
+        reader.context().push("map", "Map<String, i32>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("map: Map<String, Int>.".to_string()));
     }
     if !_map_of_arr_set {
+        reader.context().push("mapOfArr", "Map<String, Vec<i32>>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapOfArr: Map<String, [Int]>.".to_string()));
     }
     if !_map_of_map_set {
+        reader.context().push("mapOfMap", "Map<String, Map<String, i32>>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapOfMap: Map<String, Map<String, Int>>.".to_string()));
     }
     if !_map_of_obj_set {
+        reader.context().push("mapOfObj", "Map<String, AnotherType>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapOfObj: Map<String, AnotherType>.".to_string()));
     }
     if !_map_of_arr_of_obj_set {
+        reader.context().push("mapOfArrOfObj", "Map<String, Vec<AnotherType>>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("mapOfArrOfObj: Map<String, [AnotherType]>.".to_string()));
     }
 
@@ -327,13 +346,20 @@ pub fn deserialize_object_method_args(args: &[u8]) -> Result<ArgsObjectMethod, D
                 })?;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_object_set {
+        reader.context().push("object", "AnotherType", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("object: AnotherType.".to_string()));
     }
     if !_object_array_set {
+        reader.context().push("objectArray", "Vec<AnotherType>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("objectArray: [AnotherType].".to_string()));
     }
 
@@ -431,13 +457,20 @@ pub fn deserialize_optional_env_method_args(args: &[u8]) -> Result<ArgsOptionalE
                 })?;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_object_set {
+        reader.context().push("object", "AnotherType", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("object: AnotherType.".to_string()));
     }
     if !_object_array_set {
+        reader.context().push("objectArray", "Vec<AnotherType>", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("objectArray: [AnotherType].".to_string()));
     }
 
@@ -495,10 +528,15 @@ pub fn deserialize_if_args(args: &[u8]) -> Result<ArgsIf, DecodeError> {
                 _if_set = true;
                 reader.context().pop();
             }
-            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
         }
     }
     if !_if_set {
+        reader.context().push("if", "Else", "missing required argument");
+        reader.context().record_error();
         return Err(DecodeError::MissingField("if: else.".to_string()));
     }
 
@@ -1,21 +1,19 @@
 use polywrap_wasm_rs::{
-  wrap_load_env
+  wrap_env,
+  AbortReport
 };
 
 use crate::{
-    module_method,
+    Module,
     ArgsModuleMethod,
     deserialize_module_method_args,
     serialize_module_method_result,
-    object_method,
     ArgsObjectMethod,
     deserialize_object_method_args,
     serialize_object_method_result,
-    optional_env_method,
     ArgsOptionalEnvMethod,
     deserialize_optional_env_method_args,
     serialize_optional_env_method_result,
-    _if,
     ArgsIf,
     deserialize_if_args,
     serialize_if_result
@@ -23,10 +21,10 @@ use crate::{
 
 use crate::Env;
 
-pub fn module_method_wrapped(args: &[u8], env_size: u32) -> Vec<u8> {
+pub fn module_method_wrapped<M: Module>(module: &M, args: &[u8]) -> Vec<u8> {
     match deserialize_module_method_args(args) {
         Ok(args) => {
-            let result = module_method(ArgsModuleMethod {
+            let result = module.module_method(ArgsModuleMethod {
                 str: args.str,
                 opt_str: args.opt_str,
                 en: args.en,
@@ -39,72 +37,58 @@ pub fn module_method_wrapped(args: &[u8], env_size: u32) -> Vec<u8> {
                 map_of_obj: args.map_of_obj,
                 map_of_arr_of_obj: args.map_of_arr_of_obj,
             });
-            serialize_module_method_result(&result).unwrap()
-        }
-        Err(e) => {
-            panic!("{}", e.to_string())
+            serialize_module_method_result(&result).unwrap_or_else(|e| std::panic::panic_any(AbortReport::from_error(&e)))
         }
+        Err(e) => std::panic::panic_any(AbortReport::from_error(&e)),
     }
 }
 
-pub fn object_method_wrapped(args: &[u8], env_size: u32) -> Vec<u8> {
-    if env_size == 0 {
-        panic!("Environment is not set, and it is required by method 'objectMethod'");
-    }
-
-    let env_buf = wrap_load_env(env_size);
-    let env = Env::from_buffer(&env_buf).unwrap();
+pub fn object_method_wrapped<M: Module>(module: &M, args: &[u8]) -> Vec<u8> {
+    let env = match wrap_env::<Env>() {
+        Ok(Some(env)) => env,
+        Ok(None) => panic!("Environment is not set, and it is required by method 'objectMethod'"),
+        Err(e) => std::panic::panic_any(AbortReport::from_error(&e)),
+    };
 
     match deserialize_object_method_args(args) {
         Ok(args) => {
-            let result = object_method(ArgsObjectMethod {
+            let result = module.object_method(ArgsObjectMethod {
                 object: args.object,
                 opt_object: args.opt_object,
                 object_array: args.object_array,
                 opt_object_array: args.opt_object_array,
             }, env);
-            serialize_object_method_result(&result).unwrap()
-        }
-        Err(e) => {
-            panic!("{}", e.to_string())
+            serialize_object_method_result(&result).unwrap_or_else(|e| std::panic::panic_any(AbortReport::from_error(&e)))
         }
+        Err(e) => std::panic::panic_any(AbortReport::from_error(&e)),
     }
 }
 
-pub fn optional_env_method_wrapped(args: &[u8], env_size: u32) -> Vec<u8> {
-    let mut env: Option<Env> = None;
-
-    if env_size > 0 {
-      let env_buf = wrap_load_env(env_size);
-      env = Some(Env::from_buffer(&env_buf).unwrap());
-    }
+pub fn optional_env_method_wrapped<M: Module>(module: &M, args: &[u8]) -> Vec<u8> {
+    let env = wrap_env::<Env>().unwrap_or_else(|e| std::panic::panic_any(AbortReport::from_error(&e)));
 
     match deserialize_optional_env_method_args(args) {
         Ok(args) => {
-            let result = optional_env_method(ArgsOptionalEnvMethod {
+            let result = module.optional_env_method(ArgsOptionalEnvMethod {
                 object: args.object,
                 opt_object: args.opt_object,
                 object_array: args.object_array,
                 opt_object_array: args.opt_object_array,
             }, env);
-            serialize_optional_env_method_result(&result).unwrap()
-        }
-        Err(e) => {
-            panic!("{}", e.to_string())
+            serialize_optional_env_method_result(&result).unwrap_or_else(|e| std::panic::panic_any(AbortReport::from_error(&e)))
         }
+        Err(e) => std::panic::panic_any(AbortReport::from_error(&e)),
     }
 }
 
-pub fn if_wrapped(args: &[u8], env_size: u32) -> Vec<u8> {
+pub fn if_wrapped<M: Module>(module: &M, args: &[u8]) -> Vec<u8> {
     match deserialize_if_args(args) {
         Ok(args) => {
-            let result = _if(ArgsIf {
+            let result = module._if(ArgsIf {
                 _if: args._if,
             });
-            serialize_if_result(&result).unwrap()
-        }
-        Err(e) => {
-            panic!("{}", e.to_string())
+            serialize_if_result(&result).unwrap_or_else(|e| std::panic::panic_any(AbortReport::from_error(&e)))
         }
+        Err(e) => std::panic::panic_any(AbortReport::from_error(&e)),
     }
 }
@@ -5,7 +5,7 @@ pub struct TestImport {}
 impl TestImport {
   const uri: &'static str = "testimport.uri.eth";
 
-  pub fn get_implementations() -> Vec<String> {
+  pub fn get_implementations() -> Result<Vec<String>, String> {
     wrap_get_implementations(Self::uri)
   }
 }
\ No newline at end of file
@@ -4,15 +4,20 @@ use polywrap_wasm_rs::BigNumber;
 pub mod wrap;
 pub use wrap::*;
 
-pub fn method(args: ArgsMethod) -> BigNumber {
-    let mut result = args.arg1.mul(args.obj.prop1);
+#[derive(Default)]
+pub struct Wrapper;
 
-    if args.arg2.is_some() {
-        result = result.mul(args.arg2.unwrap());
-    }
-    if args.obj.prop2.is_some() {
-        result = result.mul(args.obj.prop2.unwrap());
-    }
+impl Module for Wrapper {
+    fn method(&self, args: ArgsMethod) -> BigNumber {
+        let mut result = args.arg1.mul(args.obj.prop1);
 
-    result
+        if args.arg2.is_some() {
+            result = result.mul(args.arg2.unwrap());
+        }
+        if args.obj.prop2.is_some() {
+            result = result.mul(args.obj.prop2.unwrap());
+        }
+
+        result
+    }
 }
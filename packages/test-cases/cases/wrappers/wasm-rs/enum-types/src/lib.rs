@@ -1,10 +1,15 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn method1(args: ArgsMethod1) -> SanityEnum {
-    args.en
-}
+#[derive(Default)]
+pub struct Wrapper;
+
+impl Module for Wrapper {
+    fn method1(&self, args: ArgsMethod1) -> SanityEnum {
+        args.en
+    }
 
-pub fn method2(args: ArgsMethod2) -> Vec<SanityEnum> {
-    args.enum_array
+    fn method2(&self, args: ArgsMethod2) -> Vec<SanityEnum> {
+        args.enum_array
+    }
 }
@@ -1,6 +1,11 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn external_env_method(_: ArgsExternalEnvMethod, env: Env) -> Env {
-  env
+#[derive(Default)]
+pub struct Wrapper;
+
+impl Module for Wrapper {
+    fn external_env_method(&self, _: ArgsExternalEnvMethod, env: Env) -> Env {
+      env
+    }
 }
@@ -18,26 +18,31 @@ fn create_env(env: Env) -> Env {
   }
 }
 
-pub fn method_no_env(args: ArgsMethodNoEnv) -> String {
-  args.arg
-}
+#[derive(Default)]
+pub struct Wrapper;
 
-pub fn method_require_env(_: ArgsMethodRequireEnv, env: Env) -> Env {
-  create_env(env)
-}
+impl Module for Wrapper {
+    fn method_no_env(&self, args: ArgsMethodNoEnv) -> String {
+      args.arg
+    }
 
-pub fn method_optional_env(args: ArgsMethodOptionalEnv, env: Option<Env>) -> Option<Env> {
-  match env {
-    Some(e) => Some(create_env(e)),
-    None => None
-  }
-}
+    fn method_require_env(&self, _: ArgsMethodRequireEnv, env: Env) -> Env {
+      create_env(env)
+    }
+
+    fn method_optional_env(&self, args: ArgsMethodOptionalEnv, env: Option<Env>) -> Option<Env> {
+      match env {
+        Some(e) => Some(create_env(e)),
+        None => None
+      }
+    }
 
-pub fn subinvoke_env_method(args: ArgsSubinvokeEnvMethod, env: Env) -> CompoundEnv {
-  let external_env: ExternalEnvApiEnv = ExternalEnvApiModule::external_env_method(&(imported::ArgsExternalEnvMethod {})).unwrap();
-  
-  return CompoundEnv {
-    local: env,
-    external: external_env
-  };
+    fn subinvoke_env_method(&self, args: ArgsSubinvokeEnvMethod, env: Env) -> CompoundEnv {
+      let external_env: ExternalEnvApiEnv = ExternalEnvApiModule::new().external_env_method(&(imported::ArgsExternalEnvMethod {})).unwrap();
+      
+      return CompoundEnv {
+        local: env,
+        external: external_env
+      };
+    }
 }
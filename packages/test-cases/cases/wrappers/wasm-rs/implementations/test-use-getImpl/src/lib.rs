@@ -1,14 +1,19 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn module_implementations(args: ArgsModuleImplementations) -> Vec<String> {
-  Interface::get_implementations()
-}
+#[derive(Default)]
+pub struct Wrapper;
 
-pub fn module_method(args: ArgsModuleMethod) -> ImplementationType {
-    args.arg
-}
+impl Module for Wrapper {
+    fn module_implementations(&self, args: ArgsModuleImplementations) -> Vec<String> {
+      Interface::get_implementations().unwrap()
+    }
+
+    fn module_method(&self, args: ArgsModuleMethod) -> ImplementationType {
+        args.arg
+    }
 
-pub fn abstract_module_method(args: ArgsAbstractModuleMethod) -> String {
-  args.arg.str
+    fn abstract_module_method(&self, args: ArgsAbstractModuleMethod) -> String {
+      args.arg.str
+    }
 }
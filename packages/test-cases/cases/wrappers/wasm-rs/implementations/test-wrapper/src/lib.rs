@@ -1,10 +1,15 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn module_method(args: ArgsModuleMethod) -> ImplementationType {
-    args.arg
-}
+#[derive(Default)]
+pub struct Wrapper;
+
+impl Module for Wrapper {
+    fn module_method(&self, args: ArgsModuleMethod) -> ImplementationType {
+        args.arg
+    }
 
-pub fn abstract_module_method(args: ArgsAbstractModuleMethod) -> String {
-    args.arg.str
+    fn abstract_module_method(&self, args: ArgsAbstractModuleMethod) -> String {
+        args.arg.str
+    }
 }
@@ -1,22 +1,27 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn bool_method(args: ArgsBoolMethod) -> bool {
-    args.arg
-}
+#[derive(Default)]
+pub struct Wrapper;
 
-pub fn int_method(args: ArgsIntMethod) -> i32 {
-    args.arg
-}
+impl Module for Wrapper {
+    fn bool_method(&self, args: ArgsBoolMethod) -> bool {
+        args.arg
+    }
 
-pub fn u_int_method(args: ArgsUIntMethod) -> u32 {
-    args.arg
-}
+    fn int_method(&self, args: ArgsIntMethod) -> i32 {
+        args.arg
+    }
 
-pub fn bytes_method(args: ArgsBytesMethod) -> Vec<u8> {
-    args.arg
-}
+    fn u_int_method(&self, args: ArgsUIntMethod) -> u32 {
+        args.arg
+    }
+
+    fn bytes_method(&self, args: ArgsBytesMethod) -> Vec<u8> {
+        args.arg
+    }
 
-pub fn array_method(args: ArgsArrayMethod) -> Option<Vec<String>> {
-    Some(args.arg)
+    fn array_method(&self, args: ArgsArrayMethod) -> Option<Vec<String>> {
+        Some(args.arg)
+    }
 }
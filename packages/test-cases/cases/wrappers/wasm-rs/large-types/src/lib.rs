@@ -1,6 +1,11 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn method(args: ArgsMethod) -> LargeCollection {
-    args.large_collection
+#[derive(Default)]
+pub struct Wrapper;
+
+impl Module for Wrapper {
+    fn method(&self, args: ArgsMethod) -> LargeCollection {
+        args.large_collection
+    }
 }
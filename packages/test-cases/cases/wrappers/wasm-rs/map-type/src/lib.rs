@@ -2,18 +2,23 @@ pub mod wrap;
 pub use wrap::*;
 use polywrap_wasm_rs::Map;
 
-pub fn get_key(args: ArgsGetKey) -> i32 {
-    *args.foo.map.get(&args.key).unwrap()
-}
+#[derive(Default)]
+pub struct Wrapper;
 
-pub fn return_map(args: ArgsReturnMap) -> Map<String, i32> {
-    args.map
-}
+impl Module for Wrapper {
+    fn get_key(&self, args: ArgsGetKey) -> i32 {
+        *args.foo.map.get(&args.key).unwrap()
+    }
 
-pub fn return_custom_map(args: ArgsReturnCustomMap) -> CustomMap {
-    args.foo
-}
+    fn return_map(&self, args: ArgsReturnMap) -> Map<String, i32> {
+        args.map
+    }
+
+    fn return_custom_map(&self, args: ArgsReturnCustomMap) -> CustomMap {
+        args.foo
+    }
 
-pub fn return_nested_map(args: ArgsReturnNestedMap) -> Map<String, Map<String, i32>> {
-    args.foo
+    fn return_nested_map(&self, args: ArgsReturnNestedMap) -> Map<String, Map<String, i32>> {
+        args.foo
+    }
 }
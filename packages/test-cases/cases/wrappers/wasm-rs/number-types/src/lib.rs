@@ -1,26 +1,31 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn i8_method(args: ArgsI8Method) -> i8 {
-    args.first + args.second
-}
+#[derive(Default)]
+pub struct Wrapper;
 
-pub fn u8_method(args: ArgsU8Method) -> u8 {
-    args.first + args.second
-}
+impl Module for Wrapper {
+    fn i8_method(&self, args: ArgsI8Method) -> i8 {
+        args.first + args.second
+    }
 
-pub fn i16_method(args: ArgsI16Method) -> i16 {
-    args.first + args.second
-}
+    fn u8_method(&self, args: ArgsU8Method) -> u8 {
+        args.first + args.second
+    }
 
-pub fn u16_method(args: ArgsU16Method) -> u16 {
-    args.first + args.second
-}
+    fn i16_method(&self, args: ArgsI16Method) -> i16 {
+        args.first + args.second
+    }
 
-pub fn i32_method(args: ArgsI32Method) -> i32 {
-    args.first + args.second
-}
+    fn u16_method(&self, args: ArgsU16Method) -> u16 {
+        args.first + args.second
+    }
+
+    fn i32_method(&self, args: ArgsI32Method) -> i32 {
+        args.first + args.second
+    }
 
-pub fn u32_method(args: ArgsU32Method) -> u32 {
-    args.first + args.second
+    fn u32_method(&self, args: ArgsU32Method) -> u32 {
+        args.first + args.second
+    }
 }
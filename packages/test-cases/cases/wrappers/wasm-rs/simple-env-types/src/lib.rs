@@ -1,6 +1,11 @@
 pub mod wrap;
 pub use wrap::*;
 
-pub fn get_env(_: ArgsGetEnv, env: Option<Env>) -> Option<Env> {
-  env
+#[derive(Default)]
+pub struct Wrapper;
+
+impl Module for Wrapper {
+    fn get_env(&self, _: ArgsGetEnv, env: Option<Env>) -> Option<Env> {
+      env
+    }
 }
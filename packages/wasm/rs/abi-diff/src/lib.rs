@@ -0,0 +1,513 @@
+//! Compatibility checker for WRAP ABIs.
+//!
+//! [`diff_abis`] compares the ABI of a published wrapper with the ABI of
+//! its next version, and classifies every change by whether existing
+//! callers keep working. Callers are held to what the generated bindings
+//! do: decoders reject fields they don't know (`UnknownFieldName`) and
+//! fields they require but don't get (`MissingField`), and enums are
+//! sent by index.
+//!
+//! Whether a type change is compatible depends on the direction values
+//! travel. An argument may be widened (`UInt8` to `UInt32`) or made
+//! optional, a return value may be narrowed or made required. Objects and
+//! enums take the direction of every method using them, and changes to
+//! types used in both directions must be compatible both ways.
+//!
+//! Imported modules and interfaces aren't part of the wrapper's own API,
+//! and aren't compared.
+
+mod types;
+
+pub use types::{Position, Shape, Type};
+
+use polywrap_wasm_rs::wrap_manifest::{
+    EnumDefinition, MethodDefinition, ObjectDefinition, PropertyDefinition, WrapAbi,
+    WrapManifest,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Compatibility {
+    Compatible,
+    Breaking,
+}
+
+impl Compatibility {
+    /// Breaking if either is breaking
+    pub fn and(self, other: Compatibility) -> Compatibility {
+        if self == Compatibility::Breaking || other == Compatibility::Breaking {
+            Compatibility::Breaking
+        } else {
+            Compatibility::Compatible
+        }
+    }
+}
+
+impl From<bool> for Compatibility {
+    fn from(compatible: bool) -> Compatibility {
+        if compatible {
+            Compatibility::Compatible
+        } else {
+            Compatibility::Breaking
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    MethodAdded,
+    MethodRemoved,
+    ArgumentAdded,
+    ArgumentRemoved,
+    ArgumentTypeChanged,
+    ReturnTypeChanged,
+    EnvRequirementChanged,
+    ObjectAdded,
+    ObjectRemoved,
+    PropertyAdded,
+    PropertyRemoved,
+    PropertyTypeChanged,
+    EnumAdded,
+    EnumRemoved,
+    EnumConstantAdded,
+    EnumConstantRemoved,
+    EnumConstantMoved,
+}
+
+impl ChangeKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ChangeKind::MethodAdded => "method added",
+            ChangeKind::MethodRemoved => "method removed",
+            ChangeKind::ArgumentAdded => "argument added",
+            ChangeKind::ArgumentRemoved => "argument removed",
+            ChangeKind::ArgumentTypeChanged => "argument type changed",
+            ChangeKind::ReturnTypeChanged => "return type changed",
+            ChangeKind::EnvRequirementChanged => "env requirement changed",
+            ChangeKind::ObjectAdded => "object added",
+            ChangeKind::ObjectRemoved => "object removed",
+            ChangeKind::PropertyAdded => "property added",
+            ChangeKind::PropertyRemoved => "property removed",
+            ChangeKind::PropertyTypeChanged => "property type changed",
+            ChangeKind::EnumAdded => "enum added",
+            ChangeKind::EnumRemoved => "enum removed",
+            ChangeKind::EnumConstantAdded => "enum constant added",
+            ChangeKind::EnumConstantRemoved => "enum constant removed",
+            ChangeKind::EnumConstantMoved => "enum constant moved",
+        }
+    }
+}
+
+/// A single difference between two ABIs
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// What changed, e.g. `Module.method.argument` or `Object.property`
+    pub path: String,
+    pub compatibility: Compatibility,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let compatibility = match self.compatibility {
+            Compatibility::Compatible => "compatible",
+            Compatibility::Breaking => "BREAKING",
+        };
+        write!(f, "{}: {} ({})", compatibility, self.path, self.kind.description())?;
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, ": {} -> {}", old, new),
+            (None, Some(new)) => write!(f, ": {}", new),
+            (Some(old), None) => write!(f, ": {}", old),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct AbiDiff {
+    pub breaking: bool,
+    pub changes: Vec<Change>,
+}
+
+impl AbiDiff {
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| change.compatibility == Compatibility::Breaking)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("AbiDiff is always serializable")
+    }
+
+    fn push(
+        &mut self,
+        kind: ChangeKind,
+        path: String,
+        compatibility: Compatibility,
+        old: Option<String>,
+        new: Option<String>,
+    ) {
+        self.breaking |= compatibility == Compatibility::Breaking;
+        self.changes.push(Change {
+            kind,
+            path,
+            compatibility,
+            old,
+            new,
+        });
+    }
+}
+
+/// Compare the ABIs of two versions of a wrapper
+pub fn diff_manifests(old: &WrapManifest, new: &WrapManifest) -> AbiDiff {
+    diff_abis(&old.abi, &new.abi)
+}
+
+/// Compare the ABI of a wrapper (`old`) with the ABI of its next version
+pub fn diff_abis(old: &WrapAbi, new: &WrapAbi) -> AbiDiff {
+    let mut diff = AbiDiff::default();
+    let old_positions = positions(old);
+    let new_positions = positions(new);
+    let position_of = |name: &str| match (old_positions.get(name), new_positions.get(name)) {
+        (Some(old), Some(new)) => old.merge(*new),
+        (Some(position), None) | (None, Some(position)) => *position,
+        (None, None) => Position::Both,
+    };
+
+    for (name, _) in methods(old) {
+        if !methods(new).iter().any(|(n, _)| *n == name) {
+            diff.push(
+                ChangeKind::MethodRemoved,
+                format!("Module.{}", name),
+                Compatibility::Breaking,
+                None,
+                None,
+            );
+        }
+    }
+    for (name, new_method) in methods(new) {
+        let path = format!("Module.{}", name);
+        match methods(old).into_iter().find(|(n, _)| *n == name) {
+            Some((_, old_method)) => diff_method(&mut diff, &path, old_method, new_method),
+            None => diff.push(
+                ChangeKind::MethodAdded,
+                path,
+                Compatibility::Compatible,
+                None,
+                None,
+            ),
+        }
+    }
+
+    for (name, _) in objects(old) {
+        if !objects(new).iter().any(|(n, _)| *n == name) {
+            let used = old_positions.contains_key(name);
+            diff.push(
+                ChangeKind::ObjectRemoved,
+                name.to_string(),
+                Compatibility::from(!used),
+                None,
+                None,
+            );
+        }
+    }
+    for (name, new_object) in objects(new) {
+        match objects(old).into_iter().find(|(n, _)| *n == name) {
+            Some((_, old_object)) => {
+                diff_object(&mut diff, name, old_object, new_object, position_of(name))
+            }
+            None => diff.push(
+                ChangeKind::ObjectAdded,
+                name.to_string(),
+                Compatibility::Compatible,
+                None,
+                None,
+            ),
+        }
+    }
+
+    if old.env_type.is_some() || new.env_type.is_some() {
+        let no_env = ObjectDefinition::default();
+        let old_env = old.env_type.as_ref().unwrap_or(&no_env);
+        let new_env = new.env_type.as_ref().unwrap_or(&no_env);
+        diff_object(&mut diff, "Env", old_env, new_env, Position::Input);
+    }
+
+    for (name, _) in enums(old) {
+        if !enums(new).iter().any(|(n, _)| *n == name) {
+            let used = old_positions.contains_key(name);
+            diff.push(
+                ChangeKind::EnumRemoved,
+                name.to_string(),
+                Compatibility::from(!used),
+                None,
+                None,
+            );
+        }
+    }
+    for (name, new_enum) in enums(new) {
+        match enums(old).into_iter().find(|(n, _)| *n == name) {
+            Some((_, old_enum)) => diff_enum(&mut diff, name, old_enum, new_enum, position_of(name)),
+            None => diff.push(
+                ChangeKind::EnumAdded,
+                name.to_string(),
+                Compatibility::Compatible,
+                None,
+                None,
+            ),
+        }
+    }
+
+    diff
+}
+
+fn diff_method(diff: &mut AbiDiff, path: &str, old: &MethodDefinition, new: &MethodDefinition) {
+    diff_properties(
+        diff,
+        path,
+        &old.arguments,
+        &new.arguments,
+        Position::Input,
+        [
+            ChangeKind::ArgumentAdded,
+            ChangeKind::ArgumentRemoved,
+            ChangeKind::ArgumentTypeChanged,
+        ],
+    );
+
+    let old_return = Type::from_any(&old._return.any);
+    let new_return = Type::from_any(&new._return.any);
+    if old_return != new_return {
+        diff.push(
+            ChangeKind::ReturnTypeChanged,
+            format!("{}.return", path),
+            old_return.compatibility(&new_return, Position::Output),
+            Some(old_return.to_string()),
+            Some(new_return.to_string()),
+        );
+    }
+
+    let old_env = env_requirement(old);
+    let new_env = env_requirement(new);
+    if old_env != new_env {
+        diff.push(
+            ChangeKind::EnvRequirementChanged,
+            format!("{}.env", path),
+            Compatibility::from(new_env != "required"),
+            Some(old_env.to_string()),
+            Some(new_env.to_string()),
+        );
+    }
+}
+
+fn diff_object(
+    diff: &mut AbiDiff,
+    path: &str,
+    old: &ObjectDefinition,
+    new: &ObjectDefinition,
+    position: Position,
+) {
+    diff_properties(
+        diff,
+        path,
+        &old.properties,
+        &new.properties,
+        position,
+        [
+            ChangeKind::PropertyAdded,
+            ChangeKind::PropertyRemoved,
+            ChangeKind::PropertyTypeChanged,
+        ],
+    );
+}
+
+/// Compare the arguments of a method or the properties of an object.
+/// `kinds` are the added, removed and changed kinds to report.
+fn diff_properties(
+    diff: &mut AbiDiff,
+    path: &str,
+    old: &[PropertyDefinition],
+    new: &[PropertyDefinition],
+    position: Position,
+    kinds: [ChangeKind; 3],
+) {
+    let [added, removed, changed] = kinds;
+
+    for old_property in old {
+        let name = name(&old_property.any.generic.name);
+        let old_type = Type::from_any(&old_property.any);
+        match new.iter().find(|p| p.any.generic.name.as_deref() == Some(name)) {
+            Some(new_property) => {
+                let new_type = Type::from_any(&new_property.any);
+                if old_type != new_type {
+                    diff.push(
+                        changed,
+                        format!("{}.{}", path, name),
+                        old_type.compatibility(&new_type, position),
+                        Some(old_type.to_string()),
+                        Some(new_type.to_string()),
+                    );
+                }
+            }
+            None => {
+                // Callers still send it, and the wrapper rejects unknown fields.
+                // When only returned, callers only miss it if they require it.
+                let compatible = position == Position::Output && !old_type.required;
+                diff.push(
+                    removed,
+                    format!("{}.{}", path, name),
+                    Compatibility::from(compatible),
+                    Some(old_type.to_string()),
+                    None,
+                );
+            }
+        }
+    }
+
+    for new_property in new {
+        let name = name(&new_property.any.generic.name);
+        if old.iter().any(|p| p.any.generic.name.as_deref() == Some(name)) {
+            continue;
+        }
+        // Callers don't send it, and can't decode it if it's returned
+        let new_type = Type::from_any(&new_property.any);
+        let compatible = position == Position::Input && !new_type.required;
+        diff.push(
+            added,
+            format!("{}.{}", path, name),
+            Compatibility::from(compatible),
+            None,
+            Some(new_type.to_string()),
+        );
+    }
+}
+
+fn diff_enum(
+    diff: &mut AbiDiff,
+    path: &str,
+    old: &EnumDefinition,
+    new: &EnumDefinition,
+    position: Position,
+) {
+    for (index, constant) in old.constants.iter().enumerate() {
+        let path = format!("{}.{}", path, constant);
+        match new.constants.iter().position(|c| c == constant) {
+            // Enums are sent by index
+            Some(new_index) if new_index != index => diff.push(
+                ChangeKind::EnumConstantMoved,
+                path,
+                Compatibility::Breaking,
+                Some(index.to_string()),
+                Some(new_index.to_string()),
+            ),
+            Some(_) => {}
+            None => diff.push(
+                ChangeKind::EnumConstantRemoved,
+                path,
+                Compatibility::from(position == Position::Output),
+                Some(index.to_string()),
+                None,
+            ),
+        }
+    }
+
+    for (index, constant) in new.constants.iter().enumerate() {
+        if !old.constants.contains(constant) {
+            diff.push(
+                ChangeKind::EnumConstantAdded,
+                format!("{}.{}", path, constant),
+                Compatibility::from(position == Position::Input),
+                None,
+                Some(index.to_string()),
+            );
+        }
+    }
+}
+
+fn methods(abi: &WrapAbi) -> Vec<(&str, &MethodDefinition)> {
+    abi.module_type
+        .iter()
+        .flat_map(|module| &module.methods)
+        .map(|method| (name(&method.generic.name), method))
+        .collect()
+}
+
+fn objects(abi: &WrapAbi) -> Vec<(&str, &ObjectDefinition)> {
+    abi.object_types
+        .iter()
+        .map(|object| (object.generic._type.as_str(), object))
+        .collect()
+}
+
+fn enums(abi: &WrapAbi) -> Vec<(&str, &EnumDefinition)> {
+    abi.enum_types
+        .iter()
+        .map(|_enum| (_enum.generic._type.as_str(), _enum))
+        .collect()
+}
+
+fn name(name: &Option<String>) -> &str {
+    name.as_deref().unwrap_or_default()
+}
+
+fn env_requirement(method: &MethodDefinition) -> &'static str {
+    match &method.env {
+        Some(env) if env.required == Some(true) => "required",
+        Some(_) => "optional",
+        None => "none",
+    }
+}
+
+/// Direction of every object and enum reachable from the module's methods
+fn positions(abi: &WrapAbi) -> HashMap<String, Position> {
+    let mut positions: HashMap<String, Position> = HashMap::new();
+    let mut pending: Vec<(Type, Position)> = vec![];
+
+    if let Some(module) = &abi.module_type {
+        for method in &module.methods {
+            for argument in &method.arguments {
+                pending.push((Type::from_any(&argument.any), Position::Input));
+            }
+            pending.push((Type::from_any(&method._return.any), Position::Output));
+        }
+    }
+    if let Some(env) = &abi.env_type {
+        for property in &env.properties {
+            pending.push((Type::from_any(&property.any), Position::Input));
+        }
+    }
+
+    while let Some((ty, position)) = pending.pop() {
+        match ty.shape {
+            Shape::Array(item) => pending.push((*item, position)),
+            Shape::Map(key, value) => {
+                pending.push((*key, position));
+                pending.push((*value, position));
+            }
+            Shape::Object(name) | Shape::Enum(name) => {
+                let merged = match positions.get(&name) {
+                    Some(existing) if existing.merge(position) == *existing => continue,
+                    Some(existing) => existing.merge(position),
+                    None => position,
+                };
+                positions.insert(name.clone(), merged);
+                if let Some(object) = abi.object_types.iter().find(|o| o.generic._type == name) {
+                    for property in &object.properties {
+                        pending.push((Type::from_any(&property.any), merged));
+                    }
+                }
+            }
+            Shape::Scalar(_) | Shape::Unknown(_) => {}
+        }
+    }
+    positions
+}
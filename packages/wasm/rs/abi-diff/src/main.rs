@@ -0,0 +1,57 @@
+//! `polywrap-abi-diff <old wrap.info> <new wrap.info> [--json]`
+//!
+//! Exits with 1 if the new ABI breaks callers of the old one, and with 2
+//! if the manifests can't be read.
+
+use polywrap_abi_diff::diff_manifests;
+use polywrap_wasm_rs::wrap_manifest::{deserialize_wrap_manifest, WrapManifest};
+use std::process::exit;
+
+const USAGE: &str = "Usage: polywrap-abi-diff <old wrap.info> <new wrap.info> [--json]";
+
+fn read_manifest(path: &str) -> Result<WrapManifest, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("Unable to read {}: {}", path, e))?;
+    deserialize_wrap_manifest(&bytes).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn main() {
+    let mut json = false;
+    let mut paths = vec![];
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ => paths.push(arg),
+        }
+    }
+    if paths.len() != 2 {
+        eprintln!("{}", USAGE);
+        exit(2);
+    }
+
+    let manifests = read_manifest(&paths[0]).and_then(|old| Ok((old, read_manifest(&paths[1])?)));
+    let (old, new) = match manifests {
+        Ok(manifests) => manifests,
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(2);
+        }
+    };
+
+    let diff = diff_manifests(&old, &new);
+    if json {
+        println!("{}", diff.to_json());
+    } else if diff.changes.is_empty() {
+        println!("No ABI changes");
+    } else {
+        for change in &diff.changes {
+            println!("{}", change);
+        }
+    }
+    if diff.breaking {
+        exit(1);
+    }
+}
@@ -0,0 +1,178 @@
+//! Structural view of ABI types, and the rules for changing them.
+
+use crate::Compatibility;
+use polywrap_wasm_rs::wrap_manifest::{
+    AnyDefinition, DefinitionKind, GenericDefinition, NestedDefinition,
+};
+use std::fmt;
+
+/// Which way values of a type travel between the caller and the wrapper
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Position {
+    /// Sent by the caller: arguments, and the env
+    Input,
+    /// Sent back by the wrapper: return values
+    Output,
+    /// Both, or not known
+    Both,
+}
+
+impl Position {
+    pub fn merge(self, other: Position) -> Position {
+        if self == other {
+            self
+        } else {
+            Position::Both
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Scalar(String),
+    Object(String),
+    Enum(String),
+    Array(Box<Type>),
+    Map(Box<Type>, Box<Type>),
+    Unknown(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Type {
+    pub shape: Shape,
+    pub required: bool,
+}
+
+impl Type {
+    pub fn from_any(any: &AnyDefinition) -> Type {
+        let shape = if let Some(array) = &any.array {
+            Shape::Array(Box::new(item_type(array.item.as_deref())))
+        } else if let Some(map) = &any.map {
+            Shape::Map(
+                Box::new(item_type(map.key.as_deref())),
+                Box::new(item_type(map.value.as_deref())),
+            )
+        } else if let Some(scalar) = &any.scalar {
+            Shape::Scalar(scalar._type.clone())
+        } else if let Some(object) = &any.object {
+            Shape::Object(object._type.clone())
+        } else if let Some(_enum) = &any._enum {
+            Shape::Enum(_enum._type.clone())
+        } else {
+            shape_of_kind(&any.generic)
+        };
+        Type {
+            shape,
+            required: any.generic.required.unwrap_or(false),
+        }
+    }
+
+    fn from_nested(nested: &NestedDefinition) -> Type {
+        let generic = &nested.any.generic;
+        if nested.item.is_some() || nested.key.is_some() {
+            let shape = match &nested.item {
+                Some(item) => Shape::Array(Box::new(Type::from_nested(item))),
+                None => Shape::Map(
+                    Box::new(item_type(nested.key.as_deref())),
+                    Box::new(item_type(nested.value.as_deref())),
+                ),
+            };
+            return Type {
+                shape,
+                required: generic.required.unwrap_or(false),
+            };
+        }
+
+        let mut ty = Type::from_any(&nested.any);
+        if let Shape::Unknown(_) = ty.shape {
+            ty.shape = shape_of_kind(generic);
+        }
+        ty
+    }
+
+    /// Whether values of `self` can be replaced by values of `new` when
+    /// sent in `position`
+    pub fn compatibility(&self, new: &Type, position: Position) -> Compatibility {
+        match position {
+            Position::Input => self.accepted_by(new),
+            Position::Output => new.accepted_by(self),
+            Position::Both => self.accepted_by(new).and(new.accepted_by(self)),
+        }
+    }
+
+    /// Whether every value of `self` is a valid value of `other`
+    fn accepted_by(&self, other: &Type) -> Compatibility {
+        if !self.required && other.required {
+            return Compatibility::Breaking;
+        }
+        match (&self.shape, &other.shape) {
+            (Shape::Scalar(a), Shape::Scalar(b)) => match (int_range(a), int_range(b)) {
+                (Some((a_min, a_max)), Some((b_min, b_max))) => {
+                    Compatibility::from(b_min <= a_min && a_max <= b_max)
+                }
+                _ => Compatibility::from(a == b),
+            },
+            (Shape::Object(a), Shape::Object(b)) | (Shape::Enum(a), Shape::Enum(b)) => {
+                Compatibility::from(a == b)
+            }
+            (Shape::Array(a), Shape::Array(b)) => a.accepted_by(b),
+            (Shape::Map(a_key, a_value), Shape::Map(b_key, b_value)) => {
+                a_key.accepted_by(b_key).and(a_value.accepted_by(b_value))
+            }
+            (a, b) => Compatibility::from(a == b),
+        }
+    }
+}
+
+fn item_type(item: Option<&NestedDefinition>) -> Type {
+    match item {
+        Some(item) => Type::from_nested(item),
+        None => Type {
+            shape: Shape::Unknown(String::new()),
+            required: false,
+        },
+    }
+}
+
+fn shape_of_kind(generic: &GenericDefinition) -> Shape {
+    let name = generic._type.clone();
+    if generic.is_kind(DefinitionKind::SCALAR) {
+        Shape::Scalar(name)
+    } else if generic.is_kind(DefinitionKind::OBJECT_REF) || generic.is_kind(DefinitionKind::OBJECT)
+    {
+        Shape::Object(name)
+    } else if generic.is_kind(DefinitionKind::ENUM_REF) || generic.is_kind(DefinitionKind::ENUM) {
+        Shape::Enum(name)
+    } else {
+        Shape::Unknown(name)
+    }
+}
+
+/// Range of values of integer scalars
+fn int_range(scalar: &str) -> Option<(i64, i64)> {
+    match scalar {
+        "UInt8" => Some((0, u8::MAX as i64)),
+        "UInt16" => Some((0, u16::MAX as i64)),
+        "UInt" | "UInt32" => Some((0, u32::MAX as i64)),
+        "Int8" => Some((i8::MIN as i64, i8::MAX as i64)),
+        "Int16" => Some((i16::MIN as i64, i16::MAX as i64)),
+        "Int" | "Int32" => Some((i32::MIN as i64, i32::MAX as i64)),
+        _ => None,
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.shape {
+            Shape::Scalar(name) | Shape::Object(name) | Shape::Enum(name) | Shape::Unknown(name) => {
+                write!(f, "{}", name)?
+            }
+            Shape::Array(item) => write!(f, "[{}]", item)?,
+            Shape::Map(key, value) => write!(f, "Map<{}, {}>", key, value)?,
+        }
+        if self.required {
+            write!(f, "!")?;
+        }
+        Ok(())
+    }
+}
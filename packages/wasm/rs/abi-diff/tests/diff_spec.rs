@@ -0,0 +1,246 @@
+use polywrap_abi_diff::{diff_abis, diff_manifests, ChangeKind, Compatibility};
+use polywrap_wasm_rs::wrap_manifest::{
+    deserialize_wrap_manifest, AnyDefinition, DefinitionKind, EnumDefinition, GenericDefinition,
+    MethodDefinition, ModuleDefinition, ObjectDefinition, PropertyDefinition, WrapAbi,
+};
+
+const ENS_WRAP_INFO: &[u8] = include_bytes!("../../../../js/test-env/src/wrappers/ens/wrap.info");
+
+fn property(name: &str, _type: &str, required: bool) -> PropertyDefinition {
+    let generic = GenericDefinition {
+        kind: DefinitionKind::PROPERTY,
+        _type: _type.to_string(),
+        name: Some(name.to_string()),
+        required: Some(required),
+    };
+    let reference = GenericDefinition {
+        kind: 0,
+        _type: _type.to_string(),
+        name: Some(name.to_string()),
+        required: Some(required),
+    };
+    let mut any = AnyDefinition {
+        generic,
+        ..Default::default()
+    };
+    match _type {
+        "Object" => {
+            any.object = Some(GenericDefinition {
+                kind: DefinitionKind::OBJECT_REF,
+                ..reference
+            })
+        }
+        "Enum" => {
+            any._enum = Some(GenericDefinition {
+                kind: DefinitionKind::ENUM_REF,
+                ..reference
+            })
+        }
+        _ => {
+            any.scalar = Some(GenericDefinition {
+                kind: DefinitionKind::SCALAR,
+                ..reference
+            })
+        }
+    }
+    PropertyDefinition { any, comment: None }
+}
+
+fn method(name: &str, arguments: Vec<PropertyDefinition>, _return: PropertyDefinition) -> MethodDefinition {
+    MethodDefinition {
+        generic: GenericDefinition {
+            kind: DefinitionKind::METHOD,
+            _type: "Method".to_string(),
+            name: Some(name.to_string()),
+            required: Some(true),
+        },
+        arguments,
+        _return,
+        ..Default::default()
+    }
+}
+
+fn object(properties: Vec<PropertyDefinition>) -> ObjectDefinition {
+    ObjectDefinition {
+        generic: GenericDefinition {
+            kind: DefinitionKind::OBJECT,
+            _type: "Object".to_string(),
+            ..Default::default()
+        },
+        properties,
+        ..Default::default()
+    }
+}
+
+/// A module with `send(value, object, enum): Object!`
+fn abi(arguments: Vec<PropertyDefinition>, properties: Vec<PropertyDefinition>, constants: &[&str]) -> WrapAbi {
+    WrapAbi {
+        version: Some("0.1".to_string()),
+        module_type: Some(ModuleDefinition {
+            generic: GenericDefinition {
+                kind: DefinitionKind::MODULE,
+                _type: "Module".to_string(),
+                ..Default::default()
+            },
+            methods: vec![method("send", arguments, property("send", "Object", true))],
+            ..Default::default()
+        }),
+        object_types: vec![object(properties)],
+        enum_types: vec![EnumDefinition {
+            generic: GenericDefinition {
+                kind: DefinitionKind::ENUM,
+                _type: "Enum".to_string(),
+                ..Default::default()
+            },
+            comment: None,
+            constants: constants.iter().map(|c| c.to_string()).collect(),
+        }],
+        ..Default::default()
+    }
+}
+
+fn base_arguments() -> Vec<PropertyDefinition> {
+    vec![property("value", "UInt8", true), property("en", "Enum", true)]
+}
+
+fn base_properties() -> Vec<PropertyDefinition> {
+    vec![property("count", "UInt32", true)]
+}
+
+#[test]
+fn identical_abis_have_no_changes() {
+    let manifest = deserialize_wrap_manifest(ENS_WRAP_INFO).unwrap();
+    let diff = diff_manifests(&manifest, &manifest);
+    assert!(diff.changes.is_empty());
+    assert!(!diff.breaking);
+}
+
+#[test]
+fn method_changes() {
+    let old = deserialize_wrap_manifest(ENS_WRAP_INFO).unwrap();
+    let mut new = old.clone();
+    let module = new.abi.module_type.as_mut().unwrap();
+    let removed = module.methods.remove(0);
+    let removed_name = removed.generic.name.clone().unwrap();
+    let changed_name = module.methods[0].generic.name.clone().unwrap();
+    module.methods[0].arguments.push(property("extra", "String", false));
+
+    let diff = diff_manifests(&old, &new);
+    assert_eq!(diff.changes.len(), 2);
+    assert_eq!(diff.changes[0].kind, ChangeKind::MethodRemoved);
+    assert_eq!(diff.changes[0].path, format!("Module.{}", removed_name));
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Breaking);
+    assert_eq!(diff.changes[1].kind, ChangeKind::ArgumentAdded);
+    assert_eq!(diff.changes[1].path, format!("Module.{}.extra", changed_name));
+    assert_eq!(diff.changes[1].compatibility, Compatibility::Compatible);
+    assert!(diff.breaking);
+
+    // Adding a method back is compatible
+    let diff = diff_manifests(&new, &old);
+    let added = diff.changes.iter().find(|c| c.kind == ChangeKind::MethodAdded).unwrap();
+    assert_eq!(added.compatibility, Compatibility::Compatible);
+}
+
+#[test]
+fn argument_changes() {
+    let old = abi(base_arguments(), base_properties(), &["A", "B"]);
+
+    let mut arguments = base_arguments();
+    arguments.push(property("required", "String", true));
+    let diff = diff_abis(&old, &abi(arguments, base_properties(), &["A", "B"]));
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].kind, ChangeKind::ArgumentAdded);
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Breaking);
+
+    let diff = diff_abis(&old, &abi(base_arguments()[1..].to_vec(), base_properties(), &["A", "B"]));
+    assert_eq!(diff.changes[0].kind, ChangeKind::ArgumentRemoved);
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Breaking);
+
+    // Arguments may be widened or made optional, but not narrowed
+    let mut arguments = base_arguments();
+    arguments[0] = property("value", "UInt32", false);
+    let widened = abi(arguments, base_properties(), &["A", "B"]);
+    let diff = diff_abis(&old, &widened);
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].kind, ChangeKind::ArgumentTypeChanged);
+    assert_eq!(diff.changes[0].path, "Module.send.value");
+    assert_eq!(diff.changes[0].old.as_deref(), Some("UInt8!"));
+    assert_eq!(diff.changes[0].new.as_deref(), Some("UInt32"));
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Compatible);
+
+    let diff = diff_abis(&widened, &old);
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Breaking);
+
+    let mut arguments = base_arguments();
+    arguments[0] = property("value", "Int8", true);
+    let diff = diff_abis(&old, &abi(arguments, base_properties(), &["A", "B"]));
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Breaking);
+}
+
+#[test]
+fn return_object_changes() {
+    let old = abi(base_arguments(), base_properties(), &["A", "B"]);
+
+    // Returned objects may be narrowed, but callers reject new fields
+    let narrowed = abi(base_arguments(), vec![property("count", "UInt16", true)], &["A", "B"]);
+    let diff = diff_abis(&old, &narrowed);
+    assert_eq!(diff.changes[0].kind, ChangeKind::PropertyTypeChanged);
+    assert_eq!(diff.changes[0].path, "Object.count");
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Compatible);
+
+    let mut properties = base_properties();
+    properties.push(property("extra", "String", false));
+    let diff = diff_abis(&old, &abi(base_arguments(), properties, &["A", "B"]));
+    assert_eq!(diff.changes[0].kind, ChangeKind::PropertyAdded);
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Breaking);
+
+    // Also sent as an argument, so changes have to work both ways
+    let mut arguments = base_arguments();
+    arguments.push(property("object", "Object", false));
+    let old = abi(arguments.clone(), base_properties(), &["A", "B"]);
+    let narrowed = abi(arguments, vec![property("count", "UInt16", true)], &["A", "B"]);
+    let diff = diff_abis(&old, &narrowed);
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Breaking);
+}
+
+#[test]
+fn enum_changes() {
+    let old = abi(base_arguments(), base_properties(), &["A", "B"]);
+
+    // Only sent as an argument, so new constants are fine
+    let diff = diff_abis(&old, &abi(base_arguments(), base_properties(), &["A", "B", "C"]));
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].kind, ChangeKind::EnumConstantAdded);
+    assert_eq!(diff.changes[0].compatibility, Compatibility::Compatible);
+
+    // Enums are sent by index
+    let diff = diff_abis(&old, &abi(base_arguments(), base_properties(), &["B"]));
+    assert_eq!(diff.changes.len(), 2);
+    assert_eq!(diff.changes[0].kind, ChangeKind::EnumConstantRemoved);
+    assert_eq!(diff.changes[0].path, "Enum.A");
+    assert_eq!(diff.changes[1].kind, ChangeKind::EnumConstantMoved);
+    assert!(diff.breaking);
+}
+
+#[test]
+fn serializes_to_json() {
+    let old = abi(base_arguments(), base_properties(), &["A", "B"]);
+    let mut arguments = base_arguments();
+    arguments[0] = property("value", "UInt32", true);
+    let diff = diff_abis(&old, &abi(arguments, base_properties(), &["A", "B"]));
+
+    let json: serde_json::Value = serde_json::from_str(&diff.to_json()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "breaking": false,
+            "changes": [{
+                "kind": "argumentTypeChanged",
+                "path": "Module.send.value",
+                "compatibility": "compatible",
+                "old": "UInt8!",
+                "new": "UInt32!"
+            }]
+        })
+    );
+}
@@ -0,0 +1,97 @@
+//! The ABI embedded into wrappers, for their `_wrap_abi` export.
+//!
+//! It's msgpack encoded like in `wrap.info`, but with the keys of maps
+//! sorted and without empty values (`nil`s and empty arrays), so the
+//! TypeScript bindings (`bindings/rust/wasm/abi.ts`) encode the same ABI
+//! into the same bytes.
+
+use crate::BindgenError;
+use polywrap_wasm_rs::msgpack::Format;
+use polywrap_wasm_rs::wrap_manifest::WrapAbi;
+use polywrap_wasm_rs::{Context, Read, ReadDecoder, Write, WriteEncoder};
+use serde_json::{Map, Value};
+
+/// The encoded ABI, as the lines of a byte array literal
+pub(crate) fn abi_buffer(abi: &WrapAbi) -> Result<String, BindgenError> {
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    WrapAbi::write(abi, &mut encoder).map_err(invalid)?;
+    let value = read(&mut ReadDecoder::new(&encoder.get_buffer(), Context::new()))?;
+
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    write(&value, &mut encoder)?;
+    let lines: Vec<String> = encoder
+        .get_buffer()
+        .chunks(16)
+        .map(|line| {
+            let bytes: Vec<String> = line.iter().map(|byte| format!("0x{:02x},", byte)).collect();
+            bytes.join(" ")
+        })
+        .collect();
+    Ok(lines.join("\n    "))
+}
+
+fn invalid(error: impl ToString) -> BindgenError {
+    BindgenError::Template(format!("Invalid ABI: {}", error.to_string()))
+}
+
+/// Read back a value of what `WrapAbi::write` wrote, which only writes
+/// maps, arrays, strings, booleans and unsigned integers
+fn read(reader: &mut ReadDecoder) -> Result<Value, BindgenError> {
+    let value = match reader.peek_format().map_err(invalid)? {
+        Format::FixMap(_) | Format::Map16 | Format::Map32 => {
+            let mut object = Map::new();
+            for _ in 0..reader.read_map_length().map_err(invalid)? {
+                let key = reader.read_string().map_err(invalid)?;
+                object.insert(key, read(reader)?);
+            }
+            Value::Object(object)
+        }
+        Format::FixArray(_) | Format::Array16 | Format::Array32 => {
+            let len = reader.read_array_length().map_err(invalid)?;
+            Value::Array((0..len).map(|_| read(reader)).collect::<Result<_, _>>()?)
+        }
+        Format::FixStr(_) | Format::Str8 | Format::Str16 | Format::Str32 => {
+            Value::String(reader.read_string().map_err(invalid)?)
+        }
+        Format::False | Format::True => Value::Bool(reader.read_bool().map_err(invalid)?),
+        Format::PositiveFixInt(_) | Format::Uint8 | Format::Uint16 | Format::Uint32 | Format::Uint64 => {
+            Value::from(reader.read_u64().map_err(invalid)?)
+        }
+        format => return Err(invalid(format!("unexpected format {:?}", format))),
+    };
+    Ok(value)
+}
+
+fn write(value: &Value, writer: &mut WriteEncoder) -> Result<(), BindgenError> {
+    match value {
+        Value::Null => writer.write_nil().map_err(invalid),
+        Value::Bool(value) => writer.write_bool(value).map_err(invalid),
+        Value::Number(number) => match number.as_u64() {
+            Some(number) => writer.write_u64(&number).map_err(invalid),
+            None => Err(invalid(format!("unexpected number {}", number))),
+        },
+        Value::String(value) => writer.write_string(value).map_err(invalid),
+        Value::Array(items) => {
+            writer.write_array_length(&(items.len() as u32)).map_err(invalid)?;
+            items.iter().try_for_each(|item| write(item, writer))
+        }
+        Value::Object(object) => {
+            let mut fields: Vec<(&String, &Value)> =
+                object.iter().filter(|(_, value)| !is_empty(value)).collect();
+            fields.sort_by_key(|(key, _)| *key);
+            writer.write_map_length(&(fields.len() as u32)).map_err(invalid)?;
+            fields.into_iter().try_for_each(|(key, value)| {
+                writer.write_string(key).map_err(invalid)?;
+                write(value, writer)
+            })
+        }
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
@@ -0,0 +1,270 @@
+//! Template functions, ported from `schema/bind/src/bindings/rust/functions.ts`.
+//! Both are tested against the cases of `test-cases/cases/functions/wasm-rs.json`.
+//!
+//! Each one is used as a section, e.g. `{{#toLower}}{{type}}{{/toLower}}`,
+//! and transforms the rendered content of the section.
+
+use crate::BindgenError;
+
+pub(crate) type Lambda = fn(&str) -> Result<String, BindgenError>;
+
+pub(crate) fn lambda(name: &str) -> Option<Lambda> {
+    let lambda: Lambda = match name {
+        "toLower" => |value| Ok(to_lower(value)),
+        "toUpper" => |value| Ok(to_upper(value)),
+        "toMsgPack" => to_msgpack,
+        "toWasmInit" => to_wasm_init,
+        "toWasm" => to_wasm,
+        "detectKeyword" => |value| Ok(detect_keyword(value)),
+        "toSchemaType" => |value| Ok(value.replace("Enum_", "")),
+        _ => return None,
+    };
+    Some(lambda)
+}
+
+/// `CustomType` -> `custom_type`
+pub(crate) fn to_lower(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 4);
+    for c in value.chars() {
+        let lower = c.to_ascii_lowercase();
+        if c != lower && !result.is_empty() && !result.ends_with('_') {
+            result.push('_');
+        }
+        result.push(lower);
+    }
+    result
+}
+
+/// `custom_type` -> `CustomType`
+pub(crate) fn to_upper(value: &str) -> String {
+    let mut chars: Vec<char> = value.chars().collect();
+    if let Some(first) = chars.first_mut() {
+        *first = first.to_ascii_uppercase();
+    }
+
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '_' {
+            if let Some(next) = chars.get_mut(i + 1) {
+                *next = next.to_ascii_uppercase();
+            }
+            chars.remove(i);
+        }
+        i += 1;
+    }
+    chars.into_iter().collect()
+}
+
+/// Prefix Rust keywords with `_`
+pub(crate) fn detect_keyword(value: &str) -> String {
+    if is_keyword(value) {
+        format!("_{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Split `Type!` into `(Type, optional)`
+fn split_optional(value: &str) -> (&str, bool) {
+    match value.strip_suffix('!') {
+        Some(value) => (value, false),
+        None => (value, true),
+    }
+}
+
+fn to_msgpack(value: &str) -> Result<String, BindgenError> {
+    let (ty, optional) = split_optional(value);
+    let modifier = if optional { "optional_" } else { "" };
+
+    if ty.starts_with('[') {
+        return Ok(format!("{}array", modifier));
+    }
+    if ty.starts_with("Map<") {
+        return Ok(format!("{}ext_generic_map", modifier));
+    }
+
+    let msgpack = match ty {
+        "Int" | "Int32" => "i32",
+        "Int8" => "i8",
+        "Int16" => "i16",
+        "Int64" => "i64",
+        "UInt" | "UInt32" => "u32",
+        "UInt8" => "u8",
+        "UInt16" => "u16",
+        "UInt64" => "u64",
+        "String" => "string",
+        "Boolean" => "bool",
+        "Bytes" => "bytes",
+        "BigInt" => "bigint",
+        "BigNumber" => "bignumber",
+        "JSON" => "json",
+        _ => return Err(BindgenError::Template(format!("Unknown toWasm type \"{}\"", ty))),
+    };
+    Ok(format!("{}{}", modifier, msgpack))
+}
+
+fn to_wasm_init(value: &str) -> Result<String, BindgenError> {
+    let (ty, optional) = split_optional(value);
+    if optional {
+        return Ok("None".to_string());
+    }
+
+    if ty.starts_with('[') {
+        return Ok("vec![]".to_string());
+    }
+    if ty.starts_with("Map<") {
+        let (key, value) = map_key_value(ty)?;
+        return Ok(format!("Map::<{}, {}>::new()", to_wasm(key)?, to_wasm(value)?));
+    }
+
+    let init = match ty {
+        "Int" | "Int8" | "Int16" | "Int32" | "Int64" | "UInt" | "UInt8" | "UInt16" | "UInt32"
+        | "UInt64" => "0".to_string(),
+        "String" => "String::new()".to_string(),
+        "Boolean" => "false".to_string(),
+        "Bytes" => "vec![]".to_string(),
+        "BigInt" => "BigInt::default()".to_string(),
+        "BigNumber" => "BigNumber::default()".to_string(),
+        "JSON" => "JSON::Value::Null".to_string(),
+        _ if ty.contains("Enum_") => format!("{}::_MAX_", to_wasm(value)?),
+        _ => format!("{}::new()", to_wasm(value)?),
+    };
+    Ok(init)
+}
+
+fn to_wasm(value: &str) -> Result<String, BindgenError> {
+    let (ty, optional) = split_optional(value);
+
+    let wasm = if ty.starts_with('[') {
+        format!("Vec<{}>", to_wasm(array_item(ty)?)?)
+    } else if ty.starts_with("Map<") {
+        let (key, value) = map_key_value(ty)?;
+        format!("Map<{}, {}>", to_wasm(key)?, to_wasm(value)?)
+    } else {
+        match ty {
+            "Int" | "Int32" => "i32".to_string(),
+            "Int8" => "i8".to_string(),
+            "Int16" => "i16".to_string(),
+            "Int64" => "i64".to_string(),
+            "UInt" | "UInt32" => "u32".to_string(),
+            "UInt8" => "u8".to_string(),
+            "UInt16" => "u16".to_string(),
+            "UInt64" => "u64".to_string(),
+            "String" => "String".to_string(),
+            "Boolean" => "bool".to_string(),
+            "Bytes" => "Vec<u8>".to_string(),
+            "BigInt" => "BigInt".to_string(),
+            "BigNumber" => "BigNumber".to_string(),
+            "JSON" => "JSON::Value".to_string(),
+            _ => detect_keyword(&to_upper(&ty.replacen("Enum_", "", 1))),
+        }
+    };
+
+    if optional {
+        Ok(format!("Option<{}>", wasm))
+    } else {
+        Ok(wasm)
+    }
+}
+
+/// Item type of `[Item]`, matched the same way as the TypeScript
+/// bindings (`/(\[)([[\]A-Za-z1-9_.!]+)(\])/`)
+fn array_item(ty: &str) -> Result<&str, BindgenError> {
+    let is_item_char = |c: u8| c.is_ascii_alphabetic() || b"[]123456789_.!".contains(&c);
+    let bytes = ty.as_bytes();
+
+    for start in (0..bytes.len()).filter(|i| bytes[*i] == b'[') {
+        let run = bytes[start + 1..]
+            .iter()
+            .take_while(|c| is_item_char(**c))
+            .count();
+        // The item is greedy, but has to be followed by a `]`
+        if let Some(len) = (1..=run)
+            .rev()
+            .find(|len| bytes.get(start + 1 + len) == Some(&b']'))
+        {
+            return Ok(&ty[start + 1..start + 1 + len]);
+        }
+    }
+    Err(BindgenError::Template(format!("Invalid Array: {}", ty)))
+}
+
+fn map_key_value(ty: &str) -> Result<(&str, &str), BindgenError> {
+    let invalid = || BindgenError::Template(format!("Invalid Map: {}", ty));
+    let open = ty.find('<').ok_or_else(invalid)?;
+    let close = ty.rfind('>').ok_or_else(invalid)?;
+    let key_value = ty.get(open + 1..close).ok_or_else(invalid)?;
+    let comma = key_value.find(',').ok_or_else(invalid)?;
+    Ok((key_value[..comma].trim(), key_value[comma + 1..].trim()))
+}
+
+pub(crate) fn is_base_type(ty: &str) -> bool {
+    matches!(
+        ty,
+        "UInt" | "UInt8" | "UInt16" | "UInt32" | "UInt64" | "Int" | "Int8" | "Int16" | "Int32"
+            | "Int64" | "String" | "Boolean" | "Bytes"
+    )
+}
+
+pub(crate) fn is_builtin_type(ty: &str) -> bool {
+    matches!(ty, "BigInt" | "BigNumber" | "JSON")
+}
+
+fn is_keyword(value: &str) -> bool {
+    matches!(
+        value,
+        "as" | "break"
+            | "const"
+            | "continue"
+            | "crate"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "self"
+            | "Self"
+            | "static"
+            | "struct"
+            | "super"
+            | "trait"
+            | "true"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+            | "async"
+            | "await"
+            | "dyn"
+            | "abstract"
+            | "become"
+            | "box"
+            | "Box"
+            | "do"
+            | "final"
+            | "macro"
+            | "override"
+            | "priv"
+            | "typeof"
+            | "unsized"
+            | "virtual"
+            | "yield"
+            | "try"
+            | "macro_rules"
+            | "union"
+    )
+}
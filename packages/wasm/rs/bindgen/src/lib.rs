@@ -0,0 +1,176 @@
+//! Generates the `wrap` module of Rust wrappers from their `wrap.info`,
+//! without the Node-based CLI.
+//!
+//! The module is rendered from the same templates as `polywrap codegen`,
+//! and is identical to what the CLI generates. It's meant to be run from
+//! a build script, in place of the CLI:
+//!
+//! ```no_run
+//! // In build.rs
+//! polywrap_bindgen::generate("wrap.info", "src/wrap").unwrap();
+//! println!("cargo:rerun-if-changed=wrap.info");
+//! ```
+
+mod abi;
+mod functions;
+mod mustache;
+mod templates;
+mod view;
+
+use functions::{detect_keyword, to_lower};
+use polywrap_wasm_rs::wrap_manifest::{deserialize_wrap_manifest, WrapAbi, WrapManifestError};
+use serde_json::Value;
+use std::path::Path;
+use templates::Templates;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BindgenError {
+    #[error("{0}")]
+    Manifest(#[from] WrapManifestError),
+
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unable to render template: {0}")]
+    Template(String),
+}
+
+/// A generated file or directory
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutputEntry {
+    File { name: String, data: String },
+    Directory { name: String, entries: Vec<OutputEntry> },
+}
+
+impl OutputEntry {
+    pub fn name(&self) -> &str {
+        match self {
+            OutputEntry::File { name, .. } | OutputEntry::Directory { name, .. } => name,
+        }
+    }
+}
+
+/// Render the files of a template directory. Files which render empty
+/// are left out.
+fn render(templates: &Templates, view: &Value) -> Result<Vec<OutputEntry>, BindgenError> {
+    let mut entries = vec![];
+    for (name, template) in templates {
+        let data = mustache::render(template, view, templates::PARTIALS)?;
+        if !data.is_empty() {
+            entries.push(OutputEntry::File {
+                name: name.to_string(),
+                data,
+            });
+        }
+    }
+    Ok(entries)
+}
+
+fn directory(name: String, templates: &Templates, view: &Value) -> Result<OutputEntry, BindgenError> {
+    Ok(OutputEntry::Directory {
+        name,
+        entries: render(templates, view)?,
+    })
+}
+
+/// The name of a type's directory
+fn type_name(view: &Value) -> String {
+    to_lower(view["type"].as_str().unwrap_or_default())
+}
+
+fn views(view: &Value) -> &[Value] {
+    view.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Apply the template function `name`, e.g. `toWasm`, to `value`, as the
+/// TypeScript bindings do. `None` if there is no such function.
+pub fn apply_template_function(name: &str, value: &str) -> Option<Result<String, BindgenError>> {
+    functions::lambda(name).map(|lambda| lambda(value))
+}
+
+/// Generate the `wrap` module of a wrapper
+pub fn generate_bindings(abi: &WrapAbi) -> Result<Vec<OutputEntry>, BindgenError> {
+    let abi_buffer = abi::abi_buffer(abi)?;
+    let mut abi = view::abi_view(abi);
+    let mut entries = vec![];
+
+    for object_type in views(&abi["objectTypes"]) {
+        let name = detect_keyword(&type_name(object_type));
+        entries.push(directory(name, templates::OBJECT_TYPE, object_type)?);
+    }
+
+    let env_type = &abi["envType"];
+    if !env_type.is_null() {
+        let name = detect_keyword(&type_name(env_type));
+        entries.push(directory(name, templates::ENV_TYPE, env_type)?);
+    }
+
+    let mut imports = vec![];
+    for module_type in views(&abi["importedModuleTypes"]) {
+        imports.push(directory(type_name(module_type), templates::IMPORTED_MODULE_TYPE, module_type)?);
+    }
+    for enum_type in views(&abi["importedEnumTypes"]) {
+        let name = detect_keyword(&type_name(enum_type));
+        imports.push(directory(name, templates::IMPORTED_ENUM_TYPE, enum_type)?);
+    }
+    for object_type in views(&abi["importedObjectTypes"]) {
+        let name = detect_keyword(&type_name(object_type));
+        imports.push(directory(name, templates::IMPORTED_OBJECT_TYPE, object_type)?);
+    }
+    for env_type in views(&abi["importedEnvTypes"]) {
+        let name = detect_keyword(&type_name(env_type));
+        imports.push(directory(name, templates::IMPORTED_ENV_TYPE, env_type)?);
+    }
+    if !imports.is_empty() {
+        imports.extend(render(templates::IMPORTED, &abi)?);
+        entries.push(OutputEntry::Directory {
+            name: "imported".to_string(),
+            entries: imports,
+        });
+    }
+
+    for interface_type in views(&abi["interfaceTypes"]) {
+        entries.push(directory(type_name(interface_type), templates::INTERFACE_TYPE, interface_type)?);
+    }
+
+    let module_type = &abi["moduleType"];
+    if !module_type.is_null() {
+        entries.push(directory(type_name(module_type), templates::MODULE_TYPE, &abi)?);
+    }
+
+    for enum_type in views(&abi["enumTypes"]) {
+        let name = detect_keyword(&type_name(enum_type));
+        entries.push(directory(name, templates::ENUM_TYPE, enum_type)?);
+    }
+
+    abi["abiBuffer"] = Value::String(abi_buffer);
+    entries.extend(render(templates::ROOT, &abi)?);
+    Ok(entries)
+}
+
+/// Write generated entries into `dir`, creating it if needed
+pub fn write_output(entries: &[OutputEntry], dir: impl AsRef<Path>) -> Result<(), BindgenError> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+    for entry in entries {
+        match entry {
+            OutputEntry::File { name, data } => {
+                let path = dir.join(name);
+                // Leave unchanged files alone, so they aren't rebuilt
+                if std::fs::read_to_string(&path).ok().as_deref() != Some(data.as_str()) {
+                    std::fs::write(path, data)?;
+                }
+            }
+            OutputEntry::Directory { name, entries } => write_output(entries, dir.join(name))?,
+        }
+    }
+    Ok(())
+}
+
+/// Generate the `wrap` module of the wrapper described by the `wrap.info`
+/// at `manifest_path`, into `out_dir`
+pub fn generate(manifest_path: impl AsRef<Path>, out_dir: impl AsRef<Path>) -> Result<(), BindgenError> {
+    let manifest = deserialize_wrap_manifest(&std::fs::read(manifest_path)?)?;
+    write_output(&generate_bindings(&manifest.abi)?, out_dir)
+}
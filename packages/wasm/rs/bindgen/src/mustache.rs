@@ -0,0 +1,366 @@
+//! A Mustache renderer matching `mustache.js`, which renders the same
+//! templates for the CLI.
+//!
+//! Only what the templates use is supported: values, sections, inverted
+//! sections, partials and comments, with `mustache.js` rules for
+//! standalone lines and partial indentation. Values aren't HTML escaped,
+//! and the functions of [`crate::functions`] are available as sections.
+
+use crate::functions::lambda;
+use crate::BindgenError;
+use serde_json::Value;
+
+/// Sub-templates, by name
+pub(crate) type Partials = [(&'static str, &'static str)];
+
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Value(String),
+    Section {
+        name: String,
+        inverted: bool,
+        children: Vec<Token>,
+        /// Unrendered content, passed to functions
+        raw: String,
+    },
+    Partial {
+        name: String,
+        /// What precedes the tag, if it's the first on its line
+        indentation: Option<String>,
+        line_has_non_space: bool,
+    },
+}
+
+/// Tokens of a single pass over the template, before sections are nested
+enum Flat {
+    Text(char),
+    Value(String),
+    Open { name: String, inverted: bool, end: usize },
+    Close { name: String, start: usize },
+    Partial {
+        name: String,
+        indentation: Option<String>,
+        line_has_non_space: bool,
+    },
+    Comment,
+}
+
+#[derive(Default)]
+struct Parser {
+    tokens: Vec<Option<Flat>>,
+    /// Whitespace tokens on the current line
+    spaces: Vec<usize>,
+    has_tag: bool,
+    non_space: bool,
+    line_has_non_space: bool,
+    indentation: String,
+    tag_index: usize,
+}
+
+impl Parser {
+    /// Drop the whitespace of lines with nothing but section, partial
+    /// and comment tags
+    fn strip_space(&mut self) {
+        if self.has_tag && !self.non_space {
+            for index in self.spaces.drain(..) {
+                self.tokens[index] = None;
+            }
+        } else {
+            self.spaces.clear();
+        }
+        self.has_tag = false;
+        self.non_space = false;
+    }
+
+    fn text(&mut self, text: &str) {
+        for c in text.chars() {
+            if c.is_whitespace() {
+                self.spaces.push(self.tokens.len());
+                self.indentation.push(c);
+            } else {
+                self.non_space = true;
+                self.line_has_non_space = true;
+                self.indentation.push(' ');
+            }
+            self.tokens.push(Some(Flat::Text(c)));
+
+            if c == '\n' {
+                self.strip_space();
+                self.indentation.clear();
+                self.tag_index = 0;
+                self.line_has_non_space = false;
+            }
+        }
+    }
+}
+
+fn parse(template: &str) -> Result<Vec<Token>, BindgenError> {
+    let mut parser = Parser::default();
+    let mut pos = 0;
+
+    while pos < template.len() {
+        let start = match template[pos..].find("{{") {
+            Some(offset) => pos + offset,
+            None => {
+                parser.text(&template[pos..]);
+                break;
+            }
+        };
+        parser.text(&template[pos..start]);
+        parser.has_tag = true;
+
+        let mut tag_start = start + 2;
+        let sigil = template[tag_start..]
+            .chars()
+            .next()
+            .filter(|c| "#^/>{&!=".contains(*c));
+        if sigil.is_some() {
+            tag_start += 1;
+        }
+        let close = if sigil == Some('{') { "}}}" } else { "}}" };
+        let tag_end = template[tag_start..]
+            .find(close)
+            .map(|offset| tag_start + offset)
+            .ok_or_else(|| BindgenError::Template(format!("Unclosed tag at {}", start)))?;
+        let name = template[tag_start..tag_end].trim().to_string();
+        pos = tag_end + close.len();
+
+        let token = match sigil {
+            Some('#') | Some('^') => Flat::Open {
+                name,
+                inverted: sigil == Some('^'),
+                end: pos,
+            },
+            Some('/') => Flat::Close { name, start },
+            Some('>') => Flat::Partial {
+                name,
+                indentation: (parser.tag_index == 0).then(|| parser.indentation.clone()),
+                line_has_non_space: parser.line_has_non_space,
+            },
+            Some('!') => Flat::Comment,
+            Some('=') => {
+                return Err(BindgenError::Template(
+                    "Changing delimiters isn't supported".to_string(),
+                ))
+            }
+            _ => {
+                parser.non_space = true;
+                Flat::Value(name)
+            }
+        };
+        parser.tag_index += 1;
+        parser.tokens.push(Some(token));
+    }
+    parser.strip_space();
+
+    nest(template, parser.tokens.into_iter().flatten())
+}
+
+/// An open section: its name, whether it's inverted, and where its
+/// content starts
+type Open = (String, bool, usize);
+
+/// Merge text and nest sections
+fn nest(template: &str, tokens: impl Iterator<Item = Flat>) -> Result<Vec<Token>, BindgenError> {
+    // Open sections, with the tokens collected so far
+    let mut stack: Vec<(Option<Open>, Vec<Token>)> = vec![(None, vec![])];
+
+    for token in tokens {
+        let children = &mut stack.last_mut().expect("root is never popped").1;
+        match token {
+            Flat::Text(c) => match children.last_mut() {
+                Some(Token::Text(text)) => text.push(c),
+                _ => children.push(Token::Text(c.to_string())),
+            },
+            Flat::Value(name) => children.push(Token::Value(name)),
+            Flat::Partial {
+                name,
+                indentation,
+                line_has_non_space,
+            } => children.push(Token::Partial {
+                name,
+                indentation,
+                line_has_non_space,
+            }),
+            Flat::Comment => {}
+            Flat::Open { name, inverted, end } => stack.push((Some((name, inverted, end)), vec![])),
+            Flat::Close { name, start } => {
+                let (open, children) = stack.pop().expect("root is never popped");
+                let (open_name, inverted, end) = match open {
+                    Some(open) if open.0 == name => open,
+                    _ => return Err(BindgenError::Template(format!("Unopened section \"{}\"", name))),
+                };
+                stack
+                    .last_mut()
+                    .ok_or_else(|| BindgenError::Template(format!("Unopened section \"{}\"", name)))?
+                    .1
+                    .push(Token::Section {
+                        name: open_name,
+                        inverted,
+                        children,
+                        raw: template[end..start].to_string(),
+                    });
+            }
+        }
+    }
+
+    match stack.pop() {
+        Some((None, tokens)) if stack.is_empty() => Ok(tokens),
+        Some((Some((name, ..)), _)) => Err(BindgenError::Template(format!("Unclosed section \"{}\"", name))),
+        _ => unreachable!("root is the first entry of the stack"),
+    }
+}
+
+/// What a name resolves to in the current context
+enum Found<'v> {
+    Value(&'v Value),
+    /// `.length` of an array
+    Length(usize),
+    Missing,
+}
+
+impl Found<'_> {
+    fn is_truthy(&self) -> bool {
+        match self {
+            Found::Value(Value::Null) | Found::Missing => false,
+            Found::Value(Value::Bool(b)) => *b,
+            Found::Value(Value::Number(n)) => n.as_f64() != Some(0.0),
+            Found::Value(Value::String(s)) => !s.is_empty(),
+            Found::Value(_) => true,
+            Found::Length(len) => *len != 0,
+        }
+    }
+}
+
+/// Resolve `name` from the innermost context outwards. A name is found
+/// in the first context which has it, even if its value is `null`.
+fn lookup<'v>(name: &str, stack: &[&'v Value]) -> Found<'v> {
+    if name == "." {
+        return stack.last().map_or(Found::Missing, |view| Found::Value(view));
+    }
+
+    let names: Vec<&str> = name.split('.').collect();
+    for view in stack.iter().rev() {
+        let (last, parents) = names.split_last().expect("split is never empty");
+        let parent = parents
+            .iter()
+            .try_fold(*view, |value, name| value.get(*name));
+        match parent {
+            Some(Value::Object(object)) if object.contains_key(*last) => {
+                return Found::Value(&object[*last]);
+            }
+            Some(Value::Array(array)) if *last == "length" => return Found::Length(array.len()),
+            _ => {}
+        }
+    }
+    Found::Missing
+}
+
+pub(crate) fn render(template: &str, view: &Value, partials: &Partials) -> Result<String, BindgenError> {
+    let mut output = String::new();
+    render_tokens(&parse(template)?, &mut vec![view], partials, &mut output)?;
+    Ok(output)
+}
+
+fn render_tokens(
+    tokens: &[Token],
+    stack: &mut Vec<&Value>,
+    partials: &Partials,
+    output: &mut String,
+) -> Result<(), BindgenError> {
+    for token in tokens {
+        match token {
+            Token::Text(text) => output.push_str(text),
+            Token::Value(name) => match lookup(name, stack) {
+                Found::Value(Value::Null) | Found::Missing => {}
+                Found::Value(Value::String(s)) => output.push_str(s),
+                Found::Value(value) => output.push_str(&value.to_string()),
+                Found::Length(len) => output.push_str(&len.to_string()),
+            },
+            Token::Section {
+                name,
+                inverted: false,
+                children,
+                raw,
+            } => {
+                if let Some(lambda) = lambda(name) {
+                    let mut content = String::new();
+                    render_tokens(&parse(raw)?, stack, partials, &mut content)?;
+                    output.push_str(&lambda(&content)?);
+                    continue;
+                }
+
+                let found = lookup(name, stack);
+                if !found.is_truthy() {
+                    continue;
+                }
+                match found {
+                    Found::Value(Value::Array(items)) => {
+                        for item in items {
+                            stack.push(item);
+                            render_tokens(children, stack, partials, output)?;
+                            stack.pop();
+                        }
+                    }
+                    Found::Value(value @ (Value::Object(_) | Value::String(_) | Value::Number(_))) => {
+                        stack.push(value);
+                        render_tokens(children, stack, partials, output)?;
+                        stack.pop();
+                    }
+                    _ => render_tokens(children, stack, partials, output)?,
+                }
+            }
+            Token::Section {
+                name,
+                inverted: true,
+                children,
+                ..
+            } => {
+                let found = lookup(name, stack);
+                let empty = matches!(found, Found::Value(Value::Array(items)) if items.is_empty());
+                if !found.is_truthy() || empty {
+                    render_tokens(children, stack, partials, output)?;
+                }
+            }
+            Token::Partial {
+                name,
+                indentation,
+                line_has_non_space,
+            } => {
+                let partial = partials
+                    .iter()
+                    .find(|(partial, _)| partial == name)
+                    .map(|(_, template)| *template)
+                    .ok_or_else(|| BindgenError::Template(format!("Unknown partial \"{}\"", name)))?;
+                let partial = match indentation {
+                    Some(indentation) if !indentation.is_empty() => {
+                        indent(partial, indentation, *line_has_non_space)
+                    }
+                    _ => partial.to_string(),
+                };
+                render_tokens(&parse(&partial)?, stack, partials, output)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Indent the non-empty lines of a partial by the whitespace preceding
+/// its tag. The first line follows that text already, unless it's all
+/// whitespace.
+fn indent(partial: &str, indentation: &str, line_has_non_space: bool) -> String {
+    let indentation: String = indentation.chars().filter(|c| *c == ' ' || *c == '\t').collect();
+    partial
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if line.is_empty() || (i == 0 && line_has_non_space) {
+                line.to_string()
+            } else {
+                format!("{}{}", indentation, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
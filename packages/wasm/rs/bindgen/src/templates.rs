@@ -0,0 +1,75 @@
+//! The templates of `schema/bind/src/bindings/rust/wasm/templates`, shared
+//! with the TypeScript bindings. They're copied into the crate's
+//! `templates` directory so it can be published on its own, and
+//! `tests/templates_spec.rs` checks the copies are up to date.
+
+use crate::mustache::Partials;
+
+macro_rules! template {
+    ($path:literal) => {
+        include_str!(concat!("../templates/", $path, ".mustache"))
+    };
+}
+
+/// Output file names and their templates
+pub(crate) type Templates = [(&'static str, &'static str)];
+
+pub(crate) const PARTIALS: &Partials = &[
+    ("deserialize_array", template!("deserialize_array")),
+    ("deserialize_array_nobox", template!("deserialize_array_nobox")),
+    ("deserialize_enum", template!("deserialize_enum")),
+    ("deserialize_map_value", template!("deserialize_map_value")),
+    ("deserialize_map_value_nobox", template!("deserialize_map_value_nobox")),
+    ("deserialize_object", template!("deserialize_object")),
+    ("deserialize_object_nobox", template!("deserialize_object_nobox")),
+    ("deserialize_object_refmut", template!("deserialize_object_refmut")),
+    ("serialize_array", template!("serialize_array")),
+    ("serialize_enum", template!("serialize_enum")),
+    ("serialize_map_value", template!("serialize_map_value")),
+    ("serialize_object", template!("serialize_object")),
+];
+
+pub(crate) const ROOT: &Templates = &[
+    ("entry.rs", template!("entry-rs")),
+    ("mod.rs", template!("mod-rs")),
+];
+
+pub(crate) const OBJECT_TYPE: &Templates = &[
+    ("mod.rs", template!("object-type/mod-rs")),
+    ("serialization.rs", template!("object-type/serialization-rs")),
+];
+
+pub(crate) const ENV_TYPE: &Templates = &[
+    ("mod.rs", template!("env-type/mod-rs")),
+    ("serialization.rs", template!("env-type/serialization-rs")),
+];
+
+pub(crate) const ENUM_TYPE: &Templates = &[("mod.rs", template!("enum-type/mod-rs"))];
+
+pub(crate) const INTERFACE_TYPE: &Templates = &[("mod.rs", template!("interface-type/mod-rs"))];
+
+pub(crate) const MODULE_TYPE: &Templates = &[
+    ("mod.rs", template!("module-type/mod-rs")),
+    ("methods.rs", template!("module-type/methods-rs")),
+    ("serialization.rs", template!("module-type/serialization-rs")),
+    ("wrapped.rs", template!("module-type/wrapped-rs")),
+];
+
+pub(crate) const IMPORTED: &Templates = &[("mod.rs", template!("imported/mod-rs"))];
+
+pub(crate) const IMPORTED_MODULE_TYPE: &Templates = &[
+    ("mod.rs", template!("imported/module-type/mod-rs")),
+    ("serialization.rs", template!("imported/module-type/serialization-rs")),
+];
+
+pub(crate) const IMPORTED_OBJECT_TYPE: &Templates = &[
+    ("mod.rs", template!("imported/object-type/mod-rs")),
+    ("serialization.rs", template!("imported/object-type/serialization-rs")),
+];
+
+pub(crate) const IMPORTED_ENV_TYPE: &Templates = &[
+    ("mod.rs", template!("imported/env-type/mod-rs")),
+    ("serialization.rs", template!("imported/env-type/serialization-rs")),
+];
+
+pub(crate) const IMPORTED_ENUM_TYPE: &Templates = &[("mod.rs", template!("imported/enum-type/mod-rs"))];
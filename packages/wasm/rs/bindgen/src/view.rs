@@ -0,0 +1,395 @@
+//! The ABI as seen by the templates.
+//!
+//! The TypeScript bindings render templates with the parsed ABI, after
+//! applying a list of transforms to it (`applyTransforms` in
+//! `bindings/rust/wasm/index.ts`). The view built here has the same
+//! shape: the ABI's JSON fields, with absent optional fields set to
+//! `null`, plus what the transforms add:
+//!
+//! - `toGraphQLType` on every definition
+//! - `first` and `last` on the items of arrays
+//! - `hasImports` on the ABI
+//! - `parent` on methods
+//! - `propertyDeps` on objects, the env, the module and imported modules
+
+use crate::functions::{is_base_type, is_builtin_type};
+use polywrap_wasm_rs::wrap_manifest::{
+    AnyDefinition, ArrayDefinition, DefinitionKind, EnumDefinition, GenericDefinition,
+    ImportedDefinition, ImportedEnumDefinition, ImportedModuleDefinition,
+    ImportedObjectDefinition, InterfaceDefinition, MapDefinition, MethodDefinition,
+    ModuleDefinition, NestedDefinition, ObjectDefinition, PropertyDefinition, WrapAbi,
+};
+use serde_json::{json, Map, Value};
+
+type Object = Map<String, Value>;
+
+pub(crate) fn abi_view(abi: &WrapAbi) -> Value {
+    let has_imports = !(abi.imported_enum_types.is_empty()
+        && abi.imported_object_types.is_empty()
+        && abi.imported_module_types.is_empty()
+        && abi.imported_env_types.is_empty());
+
+    json!({
+        "version": abi.version,
+        "objectTypes": first_last(abi.object_types.iter().map(object_view).collect()),
+        "moduleType": abi.module_type.as_ref().map(module_view),
+        "enumTypes": abi.enum_types.iter().map(enum_view).collect::<Vec<_>>(),
+        "interfaceTypes": abi.interface_types.iter().map(interface_view).collect::<Vec<_>>(),
+        "importedObjectTypes": first_last(
+            abi.imported_object_types.iter().map(imported_object_view).collect()
+        ),
+        "importedModuleTypes": first_last(
+            abi.imported_module_types.iter().map(imported_module_view).collect()
+        ),
+        "importedEnumTypes": abi
+            .imported_enum_types
+            .iter()
+            .map(imported_enum_view)
+            .collect::<Vec<_>>(),
+        "importedEnvTypes": first_last(
+            abi.imported_env_types.iter().map(imported_env_view).collect()
+        ),
+        "envType": abi.env_type.as_ref().map(object_view),
+        "hasImports": has_imports,
+    })
+}
+
+fn generic(def: &GenericDefinition) -> Object {
+    let mut object = Object::new();
+    object.insert("type".to_string(), json!(def._type));
+    object.insert("name".to_string(), json!(def.name));
+    object.insert("required".to_string(), json!(def.required));
+    object.insert("kind".to_string(), json!(def.kind));
+    object
+}
+
+fn imported(object: &mut Object, imported: &ImportedDefinition) {
+    object.insert("uri".to_string(), json!(imported.uri));
+    object.insert("namespace".to_string(), json!(imported.namespace));
+    object.insert("nativeType".to_string(), json!(imported.native_type));
+}
+
+/// Add `toGraphQLType`, once all fields it depends on are set
+fn finish(mut object: Object) -> Value {
+    let graphql = to_graphql(&object);
+    object.insert("toGraphQLType".to_string(), json!(graphql));
+    Value::Object(object)
+}
+
+fn reference(def: &GenericDefinition) -> Value {
+    finish(generic(def))
+}
+
+fn any_fields(any: &AnyDefinition) -> Object {
+    let mut object = generic(&any.generic);
+    object.insert("array".to_string(), json!(any.array.as_deref().map(array_view)));
+    object.insert("scalar".to_string(), json!(any.scalar.as_ref().map(reference)));
+    object.insert("map".to_string(), json!(any.map.as_deref().map(map_view)));
+    object.insert("object".to_string(), json!(any.object.as_ref().map(reference)));
+    object.insert("enum".to_string(), json!(any._enum.as_ref().map(reference)));
+    object.insert(
+        "unresolvedObjectOrEnum".to_string(),
+        json!(any.unresolved_object_or_enum.as_ref().map(reference)),
+    );
+    object
+}
+
+fn array_view(array: &ArrayDefinition) -> Value {
+    let mut object = any_fields(&array.any);
+    object.insert("item".to_string(), json!(array.item.as_deref().map(nested_view)));
+    finish(object)
+}
+
+fn map_view(map: &MapDefinition) -> Value {
+    let mut object = any_fields(&map.any);
+    object.insert("comment".to_string(), json!(map.comment));
+    object.insert("key".to_string(), json!(map.key.as_deref().map(nested_view)));
+    object.insert("value".to_string(), json!(map.value.as_deref().map(nested_view)));
+    finish(object)
+}
+
+/// Array items, map keys and map values
+fn nested_view(nested: &NestedDefinition) -> Value {
+    let generic = &nested.any.generic;
+    if generic.is_kind(DefinitionKind::ARRAY) {
+        let mut object = any_fields(&nested.any);
+        object.insert("item".to_string(), json!(nested.item.as_deref().map(nested_view)));
+        finish(object)
+    } else if generic.is_kind(DefinitionKind::MAP) {
+        let mut object = any_fields(&nested.any);
+        object.insert("comment".to_string(), json!(nested.comment));
+        object.insert("key".to_string(), json!(nested.key.as_deref().map(nested_view)));
+        object.insert("value".to_string(), json!(nested.value.as_deref().map(nested_view)));
+        finish(object)
+    } else {
+        reference(generic)
+    }
+}
+
+fn property_view(property: &PropertyDefinition) -> Value {
+    let mut object = any_fields(&property.any);
+    object.insert("comment".to_string(), json!(property.comment));
+    finish(object)
+}
+
+fn object_fields(def: &ObjectDefinition) -> Object {
+    let mut object = generic(&def.generic);
+    object.insert("comment".to_string(), json!(def.comment));
+    object.insert(
+        "properties".to_string(),
+        first_last(def.properties.iter().map(property_view).collect()),
+    );
+    object.insert(
+        "interfaces".to_string(),
+        first_last(def.interfaces.iter().map(reference).collect()),
+    );
+    object
+}
+
+/// Object types and the env type
+fn object_view(def: &ObjectDefinition) -> Value {
+    let mut object = object_fields(def);
+    let deps = property_deps(&def.generic._type, def.properties.iter());
+    object.insert("propertyDeps".to_string(), deps);
+    finish(object)
+}
+
+fn imported_object_view(def: &ImportedObjectDefinition) -> Value {
+    let mut object = object_fields(&def.object);
+    imported(&mut object, &def.imported);
+    let deps = property_deps(&def.object.generic._type, def.object.properties.iter());
+    object.insert("propertyDeps".to_string(), deps);
+    finish(object)
+}
+
+/// Imported env types aren't objects (by kind), and don't get property
+/// dependencies
+fn imported_env_view(def: &ImportedObjectDefinition) -> Value {
+    let mut object = object_fields(&def.object);
+    imported(&mut object, &def.imported);
+    finish(object)
+}
+
+fn method_view(def: &MethodDefinition, parent: &Value) -> Value {
+    let mut object = generic(&def.generic);
+    object.insert("comment".to_string(), json!(def.comment));
+    object.insert(
+        "arguments".to_string(),
+        first_last(def.arguments.iter().map(property_view).collect()),
+    );
+    object.insert(
+        "env".to_string(),
+        json!(def.env.as_ref().map(|env| json!({ "required": env.required }))),
+    );
+    object.insert("return".to_string(), property_view(&def._return));
+    object.insert("parent".to_string(), parent.clone());
+    finish(object)
+}
+
+fn methods_deps(root_type: &str, methods: &[MethodDefinition]) -> Value {
+    let properties = methods
+        .iter()
+        .flat_map(|method| method.arguments.iter().chain(std::iter::once(&method._return)));
+    property_deps(root_type, properties)
+}
+
+fn module_view(def: &ModuleDefinition) -> Value {
+    let mut object = generic(&def.generic);
+    object.insert("comment".to_string(), json!(def.comment));
+    object.insert(
+        "imports".to_string(),
+        first_last(def.imports.iter().map(|import| json!({ "type": import._type })).collect()),
+    );
+    object.insert(
+        "interfaces".to_string(),
+        first_last(def.interfaces.iter().map(reference).collect()),
+    );
+
+    let parent = finish(object.clone());
+    object.insert(
+        "methods".to_string(),
+        first_last(def.methods.iter().map(|method| method_view(method, &parent)).collect()),
+    );
+    object.insert("propertyDeps".to_string(), methods_deps(&def.generic._type, &def.methods));
+    finish(object)
+}
+
+fn imported_module_view(def: &ImportedModuleDefinition) -> Value {
+    let mut object = generic(&def.generic);
+    imported(&mut object, &def.imported);
+    object.insert("comment".to_string(), json!(def.comment));
+    object.insert("isInterface".to_string(), json!(def.is_interface));
+
+    let parent = finish(object.clone());
+    object.insert(
+        "methods".to_string(),
+        first_last(def.methods.iter().map(|method| method_view(method, &parent)).collect()),
+    );
+    object.insert("propertyDeps".to_string(), methods_deps(&def.generic._type, &def.methods));
+    finish(object)
+}
+
+fn enum_fields(def: &EnumDefinition) -> Object {
+    let mut object = generic(&def.generic);
+    object.insert("comment".to_string(), json!(def.comment));
+    object.insert("constants".to_string(), json!(def.constants));
+    object
+}
+
+fn enum_view(def: &EnumDefinition) -> Value {
+    finish(enum_fields(def))
+}
+
+fn imported_enum_view(def: &ImportedEnumDefinition) -> Value {
+    let mut object = enum_fields(&def._enum);
+    imported(&mut object, &def.imported);
+    finish(object)
+}
+
+fn interface_view(def: &InterfaceDefinition) -> Value {
+    let mut object = generic(&def.generic);
+    imported(&mut object, &def.imported);
+    let get_implementations = def
+        .capabilities
+        .get_implementations
+        .as_ref()
+        .map(|capability| json!({ "enabled": capability.enabled }));
+    object.insert(
+        "capabilities".to_string(),
+        json!({ "getImplementations": get_implementations }),
+    );
+    finish(object)
+}
+
+/// Mark the first and last objects of an array
+fn first_last(items: Vec<Value>) -> Value {
+    let len = items.len();
+    let items = items
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut item)| {
+            if let Value::Object(object) = &mut item {
+                object.insert("first".to_string(), json!((i == 0).then_some(true)));
+                object.insert("last".to_string(), json!((i + 1 == len).then_some(true)));
+            }
+            item
+        })
+        .collect();
+    Value::Array(items)
+}
+
+/// The prefixed GraphQL type of a definition (enums as `Enum_Name`), or
+/// `null` for definitions which don't have one
+fn to_graphql(def: &Object) -> Option<String> {
+    let kind = def.get("kind")?.as_u64()? as u32;
+    let ty = def.get("type").and_then(Value::as_str).unwrap_or_default();
+    let required = def.get("required") == Some(&Value::Bool(true));
+    let apply_required = |ty: String| if required { format!("{}!", ty) } else { ty };
+    let child = |field: &str| def.get(field).and_then(Value::as_object).and_then(to_graphql);
+
+    match kind {
+        DefinitionKind::OBJECT
+        | DefinitionKind::OBJECT_REF
+        | DefinitionKind::SCALAR
+        | DefinitionKind::IMPORTED_OBJECT => Some(apply_required(ty.to_string())),
+        DefinitionKind::ENUM | DefinitionKind::ENUM_REF | DefinitionKind::IMPORTED_ENUM => {
+            Some(apply_required(format!("Enum_{}", ty)))
+        }
+        DefinitionKind::ANY | DefinitionKind::PROPERTY => ["object", "array", "scalar", "enum", "map"]
+            .iter()
+            .find(|field| def.get(**field).is_some_and(Value::is_object))
+            .and_then(|field| child(field)),
+        DefinitionKind::ARRAY => Some(apply_required(format!("[{}]", child("item")?))),
+        DefinitionKind::MAP => Some(apply_required(format!("Map<{}, {}>", child("key")?, child("value")?))),
+        DefinitionKind::METHOD => {
+            let arguments = def.get("arguments")?.as_array()?;
+            let arguments = arguments
+                .iter()
+                .map(|argument| {
+                    let argument = argument.as_object()?;
+                    let name = argument.get("name").and_then(Value::as_str).unwrap_or_default();
+                    Some(format!("{}: {}", name, to_graphql(argument)?))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let name = def.get("name").and_then(Value::as_str).unwrap_or_default();
+            Some(format!(
+                "{}(\n  {}\n): {}",
+                name,
+                arguments.join("\n    "),
+                child("return")?
+            ))
+        }
+        DefinitionKind::MODULE | DefinitionKind::IMPORTED_MODULE => Some(ty.to_string()),
+        _ => None,
+    }
+}
+
+/// Types a definition's properties depend on, which its module has to
+/// import. Ported from the `propertyDeps` transform, visiting properties
+/// the same way `transformAbi` does so the order of the imports matches.
+fn property_deps<'a>(
+    root_type: &str,
+    properties: impl Iterator<Item = &'a PropertyDefinition>,
+) -> Value {
+    let mut deps: Vec<(String, bool)> = vec![];
+    for property in properties {
+        visit_any(root_type, &property.any, &mut deps);
+    }
+    let deps = deps
+        .into_iter()
+        .map(|(ty, is_enum)| json!({ "crate": "crate", "type": ty, "isEnum": is_enum }))
+        .collect();
+    Value::Array(deps)
+}
+
+fn visit_any(root_type: &str, any: &AnyDefinition, deps: &mut Vec<(String, bool)>) {
+    add_dep(root_type, any, deps);
+    if let Some(array) = &any.array {
+        visit_any(root_type, &array.any, deps);
+        if let Some(item) = &array.item {
+            visit_nested(root_type, item, deps);
+        }
+    }
+    if let Some(map) = &any.map {
+        visit_any(root_type, &map.any, deps);
+        if let Some(value) = &map.value {
+            visit_nested(root_type, value, deps);
+        }
+    }
+}
+
+/// Items and values aren't visited, only transformed themselves
+fn visit_nested(root_type: &str, nested: &NestedDefinition, deps: &mut Vec<(String, bool)>) {
+    if nested.any.generic.is_kind(DefinitionKind::ANY) {
+        add_dep(root_type, &nested.any, deps);
+    }
+}
+
+fn add_dep(root_type: &str, any: &AnyDefinition, deps: &mut Vec<(String, bool)>) {
+    let is_known = |ty: &str| is_base_type(ty) || is_builtin_type(ty) || ty == root_type;
+    let mut add = |ty: &str, is_enum: bool| {
+        if !deps.iter().any(|(dep, _)| dep == ty) {
+            deps.push((ty.to_string(), is_enum));
+        }
+    };
+
+    let mut ty = any.generic._type.clone();
+    if ty.starts_with('[') {
+        ty.retain(|c| !matches!(c, '[' | ']' | '!' | '?'));
+    }
+
+    // Maps only depend on object values
+    if ty.starts_with("Map<") {
+        let value = any.map.as_ref().and_then(|map| map.any.object.as_ref());
+        if let Some(value) = value {
+            if !is_known(&value._type) {
+                add(&value._type, false);
+            }
+        }
+        return;
+    }
+
+    if !is_known(&ty) {
+        add(&ty, any._enum.is_some());
+    }
+}
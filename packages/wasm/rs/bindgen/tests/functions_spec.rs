@@ -0,0 +1,29 @@
+use polywrap_bindgen::{apply_template_function, BindgenError};
+use serde_json::Value;
+
+/// Cases the TypeScript bindings' functions are tested against too
+const CASES: &str = include_str!("../../../../test-cases/cases/functions/wasm-rs.json");
+
+#[test]
+fn agrees_with_the_typescript_bindings() {
+    let cases: Vec<Value> = serde_json::from_str(CASES).unwrap();
+    for case in cases {
+        let function = case["function"].as_str().unwrap();
+        let value = case["value"].as_str().unwrap();
+        let output = apply_template_function(function, value)
+            .unwrap_or_else(|| panic!("{} isn't a template function", function));
+
+        match (output, case.get("error")) {
+            (Ok(result), None) => assert_eq!(result, case["result"], "{}({})", function, value),
+            (Err(BindgenError::Template(error)), Some(expected)) => {
+                assert_eq!(error, *expected, "{}({})", function, value)
+            }
+            (output, _) => panic!("{}({}) returned {:?}", function, value, output),
+        }
+    }
+}
+
+#[test]
+fn has_no_unknown_functions() {
+    assert!(apply_template_function("toUnknown", "Int!").is_none());
+}
@@ -0,0 +1,123 @@
+use polywrap_bindgen::{generate_bindings, write_output, OutputEntry};
+use polywrap_wasm_rs::wrap_manifest::WrapAbi;
+use polywrap_wasm_rs::{Context, ReadDecoder, Write, WriteEncoder};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+const SANITY_WRAP_INFO: &str = include_str!("../../../../test-cases/cases/bind/sanity/output/plugin-ts/wrap.info.ts");
+
+fn sanity_output() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../test-cases/cases/bind/sanity/output/wasm-rs")
+}
+
+fn write_json(value: &Value, writer: &mut WriteEncoder) {
+    match value {
+        Value::Null => writer.write_nil().unwrap(),
+        Value::Bool(b) => writer.write_bool(b).unwrap(),
+        Value::Number(n) => writer.write_u32(&(n.as_u64().unwrap() as u32)).unwrap(),
+        Value::String(s) => writer.write_string(s).unwrap(),
+        Value::Array(items) => {
+            writer.write_array_length(&(items.len() as u32)).unwrap();
+            for item in items {
+                write_json(item, writer);
+            }
+        }
+        Value::Object(object) => {
+            writer.write_map_length(&(object.len() as u32)).unwrap();
+            for (key, value) in object {
+                writer.write_string(key).unwrap();
+                write_json(value, writer);
+            }
+        }
+    }
+}
+
+/// The ABI of the sanity test case, from the manifest of its TypeScript
+/// plugin bindings
+fn sanity_abi() -> WrapAbi {
+    let start = SANITY_WRAP_INFO.find("abi: ").unwrap() + "abi: ".len();
+    let end = SANITY_WRAP_INFO.trim_end().rfind('}').unwrap();
+    let json: Value = serde_json::from_str(&SANITY_WRAP_INFO[start..end]).unwrap();
+
+    let mut writer = WriteEncoder::new(&[], Context::new());
+    write_json(&json, &mut writer);
+    let buffer = writer.get_buffer();
+    WrapAbi::read(&mut ReadDecoder::new(&buffer, Context::new())).unwrap()
+}
+
+/// Compare generated entries with the files of `dir`
+fn assert_matches(entries: &[OutputEntry], dir: &Path) {
+    let mut expected: Vec<String> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    expected.sort();
+    let mut names: Vec<String> = entries.iter().map(|entry| entry.name().to_string()).collect();
+    names.sort();
+    assert_eq!(names, expected, "entries of {}", dir.display());
+
+    for entry in entries {
+        let path = dir.join(entry.name());
+        match entry {
+            OutputEntry::File { data, .. } => {
+                let expected = std::fs::read_to_string(&path).unwrap();
+                if let Some((line, (generated, expected))) = data
+                    .lines()
+                    .zip(expected.lines())
+                    .enumerate()
+                    .find(|(_, (generated, expected))| generated != expected)
+                {
+                    panic!(
+                        "{}:{} differs\ngenerated: {:?}\nexpected:  {:?}",
+                        path.display(),
+                        line + 1,
+                        generated,
+                        expected
+                    );
+                }
+                assert_eq!(data, &expected, "{}", path.display());
+            }
+            OutputEntry::Directory { entries, .. } => assert_matches(entries, &path),
+        }
+    }
+}
+
+#[test]
+fn generates_sanity_bindings() {
+    let entries = generate_bindings(&sanity_abi()).unwrap();
+    assert_matches(&entries, &sanity_output());
+}
+
+#[test]
+fn writes_output() {
+    let entries = generate_bindings(&sanity_abi()).unwrap();
+    let dir = std::env::temp_dir().join(format!("polywrap-bindgen-{}", std::process::id()));
+    write_output(&entries, &dir).unwrap();
+
+    let module = std::fs::read_to_string(dir.join("module/wrapped.rs")).unwrap();
+    let expected = std::fs::read_to_string(sanity_output().join("module/wrapped.rs")).unwrap();
+    assert_eq!(module, expected);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn embeds_the_abi() {
+    let entries = generate_bindings(&sanity_abi()).unwrap();
+    let entry = entries
+        .iter()
+        .find_map(|entry| match entry {
+            OutputEntry::File { name, data } if name == "entry.rs" => Some(data),
+            _ => None,
+        })
+        .unwrap();
+
+    let start = entry.find("pub const ABI: &[u8] = &[").unwrap();
+    let end = start + entry[start..].find("];").unwrap();
+    let abi: Vec<u8> = entry[start..end]
+        .split_whitespace()
+        .filter_map(|byte| byte.strip_prefix("0x"))
+        .map(|byte| u8::from_str_radix(byte.trim_end_matches(','), 16).unwrap())
+        .collect();
+    let decoded = WrapAbi::read(&mut ReadDecoder::new(&abi, Context::new())).unwrap();
+    assert_eq!(decoded, sanity_abi());
+}
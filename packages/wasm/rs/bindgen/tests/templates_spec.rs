@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The templates of the TypeScript bindings, which the crate's copies are
+/// kept in sync with
+fn schema_templates() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../schema/bind/src/bindings/rust/wasm/templates")
+}
+
+/// The files under `dir`, by their path relative to it
+fn read_templates(dir: &Path, prefix: &Path, templates: &mut BTreeMap<PathBuf, String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let entry = entry.unwrap();
+        let path = prefix.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            read_templates(&entry.path(), &path, templates);
+        } else {
+            templates.insert(path, std::fs::read_to_string(entry.path()).unwrap());
+        }
+    }
+}
+
+fn templates(dir: &Path) -> BTreeMap<PathBuf, String> {
+    let mut templates = BTreeMap::new();
+    read_templates(dir, Path::new(""), &mut templates);
+    templates
+}
+
+#[test]
+fn copies_the_templates_of_the_typescript_bindings() {
+    let schema = schema_templates();
+    // Only checked in the monorepo, not in the published crate
+    if !schema.exists() {
+        return;
+    }
+
+    let copies = templates(&Path::new(env!("CARGO_MANIFEST_DIR")).join("templates"));
+    let originals = templates(&schema);
+    assert_eq!(
+        copies.keys().collect::<Vec<_>>(),
+        originals.keys().collect::<Vec<_>>(),
+        "copy {} into templates",
+        schema.display()
+    );
+    for (path, original) in &originals {
+        assert!(
+            &copies[path] == original,
+            "templates/{} is out of date, copy it from {}",
+            path.display(),
+            schema.display()
+        );
+    }
+}
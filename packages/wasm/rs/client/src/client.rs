@@ -0,0 +1,173 @@
+use crate::plugin::PluginWrapper;
+use crate::{ClientError, Plugin, WasmWrapper, Wrapper};
+use polywrap_wasm_rs::{Cassette, Interaction, Uri};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type DebugLog = Arc<dyn Fn(&str, &str) + Send + Sync>;
+
+/// Wrappers by URI, and the configuration of their invocations.
+///
+/// URIs are normalized, so `ens/domain.eth` and `wrap://ens/domain.eth`
+/// name the same wrapper.
+///
+/// Clones share their registrations, so wrappers invoked by the client
+/// can subinvoke anything registered later.
+#[derive(Clone)]
+pub struct Client {
+    registry: Arc<RwLock<Registry>>,
+    /// URI of the wrapper invoking through this handle, if any
+    caller: Option<String>,
+}
+
+/// URIs are normalized, see [`key`]
+struct Registry {
+    wrappers: HashMap<String, Arc<dyn Wrapper>>,
+    envs: HashMap<String, Vec<u8>>,
+    implementations: HashMap<String, Vec<String>>,
+    /// Host interactions of the wrappers being recorded, by URI
+    recordings: HashMap<String, Cassette>,
+    debug_log: DebugLog,
+}
+
+impl Default for Client {
+    fn default() -> Client {
+        Client::new()
+    }
+}
+
+impl Client {
+    pub fn new() -> Client {
+        let registry = Registry {
+            wrappers: HashMap::new(),
+            envs: HashMap::new(),
+            implementations: HashMap::new(),
+            recordings: HashMap::new(),
+            debug_log: Arc::new(|uri, message| eprintln!("{}: {}", uri, message)),
+        };
+        Client {
+            registry: Arc::new(RwLock::new(registry)),
+            caller: None,
+        }
+    }
+
+    /// A handle to the same client for the wrapper at `uri`, whose
+    /// invocations it subinvokes see it as their caller
+    pub(crate) fn with_caller(&self, uri: &str) -> Client {
+        Client {
+            registry: self.registry.clone(),
+            caller: Some(uri.to_string()),
+        }
+    }
+
+    pub(crate) fn caller(&self) -> Option<&str> {
+        self.caller.as_deref()
+    }
+
+    /// Compile a `.wasm` wrapper, and register it under `uri`
+    pub fn register_wasm(&self, uri: &str, wasm: &[u8]) -> Result<(), ClientError> {
+        let wrapper = WasmWrapper::new(wasm)?;
+        self.register_wrapper(uri, wrapper);
+        Ok(())
+    }
+
+    pub fn register_wrapper(&self, uri: &str, wrapper: impl Wrapper + 'static) {
+        self.write().wrappers.insert(key(uri), Arc::new(wrapper));
+    }
+
+    /// Register native code under `uri`, to be invoked and subinvoked like
+    /// a wrapper
+    pub fn register_plugin(&self, uri: &str, plugin: impl Plugin + 'static) {
+        self.register_wrapper(uri, PluginWrapper(plugin));
+    }
+
+    /// Set the msgpack encoded env passed to the wrapper at `uri`, including
+    /// when it's subinvoked
+    pub fn set_env(&self, uri: &str, env: Vec<u8>) {
+        self.write().envs.insert(key(uri), env);
+    }
+
+    pub fn register_implementation(&self, interface_uri: &str, implementation_uri: &str) {
+        let mut registry = self.write();
+        let implementations = registry.implementations.entry(key(interface_uri)).or_default();
+        let implementation_uri = key(implementation_uri);
+        if !implementations.contains(&implementation_uri) {
+            implementations.push(implementation_uri);
+        }
+    }
+
+    pub fn get_implementations(&self, interface_uri: &str) -> Vec<String> {
+        self.read()
+            .implementations
+            .get(&key(interface_uri))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Record what the wasm wrapper at `uri` asks of the client, from its
+    /// subinvokes, env and implementation lookups, for replaying in tests
+    pub fn record(&self, uri: &str) {
+        self.write().recordings.insert(key(uri), Cassette::default());
+    }
+
+    /// Stop recording the wrapper at `uri`, returning what was recorded
+    pub fn eject_cassette(&self, uri: &str) -> Option<Cassette> {
+        self.write().recordings.remove(&key(uri))
+    }
+
+    pub(crate) fn record_interaction(&self, uri: &str, interaction: impl FnOnce() -> Interaction) {
+        if let Some(cassette) = self.write().recordings.get_mut(&key(uri)) {
+            cassette.interactions.push(interaction());
+        }
+    }
+
+    /// Handle the messages of `__wrap_debug_log`, which go to stderr by
+    /// default
+    pub fn on_debug_log(&self, debug_log: impl Fn(&str, &str) + Send + Sync + 'static) {
+        self.write().debug_log = Arc::new(debug_log);
+    }
+
+    pub(crate) fn debug_log(&self, uri: &str, message: &str) {
+        let debug_log = self.read().debug_log.clone();
+        debug_log(uri, message)
+    }
+
+    /// Invoke `method` of the wrapper at `uri` with msgpack encoded args,
+    /// and the env set for it
+    pub fn invoke(&self, uri: &str, method: &str, args: &[u8]) -> Result<Vec<u8>, ClientError> {
+        let env = self.read().envs.get(&key(uri)).cloned().unwrap_or_default();
+        self.invoke_with_env(uri, method, args, &env)
+    }
+
+    pub fn invoke_with_env(
+        &self,
+        uri: &str,
+        method: &str,
+        args: &[u8],
+        env: &[u8],
+    ) -> Result<Vec<u8>, ClientError> {
+        let uri = key(uri);
+        let wrapper = self
+            .read()
+            .wrappers
+            .get(&uri)
+            .cloned()
+            .ok_or_else(|| ClientError::NotFound(uri.clone()))?;
+        wrapper.invoke(self, &uri, method, args, env)
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Registry> {
+        self.registry.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Registry> {
+        self.registry.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The normalized form of `uri`, e.g. `wrap://ens/domain.eth` for
+/// `ens/domain.eth`. Invalid URIs are kept as they are, so they only match
+/// themselves.
+fn key(uri: &str) -> String {
+    Uri::new(uri).map_or_else(|_| uri.to_string(), |uri| uri.uri().to_string())
+}
@@ -0,0 +1,35 @@
+use polywrap_wasm_rs::AbortReport;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("No wrapper registered for \"{0}\"")]
+    NotFound(String),
+
+    #[error("{uri} aborted: {}", describe_abort(report))]
+    Abort { uri: String, report: Box<AbortReport> },
+
+    #[error("{uri} failed to invoke \"{method}\": {message}")]
+    Invoke {
+        uri: String,
+        method: String,
+        message: String,
+    },
+
+    #[error("Invalid wrapper: {0}")]
+    InvalidWrapper(String),
+
+    #[error("{0}")]
+    Wasm(#[from] wasmi::Error),
+}
+
+fn describe_abort(report: &AbortReport) -> String {
+    let mut description = report.message.clone();
+    if let Some(method) = &report.method {
+        description = format!("{} (in {})", description, method);
+    }
+    if let Some(file) = &report.file {
+        description = format!("{} at {}:{}:{}", description, file, report.line, report.column);
+    }
+    description
+}
@@ -0,0 +1,40 @@
+//! Loads and invokes wasm wrappers built with `polywrap-wasm-rs`.
+//!
+//! The [`Client`] implements the host side of the `wrap` import module on
+//! an embedded interpreter, so wrappers run wherever Rust does, with no
+//! JavaScript runtime. Subinvokes are routed to the wrappers registered
+//! with the same client, and aborts come back as [`ClientError::Abort`]
+//! with the wrapper's [`AbortReport`](polywrap_wasm_rs::AbortReport).
+//! Native Rust code can be registered too, as a [`Plugin`].
+//!
+//! ```no_run
+//! use polywrap_client::Client;
+//!
+//! let client = Client::new();
+//! client.register_wasm("wrap://ens/wrapper.eth", &std::fs::read("wrap.wasm").unwrap()).unwrap();
+//! let result = client.invoke("wrap://ens/wrapper.eth", "method", &[0x80]).unwrap();
+//! ```
+
+mod client;
+mod error;
+mod plugin;
+mod wasm;
+
+pub use client::Client;
+pub use error::ClientError;
+pub use plugin::{Plugin, PluginContext, PluginError};
+pub use wasm::WasmWrapper;
+
+/// Something invokable through a [`Client`]
+pub trait Wrapper: Send + Sync {
+    /// Invoke `method` with msgpack encoded args and env. `client` is the
+    /// client the invocation goes through, for subinvokes.
+    fn invoke(
+        &self,
+        client: &Client,
+        uri: &str,
+        method: &str,
+        args: &[u8],
+        env: &[u8],
+    ) -> Result<Vec<u8>, ClientError>;
+}
@@ -0,0 +1,102 @@
+//! Wrappers implemented in Rust, running in the host.
+//!
+//! A [`Plugin`] is registered under a URI like any other wrapper, and
+//! wrappers subinvoking that URI run its native code. Arguments and
+//! results are msgpack encoded, as they are for wasm wrappers, so a plugin
+//! can stand in for a wasm implementation of the same interface.
+
+use crate::{Client, ClientError, Wrapper};
+use polywrap_wasm_rs::{Context, DecodeError, EncodeError, ReadDecoder, WriteEncoder};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PluginError {
+    #[error("Unknown method \"{0}\"")]
+    UnknownMethod(String),
+
+    #[error("{0}")]
+    DecodeError(#[from] DecodeError),
+
+    #[error("{0}")]
+    EncodeError(#[from] EncodeError),
+
+    #[error("{0}")]
+    Client(Box<ClientError>),
+
+    #[error("{0}")]
+    Message(String),
+}
+
+impl From<ClientError> for PluginError {
+    fn from(error: ClientError) -> PluginError {
+        PluginError::Client(Box::new(error))
+    }
+}
+
+/// What a plugin gets to know about its invocation
+pub struct PluginContext<'a> {
+    /// The client the invocation goes through, for subinvokes
+    pub client: &'a Client,
+    pub uri: &'a str,
+    /// URI of the wrapper which subinvoked the plugin, if any
+    pub caller: Option<&'a str>,
+    /// The msgpack encoded env, empty if there is none
+    pub env: &'a [u8],
+}
+
+impl PluginContext<'_> {
+    pub fn env_decoder(&self) -> ReadDecoder {
+        let mut context = Context::new();
+        context.description = format!("Deserializing env of {}", self.uri);
+        ReadDecoder::new(self.env, context)
+    }
+}
+
+pub trait Plugin: Send + Sync {
+    /// Invoke `method`, reading its arguments from `args` and writing its
+    /// result to `result`
+    fn invoke(
+        &self,
+        context: &PluginContext<'_>,
+        method: &str,
+        args: &mut ReadDecoder,
+        result: &mut WriteEncoder,
+    ) -> Result<(), PluginError>;
+}
+
+pub(crate) struct PluginWrapper<P: Plugin>(pub P);
+
+impl<P: Plugin> Wrapper for PluginWrapper<P> {
+    fn invoke(
+        &self,
+        client: &Client,
+        uri: &str,
+        method: &str,
+        args: &[u8],
+        env: &[u8],
+    ) -> Result<Vec<u8>, ClientError> {
+        let plugin_client = client.with_caller(uri);
+        let context = PluginContext {
+            client: &plugin_client,
+            uri,
+            caller: client.caller(),
+            env,
+        };
+
+        let mut decoder_context = Context::new();
+        decoder_context.description = format!("Deserializing plugin method: {}", method);
+        let mut args = ReadDecoder::new(args, decoder_context);
+        let mut encoder_context = Context::new();
+        encoder_context.description = format!("Serializing (encoding) plugin method: {}", method);
+        let mut result = WriteEncoder::new(&[], encoder_context);
+
+        match self.0.invoke(&context, method, &mut args, &mut result) {
+            Ok(()) => Ok(result.get_buffer()),
+            Err(error) => Err(ClientError::Invoke {
+                uri: uri.to_string(),
+                method: method.to_string(),
+                message: error.to_string(),
+            }),
+        }
+    }
+}
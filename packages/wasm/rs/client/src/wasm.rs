@@ -0,0 +1,599 @@
+//! The host side of the `wrap` import module.
+//!
+//! Every invocation runs in a fresh instance, whose store keeps the state
+//! the imports share: the invocation's method, args and env, and the
+//! results handed back and forth with the wrapper.
+
+use crate::{Client, ClientError, Wrapper};
+use polywrap_wasm_rs::{
+    deserialize_subinvoke_requests, serialize_subinvoke_results, AbortReport, Context, Interaction,
+    SubinvokeOptions, SubinvokeRequest, Write, WriteEncoder,
+};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use wasmi::{Caller, Engine, Extern, Instance, Linker, Memory, MemoryType, Module, Store};
+
+/// A wrapper built with `polywrap-wasm-rs`
+pub struct WasmWrapper {
+    wasm: Vec<u8>,
+    /// Compiled modules not in use. An engine can't instantiate while it
+    /// runs a call, so each invocation takes its own, and subinvokes back
+    /// into the wrapper compile another.
+    idle: Mutex<Vec<Compiled>>,
+}
+
+struct Compiled {
+    module: Module,
+    linker: Linker<State>,
+    /// The type of `env.memory`, for wrappers which import their memory
+    /// (as AssemblyScript ones do) instead of exporting it
+    imported_memory: Option<MemoryType>,
+}
+
+impl Compiled {
+    fn new(wasm: &[u8]) -> Result<Compiled, ClientError> {
+        let engine = Engine::default();
+        let module = Module::new(&engine, wasm)?;
+        let mut linker = Linker::new(&engine);
+        define_imports(&mut linker).map_err(wasmi::Error::from)?;
+        let imported_memory = module
+            .imports()
+            .filter(|import| import.module() == "env" && import.name() == "memory")
+            .find_map(|import| import.ty().memory().copied());
+        Ok(Compiled {
+            module,
+            linker,
+            imported_memory,
+        })
+    }
+
+    fn instantiate(&self, store: &mut Store<State>) -> Result<Instance, wasmi::Error> {
+        let instance = match self.imported_memory {
+            Some(ty) => {
+                let memory = Memory::new(&mut *store, ty)?;
+                store.data_mut().memory = Some(memory);
+                let mut linker = self.linker.clone();
+                linker.define("env", "memory", memory)?;
+                linker.instantiate(&mut *store, &self.module)?
+            }
+            None => self.linker.instantiate(&mut *store, &self.module)?,
+        };
+        instance.start(&mut *store)
+    }
+
+    /// Run `_wrap_invoke` in a new instance
+    fn call(&self, store: &mut Store<State>) -> Result<u32, wasmi::Error> {
+        let instance = self.instantiate(store)?;
+        let wrap_invoke = instance.get_typed_func::<(u32, u32, u32), u32>(&*store, "_wrap_invoke")?;
+
+        let state = store.data();
+        let sizes = (state.method.len() as u32, state.args.len() as u32, state.env.len() as u32);
+        wrap_invoke.call(store, sizes)
+    }
+}
+
+struct State {
+    /// Handle for the subinvokes of the wrapper
+    client: Client,
+    uri: String,
+    caller: Option<String>,
+    method: String,
+    args: Vec<u8>,
+    env: Vec<u8>,
+    invoke_result: Option<Vec<u8>>,
+    invoke_error: Option<String>,
+    subinvoke_result: Option<Vec<u8>>,
+    subinvoke_error: Option<String>,
+    subinvoke_batch_result: Option<Vec<u8>>,
+    implementations: Option<Vec<u8>>,
+    abort: Option<AbortReport>,
+    /// Imported memory, if the wrapper doesn't export its own
+    memory: Option<Memory>,
+}
+
+impl State {
+    fn new(client: Client, uri: &str, caller: Option<&str>) -> State {
+        State {
+            client,
+            uri: uri.to_string(),
+            caller: caller.map(str::to_string),
+            method: String::new(),
+            args: vec![],
+            env: vec![],
+            invoke_result: None,
+            invoke_error: None,
+            subinvoke_result: None,
+            subinvoke_error: None,
+            subinvoke_batch_result: None,
+            implementations: None,
+            abort: None,
+            memory: None,
+        }
+    }
+
+    /// Keep the outcome of a subinvoke for the wrapper to fetch, returning
+    /// whether it succeeded
+    fn set_subinvoke_result(&mut self, result: Result<Vec<u8>, ClientError>) -> u32 {
+        self.subinvoke_result = None;
+        self.subinvoke_error = None;
+        match result {
+            Ok(result) => {
+                self.subinvoke_result = Some(result);
+                1
+            }
+            Err(error) => {
+                self.subinvoke_error = Some(error.to_string());
+                0
+            }
+        }
+    }
+}
+
+impl WasmWrapper {
+    pub fn new(wasm: &[u8]) -> Result<WasmWrapper, ClientError> {
+        let compiled = Compiled::new(wasm)?;
+        let exports: Vec<&str> = compiled.module.exports().map(|export| export.name()).collect();
+        if !exports.contains(&"_wrap_invoke") {
+            return Err(ClientError::InvalidWrapper("missing the \"_wrap_invoke\" export".to_string()));
+        }
+        if !exports.contains(&"memory") && compiled.imported_memory.is_none() {
+            return Err(ClientError::InvalidWrapper(
+                "neither exports \"memory\" nor imports \"env.memory\"".to_string(),
+            ));
+        }
+
+        Ok(WasmWrapper {
+            wasm: wasm.to_vec(),
+            idle: Mutex::new(vec![compiled]),
+        })
+    }
+
+    /// The msgpack encoded ABI the wrapper exports, the `abi` of its
+    /// wrap.info. It's read from the memory of a new instance, without
+    /// invoking anything.
+    pub fn abi(&self) -> Result<Vec<u8>, ClientError> {
+        let compiled = self.take_compiled()?;
+        let exports: Vec<&str> = compiled.module.exports().map(|export| export.name()).collect();
+        if !exports.contains(&"_wrap_abi") || !exports.contains(&"_wrap_abi_len") {
+            self.put_back(compiled);
+            return Err(ClientError::InvalidWrapper(
+                "missing the \"_wrap_abi\" and \"_wrap_abi_len\" exports".to_string(),
+            ));
+        }
+
+        let mut store = Store::new(compiled.module.engine(), State::new(Client::new(), "", None));
+        let abi = read_abi(&compiled, &mut store);
+        self.put_back(compiled);
+        Ok(abi?)
+    }
+
+    fn take_compiled(&self) -> Result<Compiled, ClientError> {
+        let idle = self.idle.lock().unwrap_or_else(|e| e.into_inner()).pop();
+        match idle {
+            Some(compiled) => Ok(compiled),
+            None => Compiled::new(&self.wasm),
+        }
+    }
+
+    fn put_back(&self, compiled: Compiled) {
+        self.idle.lock().unwrap_or_else(|e| e.into_inner()).push(compiled);
+    }
+}
+
+fn read_abi(compiled: &Compiled, store: &mut Store<State>) -> Result<Vec<u8>, wasmi::Error> {
+    let instance = compiled.instantiate(store)?;
+    let ptr = instance.get_typed_func::<(), u32>(&*store, "_wrap_abi")?.call(&mut *store, ())?;
+    let len = instance.get_typed_func::<(), u32>(&*store, "_wrap_abi_len")?.call(&mut *store, ())?;
+    let memory = instance
+        .get_memory(&*store, "memory")
+        .or(store.data().memory)
+        .ok_or_else(|| wasmi::Error::new("the wrapper has no memory"))?;
+
+    let mut abi = vec![0; len as usize];
+    memory.read(&*store, ptr as usize, &mut abi)?;
+    Ok(abi)
+}
+
+impl Wrapper for WasmWrapper {
+    fn invoke(
+        &self,
+        client: &Client,
+        uri: &str,
+        method: &str,
+        args: &[u8],
+        env: &[u8],
+    ) -> Result<Vec<u8>, ClientError> {
+        let mut state = State::new(client.with_caller(uri), uri, client.caller());
+        state.method = method.to_string();
+        state.args = args.to_vec();
+        state.env = env.to_vec();
+
+        let compiled = self.take_compiled()?;
+        let mut store = Store::new(compiled.module.engine(), state);
+        let result = compiled.call(&mut store);
+        self.put_back(compiled);
+        let state = store.into_data();
+        if let Some(report) = state.abort {
+            return Err(ClientError::Abort {
+                uri: uri.to_string(),
+                report: Box::new(report),
+            });
+        }
+
+        let invoke_error = |message: String| ClientError::Invoke {
+            uri: uri.to_string(),
+            method: method.to_string(),
+            message,
+        };
+        match (result?, state.invoke_result, state.invoke_error) {
+            (0, _, error) => Err(invoke_error(
+                error.unwrap_or_else(|| "_wrap_invoke failed without setting an error".to_string()),
+            )),
+            (_, Some(result), _) => Ok(result),
+            (_, None, _) => Err(invoke_error("_wrap_invoke didn't set a result".to_string())),
+        }
+    }
+}
+
+fn memory(caller: &Caller<'_, State>) -> Result<Memory, wasmi::Error> {
+    caller
+        .get_export("memory")
+        .and_then(Extern::into_memory)
+        .or(caller.data().memory)
+        .ok_or_else(|| wasmi::Error::new("the wrapper has no memory"))
+}
+
+fn read(caller: &Caller<'_, State>, ptr: u32, len: u32) -> Result<Vec<u8>, wasmi::Error> {
+    let mut buffer = vec![0; len as usize];
+    memory(caller)?.read(caller, ptr as usize, &mut buffer)?;
+    Ok(buffer)
+}
+
+fn read_string(caller: &Caller<'_, State>, ptr: u32, len: u32) -> Result<String, wasmi::Error> {
+    String::from_utf8(read(caller, ptr, len)?).map_err(|e| wasmi::Error::new(e.to_string()))
+}
+
+fn write(caller: &mut Caller<'_, State>, ptr: u32, data: &[u8]) -> Result<(), wasmi::Error> {
+    memory(caller)?.write(caller, ptr as usize, data)?;
+    Ok(())
+}
+
+/// Write what an import left in the state for the wrapper to fetch
+fn write_pending(
+    caller: &mut Caller<'_, State>,
+    ptr: u32,
+    name: &str,
+    pending: impl Fn(&State) -> Option<Vec<u8>>,
+) -> Result<(), wasmi::Error> {
+    let data = pending(caller.data()).ok_or_else(|| wasmi::Error::new(format!("{}: nothing to read", name)))?;
+    write(caller, ptr, &data)
+}
+
+/// Run the requests of a batch, on as many threads as there are cores at
+/// most
+fn subinvoke_batch(client: &Client, requests: &[SubinvokeRequest]) -> Vec<Result<Vec<u8>, String>> {
+    let workers = std::thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(requests.len());
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; requests.len()]);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let SubinvokeRequest { uri, method, args, options } = match requests.get(index) {
+                        Some(request) => request,
+                        None => break,
+                    };
+                    let result = match &options.env {
+                        Some(env) => client.invoke_with_env(uri, method, args, env),
+                        None => client.invoke(uri, method, args),
+                    };
+                    let result = result.map_err(|error| error.to_string());
+                    results.lock().unwrap_or_else(|e| e.into_inner())[index] = Some(result);
+                })
+            })
+            .collect();
+        // A panicking worker leaves its request without a result
+        for handle in handles {
+            let _ = handle.join();
+        }
+    });
+    results
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|| Err("subinvoke panicked".to_string())))
+        .collect()
+}
+
+fn abort(caller: &mut Caller<'_, State>, report: AbortReport) -> wasmi::Error {
+    let error = wasmi::Error::new(format!("aborted: {}", report.message));
+    caller.data_mut().abort = Some(report);
+    error
+}
+
+fn define_imports(linker: &mut Linker<State>) -> Result<(), wasmi::errors::LinkerError> {
+    linker.func_wrap(
+        "wrap",
+        "__wrap_invoke_args",
+        |mut caller: Caller<'_, State>, method_ptr: u32, args_ptr: u32| {
+            let (method, args) = (caller.data().method.clone(), caller.data().args.clone());
+            write(&mut caller, method_ptr, method.as_bytes())?;
+            write(&mut caller, args_ptr, &args)
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_invoke_result",
+        |mut caller: Caller<'_, State>, ptr: u32, len: u32| {
+            let result = read(&caller, ptr, len)?;
+            caller.data_mut().invoke_result = Some(result);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_invoke_error",
+        |mut caller: Caller<'_, State>, ptr: u32, len: u32| {
+            let error = read_string(&caller, ptr, len)?;
+            caller.data_mut().invoke_error = Some(error);
+            Ok(())
+        },
+    )?;
+
+    linker.func_wrap(
+        "wrap",
+        "__wrap_invoke_caller_len",
+        |caller: Caller<'_, State>| {
+            let state = caller.data();
+            state.client.record_interaction(&state.uri, || Interaction::Caller {
+                caller: state.caller.clone(),
+            });
+            Ok(state.caller.as_ref().map_or(0, String::len) as u32)
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_invoke_caller",
+        |mut caller: Caller<'_, State>, ptr: u32| {
+            write_pending(&mut caller, ptr, "__wrap_invoke_caller", |state| {
+                state.caller.clone().map(String::into_bytes)
+            })
+        },
+    )?;
+
+    linker.func_wrap(
+        "wrap",
+        "__wrap_load_env",
+        |mut caller: Caller<'_, State>, ptr: u32| {
+            let state = caller.data();
+            let env = state.env.clone();
+            state
+                .client
+                .record_interaction(&state.uri, || Interaction::LoadEnv { env: env.clone() });
+            write(&mut caller, ptr, &env)
+        },
+    )?;
+
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke",
+        |mut caller: Caller<'_, State>,
+         uri_ptr: u32,
+         uri_len: u32,
+         method_ptr: u32,
+         method_len: u32,
+         args_ptr: u32,
+         args_len: u32| {
+            let uri = read_string(&caller, uri_ptr, uri_len)?;
+            let method = read_string(&caller, method_ptr, method_len)?;
+            let args = read(&caller, args_ptr, args_len)?;
+
+            let state = caller.data_mut();
+            let result = state.client.invoke(&uri, &method, &args);
+            state.client.record_interaction(&state.uri, || Interaction::Subinvoke {
+                result: result.as_ref().map(Clone::clone).map_err(ToString::to_string),
+                uri,
+                method,
+                args,
+                env: None,
+            });
+            Ok(state.set_subinvoke_result(result))
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_with_options",
+        |mut caller: Caller<'_, State>,
+         uri_ptr: u32,
+         uri_len: u32,
+         method_ptr: u32,
+         method_len: u32,
+         args_ptr: u32,
+         args_len: u32,
+         options_ptr: u32,
+         options_len: u32| {
+            let uri = read_string(&caller, uri_ptr, uri_len)?;
+            let method = read_string(&caller, method_ptr, method_len)?;
+            let args = read(&caller, args_ptr, args_len)?;
+            let options = SubinvokeOptions::from_buffer(&read(&caller, options_ptr, options_len)?)
+                .map_err(|e| wasmi::Error::new(e.to_string()))?;
+
+            let state = caller.data_mut();
+            let result = match &options.env {
+                Some(env) => state.client.invoke_with_env(&uri, &method, &args, env),
+                None => state.client.invoke(&uri, &method, &args),
+            };
+            state.client.record_interaction(&state.uri, || Interaction::Subinvoke {
+                result: result.as_ref().map(Clone::clone).map_err(ToString::to_string),
+                uri,
+                method,
+                args,
+                env: options.env,
+            });
+            Ok(state.set_subinvoke_result(result))
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_batch",
+        |mut caller: Caller<'_, State>, requests_ptr: u32, requests_len: u32| {
+            let requests = deserialize_subinvoke_requests(&read(&caller, requests_ptr, requests_len)?)
+                .map_err(|e| wasmi::Error::new(e.to_string()))?;
+
+            let state = caller.data_mut();
+            let results = subinvoke_batch(&state.client, &requests);
+            for (request, result) in requests.into_iter().zip(&results) {
+                state.client.record_interaction(&state.uri, || Interaction::Subinvoke {
+                    uri: request.uri,
+                    method: request.method,
+                    args: request.args,
+                    env: request.options.env,
+                    result: result.clone(),
+                });
+            }
+            let results = serialize_subinvoke_results(&results).map_err(|e| wasmi::Error::new(e.to_string()))?;
+            state.subinvoke_batch_result = Some(results);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_batch_result_len",
+        |caller: Caller<'_, State>| {
+            let len = caller.data().subinvoke_batch_result.as_ref().map_or(0, Vec::len);
+            Ok(len as u32)
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_batch_result",
+        |mut caller: Caller<'_, State>, ptr: u32| {
+            write_pending(&mut caller, ptr, "__wrap_subinvoke_batch_result", |state| {
+                state.subinvoke_batch_result.clone()
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_result_len",
+        |caller: Caller<'_, State>| {
+            let len = caller.data().subinvoke_result.as_ref().map_or(0, Vec::len);
+            Ok(len as u32)
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_result",
+        |mut caller: Caller<'_, State>, ptr: u32| {
+            write_pending(&mut caller, ptr, "__wrap_subinvoke_result", |state| {
+                state.subinvoke_result.clone()
+            })
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_error_len",
+        |caller: Caller<'_, State>| {
+            let len = caller.data().subinvoke_error.as_ref().map_or(0, String::len);
+            Ok(len as u32)
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_subinvoke_error",
+        |mut caller: Caller<'_, State>, ptr: u32| {
+            write_pending(&mut caller, ptr, "__wrap_subinvoke_error", |state| {
+                state.subinvoke_error.clone().map(String::into_bytes)
+            })
+        },
+    )?;
+
+    linker.func_wrap(
+        "wrap",
+        "__wrap_getImplementations",
+        |mut caller: Caller<'_, State>, uri_ptr: u32, uri_len: u32| {
+            let uri = read_string(&caller, uri_ptr, uri_len)?;
+            let state = caller.data();
+            let implementations = state.client.get_implementations(&uri);
+            state.client.record_interaction(&state.uri, || Interaction::GetImplementations {
+                uri,
+                implementations: implementations.clone(),
+            });
+
+            let mut encoder = WriteEncoder::new(&[], Context::new());
+            encoder
+                .write_array(&implementations, |writer, item| writer.write_string(item))
+                .map_err(|e| wasmi::Error::new(e.to_string()))?;
+            caller.data_mut().implementations = Some(encoder.get_buffer());
+            Ok(u32::from(!implementations.is_empty()))
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_getImplementations_result_len",
+        |caller: Caller<'_, State>| {
+            let len = caller.data().implementations.as_ref().map_or(0, Vec::len);
+            Ok(len as u32)
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_getImplementations_result",
+        |mut caller: Caller<'_, State>, ptr: u32| {
+            write_pending(&mut caller, ptr, "__wrap_getImplementations_result", |state| {
+                state.implementations.clone()
+            })
+        },
+    )?;
+
+    linker.func_wrap(
+        "wrap",
+        "__wrap_abort",
+        |mut caller: Caller<'_, State>,
+         msg_ptr: u32,
+         msg_len: u32,
+         file_ptr: u32,
+         file_len: u32,
+         line: u32,
+         column: u32|
+         -> Result<(), wasmi::Error> {
+            let report = AbortReport {
+                message: read_string(&caller, msg_ptr, msg_len)?,
+                file: Some(read_string(&caller, file_ptr, file_len)?),
+                method: Some(caller.data().method.clone()),
+                line,
+                column,
+                ..Default::default()
+            };
+            Err(abort(&mut caller, report))
+        },
+    )?;
+    linker.func_wrap(
+        "wrap",
+        "__wrap_abort_report",
+        |mut caller: Caller<'_, State>, ptr: u32, len: u32| -> Result<(), wasmi::Error> {
+            let buffer = read(&caller, ptr, len)?;
+            let report = AbortReport::from_buffer(&buffer).unwrap_or_else(|e| {
+                AbortReport::new(&format!("unreadable abort report: {}", e))
+            });
+            Err(abort(&mut caller, report))
+        },
+    )?;
+
+    linker.func_wrap(
+        "wrap",
+        "__wrap_debug_log",
+        |caller: Caller<'_, State>, ptr: u32, len: u32| {
+            let message = read_string(&caller, ptr, len)?;
+            let state = caller.data();
+            state.client.debug_log(&state.uri, &message);
+            Ok(())
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,508 @@
+use polywrap_client::{Client, ClientError, WasmWrapper};
+use polywrap_wasm_rs::{
+    deserialize_subinvoke_results, serialize_subinvoke_requests, AbortReport, Cassette, Context,
+    Interaction, Read, ReadDecoder, SubinvokeOptions, SubinvokeRequest,
+};
+use std::sync::{Arc, Mutex};
+
+/// A wrapper reading its method into memory at 0 and its args at 256.
+/// Methods are told apart by the length of their name.
+fn wrapper(imports: &str, data: &str, body: &str) -> Vec<u8> {
+    wat::parse_str(format!(
+        r#"(module
+            (import "wrap" "__wrap_invoke_args" (func $invoke_args (param i32 i32)))
+            (import "wrap" "__wrap_invoke_result" (func $invoke_result (param i32 i32)))
+            (import "wrap" "__wrap_invoke_error" (func $invoke_error (param i32 i32)))
+            {}
+            (memory (export "memory") 1)
+            (data (i32.const 2048) "unknown method")
+            {}
+            (func (export "_wrap_invoke") (param $method i32) (param $args i32) (param $env i32) (result i32)
+                (call $invoke_args (i32.const 0) (i32.const 256))
+                {}
+                (call $invoke_error (i32.const 2048) (i32.const 14))
+                (i32.const 0)))"#,
+        imports, data, body
+    ))
+    .unwrap()
+}
+
+/// `args` returns the args, `env` the env
+fn echo() -> Vec<u8> {
+    wrapper(
+        r#"(import "wrap" "__wrap_load_env" (func $load_env (param i32)))"#,
+        "",
+        r#"(if (i32.eq (local.get $method) (i32.const 4))
+            (then
+                (call $invoke_result (i32.const 256) (local.get $args))
+                (return (i32.const 1))))
+        (if (i32.eq (local.get $method) (i32.const 3))
+            (then
+                (call $load_env (i32.const 512))
+                (call $invoke_result (i32.const 512) (local.get $env))
+                (return (i32.const 1))))"#,
+    )
+}
+
+/// `forward` subinvokes `args` of the URI it gets as args
+fn forward() -> Vec<u8> {
+    wrapper(
+        r#"(import "wrap" "__wrap_subinvoke" (func $subinvoke (param i32 i32 i32 i32 i32 i32) (result i32)))
+        (import "wrap" "__wrap_subinvoke_result_len" (func $result_len (result i32)))
+        (import "wrap" "__wrap_subinvoke_result" (func $result (param i32)))
+        (import "wrap" "__wrap_subinvoke_error_len" (func $error_len (result i32)))
+        (import "wrap" "__wrap_subinvoke_error" (func $error (param i32)))"#,
+        r#"(data (i32.const 3072) "args")"#,
+        r#"(if (i32.eq (local.get $method) (i32.const 7))
+            (then
+                (if (call $subinvoke
+                        (i32.const 256) (local.get $args)
+                        (i32.const 3072) (i32.const 4)
+                        (i32.const 256) (local.get $args))
+                    (then
+                        (call $result (i32.const 4096))
+                        (call $invoke_result (i32.const 4096) (call $result_len)))
+                    (else
+                        (call $error (i32.const 4096))
+                        (call $invoke_error (i32.const 4096) (call $error_len))
+                        (return (i32.const 0))))
+                (return (i32.const 1))))"#,
+    )
+}
+
+/// `args` returns the URI of its caller
+fn caller() -> Vec<u8> {
+    wrapper(
+        r#"(import "wrap" "__wrap_invoke_caller_len" (func $caller_len (result i32)))
+        (import "wrap" "__wrap_invoke_caller" (func $caller (param i32)))"#,
+        "",
+        r#"(if (i32.eq (local.get $method) (i32.const 4))
+            (then
+                (local.set $args (call $caller_len))
+                (if (local.get $args)
+                    (then (call $caller (i32.const 4096))))
+                (call $invoke_result (i32.const 4096) (local.get $args))
+                (return (i32.const 1))))"#,
+    )
+}
+
+/// `batch` subinvokes the batch it gets as args
+fn batch() -> Vec<u8> {
+    wrapper(
+        r#"(import "wrap" "__wrap_subinvoke_batch" (func $batch (param i32 i32)))
+        (import "wrap" "__wrap_subinvoke_batch_result_len" (func $result_len (result i32)))
+        (import "wrap" "__wrap_subinvoke_batch_result" (func $result (param i32)))"#,
+        "",
+        r#"(if (i32.eq (local.get $method) (i32.const 5))
+            (then
+                (call $batch (i32.const 256) (local.get $args))
+                (call $result (i32.const 4096))
+                (call $invoke_result (i32.const 4096) (call $result_len))
+                (return (i32.const 1))))"#,
+    )
+}
+
+/// Exports `abi` like generated bindings do
+fn with_abi(abi: &[u8]) -> Vec<u8> {
+    wrapper(
+        "",
+        &format!(
+            r#"(data (i32.const 1024) "{}")
+            (func (export "_wrap_abi") (result i32) (i32.const 1024))
+            (func (export "_wrap_abi_len") (result i32) (i32.const {}))"#,
+            data_string(abi),
+            abi.len()
+        ),
+        "",
+    )
+}
+
+/// Escape bytes for a WAT data segment
+fn data_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("\\{:02x}", byte)).collect()
+}
+
+/// `forward` subinvokes `env` of the URI it gets as args, with `options`
+fn forward_with_options(options: &SubinvokeOptions) -> Vec<u8> {
+    let options = options.to_buffer().unwrap();
+    wrapper(
+        r#"(import "wrap" "__wrap_subinvoke_with_options" (func $subinvoke (param i32 i32 i32 i32 i32 i32 i32 i32) (result i32)))
+        (import "wrap" "__wrap_subinvoke_result_len" (func $result_len (result i32)))
+        (import "wrap" "__wrap_subinvoke_result" (func $result (param i32)))"#,
+        &format!(
+            r#"(data (i32.const 3072) "env")
+            (data (i32.const 3200) "{}")"#,
+            data_string(&options)
+        ),
+        &format!(
+            r#"(if (i32.eq (local.get $method) (i32.const 7))
+                (then
+                    (if (call $subinvoke
+                            (i32.const 256) (local.get $args)
+                            (i32.const 3072) (i32.const 3)
+                            (i32.const 256) (i32.const 0)
+                            (i32.const 3200) (i32.const {}))
+                        (then
+                            (call $result (i32.const 4096))
+                            (call $invoke_result (i32.const 4096) (call $result_len))
+                            (return (i32.const 1))))))"#,
+            options.len()
+        ),
+    )
+}
+
+/// `abort` calls `__wrap_abort`, `report` calls `__wrap_abort_report`
+fn aborting(report: &[u8]) -> Vec<u8> {
+    wrapper(
+        r#"(import "wrap" "__wrap_abort" (func $abort (param i32 i32 i32 i32 i32 i32)))
+        (import "wrap" "__wrap_abort_report" (func $abort_report (param i32 i32)))"#,
+        &format!(
+            r#"(data (i32.const 3072) "out of gas")
+            (data (i32.const 3200) "src/lib.rs")
+            (data (i32.const 4096) "{}")"#,
+            data_string(report)
+        ),
+        &format!(
+            r#"(if (i32.eq (local.get $method) (i32.const 5))
+                (then
+                    (call $abort (i32.const 3072) (i32.const 10) (i32.const 3200) (i32.const 10) (i32.const 12) (i32.const 3))))
+            (if (i32.eq (local.get $method) (i32.const 6))
+                (then (call $abort_report (i32.const 4096) (i32.const {}))))"#,
+            report.len()
+        ),
+    )
+}
+
+/// `implementations` logs `interface`, and returns its implementations
+fn implementations() -> Vec<u8> {
+    wrapper(
+        r#"(import "wrap" "__wrap_getImplementations" (func $get (param i32 i32) (result i32)))
+        (import "wrap" "__wrap_getImplementations_result_len" (func $result_len (result i32)))
+        (import "wrap" "__wrap_getImplementations_result" (func $result (param i32)))
+        (import "wrap" "__wrap_debug_log" (func $debug_log (param i32 i32)))"#,
+        r#"(data (i32.const 3072) "interface")"#,
+        r#"(if (i32.eq (local.get $method) (i32.const 15))
+            (then
+                (call $debug_log (i32.const 3072) (i32.const 9))
+                (drop (call $get (i32.const 3072) (i32.const 9)))
+                (call $result (i32.const 4096))
+                (call $invoke_result (i32.const 4096) (call $result_len))
+                (return (i32.const 1))))"#,
+    )
+}
+
+#[test]
+fn invokes_with_args_and_env() {
+    let client = Client::new();
+    client.register_wasm("wrap://echo", &echo()).unwrap();
+
+    assert_eq!(client.invoke("wrap://echo", "args", &[1, 2, 3]).unwrap(), vec![1, 2, 3]);
+    assert_eq!(client.invoke("wrap://echo", "env", &[]).unwrap(), Vec::<u8>::new());
+
+    client.set_env("wrap://echo", vec![0x80]);
+    assert_eq!(client.invoke("wrap://echo", "env", &[]).unwrap(), vec![0x80]);
+    assert_eq!(
+        client.invoke_with_env("wrap://echo", "env", &[], &[0x90]).unwrap(),
+        vec![0x90]
+    );
+}
+
+#[test]
+fn normalizes_uris() {
+    let client = Client::new();
+    client.register_wasm("test/echo", &echo()).unwrap();
+    client.set_env("//test/echo", vec![0x80]);
+
+    assert_eq!(client.invoke("wrap://test/echo", "env", &[]).unwrap(), vec![0x80]);
+    match client.invoke("test/missing", "env", &[]) {
+        Err(ClientError::NotFound(uri)) => assert_eq!(uri, "wrap://test/missing"),
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    client.register_implementation("test/interface", "test/a");
+    client.register_implementation("wrap://test/interface", "wrap://test/a");
+    assert_eq!(client.get_implementations("/test/interface"), vec!["wrap://test/a"]);
+}
+
+#[test]
+fn reports_invoke_errors() {
+    let client = Client::new();
+    client.register_wasm("wrap://echo", &echo()).unwrap();
+
+    match client.invoke("wrap://echo", "missing", &[]) {
+        Err(ClientError::Invoke { uri, method, message }) => {
+            assert_eq!(uri, "wrap://echo");
+            assert_eq!(method, "missing");
+            assert_eq!(message, "unknown method");
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+    assert!(matches!(
+        client.invoke("wrap://missing", "args", &[]),
+        Err(ClientError::NotFound(uri)) if uri == "wrap://missing"
+    ));
+}
+
+#[test]
+fn rejects_invalid_wrappers() {
+    let client = Client::new();
+    let wasm = wat::parse_str("(module (memory (export \"memory\") 1))").unwrap();
+    assert!(matches!(
+        client.register_wasm("wrap://invalid", &wasm),
+        Err(ClientError::InvalidWrapper(_))
+    ));
+    assert!(matches!(
+        client.register_wasm("wrap://invalid", b"not wasm"),
+        Err(ClientError::Wasm(_))
+    ));
+}
+
+#[test]
+fn reads_the_abi() {
+    // {"version": "0.1"}
+    let abi = [0x81, 0xa7, b'v', b'e', b'r', b's', b'i', b'o', b'n', 0xa3, b'0', b'.', b'1'];
+    let wrapper = WasmWrapper::new(&with_abi(&abi)).unwrap();
+    assert_eq!(wrapper.abi().unwrap(), abi);
+
+    match WasmWrapper::new(&echo()).unwrap().abi() {
+        Err(ClientError::InvalidWrapper(message)) => {
+            assert_eq!(message, "missing the \"_wrap_abi\" and \"_wrap_abi_len\" exports")
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn routes_subinvokes() {
+    let client = Client::new();
+    client.register_wasm("wrap://echo", &echo()).unwrap();
+    client.register_wasm("wrap://forward", &forward()).unwrap();
+
+    assert_eq!(
+        client.invoke("wrap://forward", "forward", b"wrap://echo").unwrap(),
+        b"wrap://echo".to_vec()
+    );
+
+    // Subinvoke errors go back to the caller
+    match client.invoke("wrap://forward", "forward", b"wrap://missing") {
+        Err(ClientError::Invoke { message, .. }) => {
+            assert_eq!(message, "No wrapper registered for \"wrap://missing\"")
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    // Wrappers can subinvoke themselves, and `forward` doesn't have `args`
+    match client.invoke("wrap://forward", "forward", b"wrap://forward") {
+        Err(ClientError::Invoke { message, .. }) => {
+            assert_eq!(message, "wrap://forward failed to invoke \"args\": unknown method")
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn subinvokes_with_options() {
+    let client = Client::new();
+    client.register_wasm("wrap://echo", &echo()).unwrap();
+    client.set_env("wrap://echo", vec![0x80]);
+
+    let with_env = SubinvokeOptions {
+        env: Some(vec![0x90]),
+    };
+    client.register_wasm("wrap://with-env", &forward_with_options(&with_env)).unwrap();
+    client.record("wrap://with-env");
+    assert_eq!(client.invoke("wrap://with-env", "forward", b"wrap://echo").unwrap(), vec![0x90]);
+    assert_eq!(
+        client.eject_cassette("wrap://with-env").unwrap().interactions,
+        vec![Interaction::Subinvoke {
+            uri: "wrap://echo".to_string(),
+            method: "env".to_string(),
+            args: vec![],
+            env: Some(vec![0x90]),
+            result: Ok(vec![0x90]),
+        }]
+    );
+
+    // Without an env, the one set for the URI is used
+    client
+        .register_wasm("wrap://without-env", &forward_with_options(&SubinvokeOptions::default()))
+        .unwrap();
+    assert_eq!(client.invoke("wrap://without-env", "forward", b"wrap://echo").unwrap(), vec![0x80]);
+}
+
+#[test]
+fn subinvokes_batches() {
+    let client = Client::new();
+    client.register_wasm("wrap://echo", &echo()).unwrap();
+    client.register_wasm("wrap://batch", &batch()).unwrap();
+    client.set_env("wrap://echo", vec![0x80]);
+
+    let requests = serialize_subinvoke_requests(&[
+        SubinvokeRequest::new("wrap://echo", "args", vec![1, 2, 3]),
+        SubinvokeRequest::new("wrap://echo", "env", vec![]),
+        SubinvokeRequest::new("wrap://echo", "env", vec![]).with_options(SubinvokeOptions {
+            env: Some(vec![0x90]),
+        }),
+        SubinvokeRequest::new("wrap://missing", "args", vec![]),
+    ])
+    .unwrap();
+    client.record("wrap://batch");
+    let results = client.invoke("wrap://batch", "batch", &requests).unwrap();
+
+    // Results come back in the order of the requests
+    assert_eq!(
+        deserialize_subinvoke_results(&results).unwrap(),
+        vec![
+            Ok(vec![1, 2, 3]),
+            Ok(vec![0x80]),
+            Ok(vec![0x90]),
+            Err("No wrapper registered for \"wrap://missing\"".to_string()),
+        ]
+    );
+
+    // Each request is recorded as a subinvoke
+    let interactions = client.eject_cassette("wrap://batch").unwrap().interactions;
+    assert_eq!(interactions.len(), 4);
+    assert_eq!(
+        interactions[2],
+        Interaction::Subinvoke {
+            uri: "wrap://echo".to_string(),
+            method: "env".to_string(),
+            args: vec![],
+            env: Some(vec![0x90]),
+            result: Ok(vec![0x90]),
+        }
+    );
+}
+
+#[test]
+fn records_host_interactions() {
+    let client = Client::new();
+    client.register_wasm("wrap://echo", &echo()).unwrap();
+    client.register_wasm("wrap://forward", &forward()).unwrap();
+    client.set_env("wrap://echo", vec![0x80]);
+
+    client.record("wrap://forward");
+    client.record("wrap://echo");
+    client.invoke("wrap://forward", "forward", b"wrap://echo").unwrap();
+    client.invoke("wrap://forward", "forward", b"wrap://missing").unwrap_err();
+    client.invoke("wrap://echo", "env", &[]).unwrap();
+
+    let cassette = client.eject_cassette("wrap://forward").unwrap();
+    assert_eq!(
+        cassette.interactions,
+        vec![
+            Interaction::Subinvoke {
+                uri: "wrap://echo".to_string(),
+                method: "args".to_string(),
+                args: b"wrap://echo".to_vec(),
+                env: None,
+                result: Ok(b"wrap://echo".to_vec()),
+            },
+            Interaction::Subinvoke {
+                uri: "wrap://missing".to_string(),
+                method: "args".to_string(),
+                args: b"wrap://missing".to_vec(),
+                env: None,
+                result: Err("No wrapper registered for \"wrap://missing\"".to_string()),
+            },
+        ]
+    );
+    assert_eq!(Cassette::from_buffer(&cassette.to_buffer().unwrap()).unwrap(), cassette);
+    assert_eq!(
+        client.eject_cassette("wrap://echo").unwrap().interactions,
+        vec![Interaction::LoadEnv { env: vec![0x80] }]
+    );
+
+    // Recording stops with the eject
+    client.invoke("wrap://echo", "env", &[]).unwrap();
+    assert_eq!(client.eject_cassette("wrap://echo"), None);
+}
+
+#[test]
+fn tells_wrappers_their_caller() {
+    let client = Client::new();
+    client.register_wasm("wrap://test/caller", &caller()).unwrap();
+    client.register_wasm("wrap://test/forward", &forward()).unwrap();
+
+    client.record("wrap://test/caller");
+    assert_eq!(client.invoke("wrap://test/caller", "args", &[]).unwrap(), Vec::<u8>::new());
+    assert_eq!(
+        client.invoke("wrap://test/forward", "forward", b"wrap://test/caller").unwrap(),
+        b"wrap://test/forward".to_vec()
+    );
+    assert_eq!(
+        client.eject_cassette("wrap://test/caller").unwrap().interactions,
+        vec![
+            Interaction::Caller { caller: None },
+            Interaction::Caller {
+                caller: Some("wrap://test/forward".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn surfaces_aborts() {
+    let mut expected = AbortReport::new("division by zero").with_code(3);
+    expected.source = vec!["arithmetic".to_string()];
+    let client = Client::new();
+    client
+        .register_wasm("wrap://abort", &aborting(&AbortReport::to_buffer(&expected).unwrap()))
+        .unwrap();
+
+    match client.invoke("wrap://abort", "abort", &[]) {
+        Err(error @ ClientError::Abort { .. }) => {
+            assert_eq!(
+                error.to_string(),
+                "wrap://abort aborted: out of gas (in abort) at src/lib.rs:12:3"
+            );
+            let ClientError::Abort { report, .. } = error else { unreachable!() };
+            assert_eq!(report.file.as_deref(), Some("src/lib.rs"));
+            assert_eq!((report.line, report.column), (12, 3));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    match client.invoke("wrap://abort", "report", &[]) {
+        Err(ClientError::Abort { uri, report }) => {
+            assert_eq!(uri, "wrap://abort");
+            assert_eq!(*report, expected);
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn gets_implementations() {
+    let client = Client::new();
+    let logs = Arc::new(Mutex::new(vec![]));
+    let sink = logs.clone();
+    client.on_debug_log(move |uri, message| sink.lock().unwrap().push(format!("{}: {}", uri, message)));
+    client.register_wasm("wrap://implementations", &implementations()).unwrap();
+    client.register_implementation("interface", "wrap://a");
+    client.register_implementation("interface", "wrap://b");
+    client.register_implementation("interface", "wrap://a");
+
+    let result = client.invoke("wrap://implementations", "implementations", &[]).unwrap();
+    let mut decoder = ReadDecoder::new(&result, Context::new());
+    let implementations = decoder.read_array(|reader| reader.read_string()).unwrap();
+    assert_eq!(implementations, vec!["wrap://a", "wrap://b"]);
+    assert_eq!(*logs.lock().unwrap(), vec!["wrap://implementations: interface"]);
+}
+
+#[test]
+fn invokes_assemblyscript_wrappers() {
+    let wasm = include_bytes!("../../../../js/test-env/src/wrappers/ens/wrap.wasm");
+    let client = Client::new();
+    client.register_wasm("wrap://ens/ens.polywrap.eth", wasm).unwrap();
+
+    // Without its arguments, `getOwner` aborts while decoding them
+    match client.invoke("wrap://ens/ens.polywrap.eth", "getOwner", &[0x80]) {
+        Err(ClientError::Abort { report, .. }) => {
+            assert!(report.message.starts_with("Missing required argument: 'domain: String'"));
+            assert_eq!(report.method.as_deref(), Some("getOwner"));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
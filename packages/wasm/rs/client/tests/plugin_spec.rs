@@ -0,0 +1,145 @@
+use polywrap_client::{Client, ClientError, Plugin, PluginContext, PluginError};
+use polywrap_wasm_rs::{Context, DecodeError, Read, ReadDecoder, Write, WriteEncoder};
+use std::sync::{Arc, Mutex};
+
+/// Keeps the messages it's sent
+struct Logger {
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl Plugin for Logger {
+    fn invoke(
+        &self,
+        context: &PluginContext<'_>,
+        method: &str,
+        args: &mut ReadDecoder,
+        result: &mut WriteEncoder,
+    ) -> Result<(), PluginError> {
+        match method {
+            "log" => {
+                let mut message = None;
+                for _ in 0..args.read_map_length()? {
+                    match args.read_string()?.as_str() {
+                        "message" => message = Some(args.read_string()?),
+                        field => return Err(DecodeError::UnknownFieldName(field.to_string()).into()),
+                    }
+                }
+                let message = message.ok_or_else(|| DecodeError::MissingField("message".to_string()))?;
+                self.messages.lock().unwrap().push(message);
+                result.write_bool(&true)?;
+            }
+            "prefix" => {
+                let prefix = match context.env.is_empty() {
+                    true => None,
+                    false => Some(context.env_decoder().read_string()?),
+                };
+                result.write_optional_string(&prefix)?;
+            }
+            "forward" => {
+                let forwarded = context.client.invoke("wrap://missing", "log", &[0x80])?;
+                result.write_bytes(&forwarded)?;
+            }
+            _ => return Err(PluginError::UnknownMethod(method.to_string())),
+        }
+        Ok(())
+    }
+}
+
+fn log_args(message: &str) -> Vec<u8> {
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_map_length(&1).unwrap();
+    encoder.write_string("message").unwrap();
+    encoder.write_string(message).unwrap();
+    encoder.get_buffer()
+}
+
+fn logger(client: &Client) -> Arc<Mutex<Vec<String>>> {
+    let messages = Arc::new(Mutex::new(vec![]));
+    client.register_plugin(
+        "wrap://plugin/logger",
+        Logger {
+            messages: messages.clone(),
+        },
+    );
+    messages
+}
+
+/// Subinvokes `log` of `wrap://plugin/logger` with its own args
+fn log_wrapper() -> Vec<u8> {
+    wat::parse_str(
+        r#"(module
+            (import "wrap" "__wrap_invoke_args" (func $invoke_args (param i32 i32)))
+            (import "wrap" "__wrap_invoke_result" (func $invoke_result (param i32 i32)))
+            (import "wrap" "__wrap_subinvoke" (func $subinvoke (param i32 i32 i32 i32 i32 i32) (result i32)))
+            (import "wrap" "__wrap_subinvoke_result_len" (func $result_len (result i32)))
+            (import "wrap" "__wrap_subinvoke_result" (func $result (param i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 2048) "wrap://plugin/logger")
+            (data (i32.const 2112) "log")
+            (func (export "_wrap_invoke") (param $method i32) (param $args i32) (param $env i32) (result i32)
+                (call $invoke_args (i32.const 0) (i32.const 256))
+                (drop (call $subinvoke
+                    (i32.const 2048) (i32.const 20)
+                    (i32.const 2112) (i32.const 3)
+                    (i32.const 256) (local.get $args)))
+                (call $result (i32.const 4096))
+                (call $invoke_result (i32.const 4096) (call $result_len))
+                (i32.const 1)))"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn invokes_plugins() {
+    let client = Client::new();
+    let messages = logger(&client);
+
+    let result = client.invoke("wrap://plugin/logger", "log", &log_args("hello")).unwrap();
+    assert!(ReadDecoder::new(&result, Context::new()).read_bool().unwrap());
+    assert_eq!(*messages.lock().unwrap(), vec!["hello"]);
+}
+
+#[test]
+fn subinvokes_plugins_from_wasm() {
+    let client = Client::new();
+    let messages = logger(&client);
+    client.register_wasm("wrap://wasm/log", &log_wrapper()).unwrap();
+
+    let result = client.invoke("wrap://wasm/log", "log", &log_args("from wasm")).unwrap();
+    assert!(ReadDecoder::new(&result, Context::new()).read_bool().unwrap());
+    assert_eq!(*messages.lock().unwrap(), vec!["from wasm"]);
+}
+
+#[test]
+fn passes_env_to_plugins() {
+    let client = Client::new();
+    logger(&client);
+
+    let prefix = |client: &Client| {
+        let result = client.invoke("wrap://plugin/logger", "prefix", &[0x80]).unwrap();
+        ReadDecoder::new(&result, Context::new()).read_optional_string().unwrap()
+    };
+    assert_eq!(prefix(&client), None);
+
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_string("[info]").unwrap();
+    client.set_env("wrap://plugin/logger", encoder.get_buffer());
+    assert_eq!(prefix(&client).as_deref(), Some("[info]"));
+}
+
+#[test]
+fn reports_plugin_errors() {
+    let client = Client::new();
+    logger(&client);
+
+    let message = |method: &str, args: &[u8]| match client.invoke("wrap://plugin/logger", method, args) {
+        Err(ClientError::Invoke { message, .. }) => message,
+        result => panic!("unexpected result {:?}", result),
+    };
+    assert_eq!(message("missing", &[0x80]), "Unknown method \"missing\"");
+    assert_eq!(message("log", &[0x80]), "Missing required field: 'message'");
+    assert_eq!(
+        message("forward", &[0x80]),
+        "No wrapper registered for \"wrap://missing\""
+    );
+}
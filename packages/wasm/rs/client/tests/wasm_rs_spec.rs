@@ -0,0 +1,157 @@
+use polywrap_bindgen::{generate_bindings, write_output};
+use polywrap_client::{Client, ClientError};
+use polywrap_wasm_rs::wrap_manifest::WrapAbi;
+use polywrap_wasm_rs::{Context, ReadDecoder, Write, WriteEncoder};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const TARGET: &str = "wasm32-unknown-unknown";
+
+/// The `simple-env-types` wrapper of the test cases
+fn test_case() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../test-cases/cases/wrappers/wasm-rs/simple-env-types")
+}
+
+/// The ABI of `test_case`'s schema
+fn abi() -> WrapAbi {
+    let string = |name: &str, required: bool| {
+        json!({
+            "type": "String", "name": name, "kind": 34, "required": required,
+            "scalar": { "type": "String", "name": name, "kind": 4, "required": required },
+        })
+    };
+    let json = json!({
+        "version": "0.1",
+        "moduleType": {
+            "type": "Module",
+            "kind": 128,
+            "methods": [{
+                "type": "Method",
+                "name": "getEnv",
+                "kind": 64,
+                "required": true,
+                "arguments": [string("arg", true)],
+                "return": {
+                    "type": "Env", "name": "getEnv", "kind": 34,
+                    "object": { "type": "Env", "name": "getEnv", "kind": 8192 },
+                },
+                "env": { "required": false },
+            }],
+        },
+        "envType": {
+            "type": "Env",
+            "kind": 65536,
+            "properties": [
+                string("str", false),
+                {
+                    "type": "Int", "name": "requiredInt", "kind": 34, "required": true,
+                    "scalar": { "type": "Int", "name": "requiredInt", "kind": 4, "required": true },
+                },
+            ],
+        },
+    });
+
+    let mut writer = WriteEncoder::new(&[], Context::new());
+    write_json(&json, &mut writer);
+    WrapAbi::read(&mut ReadDecoder::new(&writer.get_buffer(), Context::new())).unwrap()
+}
+
+fn write_json(value: &Value, writer: &mut WriteEncoder) {
+    match value {
+        Value::Null => writer.write_nil().unwrap(),
+        Value::Bool(b) => writer.write_bool(b).unwrap(),
+        Value::Number(n) => writer.write_u32(&(n.as_u64().unwrap() as u32)).unwrap(),
+        Value::String(s) => writer.write_string(s).unwrap(),
+        Value::Array(items) => {
+            writer.write_array_length(&(items.len() as u32)).unwrap();
+            for item in items {
+                write_json(item, writer);
+            }
+        }
+        Value::Object(object) => {
+            writer.write_map_length(&(object.len() as u32)).unwrap();
+            for (key, value) in object {
+                writer.write_string(key).unwrap();
+                write_json(value, writer);
+            }
+        }
+    }
+}
+
+/// Whether the standard library of `TARGET` is installed
+fn has_target() -> bool {
+    let sysroot = Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+        .args(["--print", "sysroot"])
+        .output()
+        .unwrap();
+    let sysroot = String::from_utf8(sysroot.stdout).unwrap();
+    Path::new(sysroot.trim()).join("lib/rustlib").join(TARGET).exists()
+}
+
+/// Build `test_case` against this polywrap-wasm-rs, with the bindings of
+/// polywrap-bindgen
+fn build_wrapper() -> Vec<u8> {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("simple-env-types");
+    let runtime = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").canonicalize().unwrap();
+    let manifest = std::fs::read_to_string(test_case().join("Cargo.toml"))
+        .unwrap()
+        .replace("\"../../../../../wasm/rs\"", &format!("{:?}", runtime))
+        // Not a member of the workspace it's built in
+        + "\n[workspace]\n";
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    std::fs::copy(test_case().join("src/lib.rs"), dir.join("src/lib.rs")).unwrap();
+    write_output(&generate_bindings(&abi()).unwrap(), dir.join("src/wrap")).unwrap();
+
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["build", "--release", "--target", TARGET])
+        .current_dir(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to build {}", dir.display());
+    std::fs::read(dir.join("target").join(TARGET).join("release/env_types.wasm")).unwrap()
+}
+
+fn encode_env(str: &str, required_int: i32) -> Vec<u8> {
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_map_length(&2).unwrap();
+    encoder.write_string("str").unwrap();
+    encoder.write_string(str).unwrap();
+    encoder.write_string("requiredInt").unwrap();
+    encoder.write_i32(&required_int).unwrap();
+    encoder.get_buffer()
+}
+
+#[test]
+fn invokes_wrappers_built_with_polywrap_wasm_rs() {
+    if !has_target() {
+        // CI installs the target, so the wrapper is always tested there
+        assert!(std::env::var_os("CI").is_none(), "the {} target isn't installed", TARGET);
+        eprintln!("skipping, the {} target isn't installed", TARGET);
+        return;
+    }
+    let client = Client::new();
+    client.register_wasm("ens/env-types.eth", &build_wrapper()).unwrap();
+
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_map_length(&1).unwrap();
+    encoder.write_string("arg").unwrap();
+    encoder.write_string("arg").unwrap();
+    let args = encoder.get_buffer();
+
+    // Without an env, `getEnv` returns none
+    assert_eq!(client.invoke("wrap://ens/env-types.eth", "getEnv", &args).unwrap(), vec![0xc0]);
+
+    // Without its required argument, `getEnv` aborts with where decoding failed
+    match client.invoke("ens/env-types.eth", "getEnv", &[0x80]) {
+        Err(ClientError::Abort { report, .. }) => assert!(report.message.contains(
+            "Context: Deserializing module-type: get_env\n  at arg : String >> missing required argument"
+        )),
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    let env = encode_env("env", 3);
+    client.set_env("wrap://ens/env-types.eth", env.clone());
+    assert_eq!(client.invoke("ens/env-types.eth", "getEnv", &args).unwrap(), env);
+}
@@ -0,0 +1,60 @@
+//! Runtime description of a wrapper.
+//!
+//! The bindings embed the wrapper's ABI, the same data as the `abi` of its
+//! `wrap.info`, and export it so hosts can check method names and argument
+//! types before invoking anything:
+//!
+//! - `_wrap_abi() -> u32` returns the address of the msgpack encoded ABI
+//!   in the wrapper's memory
+//! - `_wrap_abi_len() -> u32` returns its length
+//!
+//! The ABI is static, so reading it doesn't run an invocation or call any
+//! host import. Hosts call both exports on an instance and read the bytes
+//! from its memory. The bindings also generate a [`MethodInfo`] table, for
+//! code in the wrapper listing its own methods.
+
+/// Whether a method needs the client to provide an env
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnvRequirement {
+    None,
+    Optional,
+    Required,
+}
+
+impl EnvRequirement {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnvRequirement::None => "none",
+            EnvRequirement::Optional => "optional",
+            EnvRequirement::Required => "required",
+        }
+    }
+}
+
+/// A method argument, with its schema type (e.g. `[String!]`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArgumentInfo {
+    pub name: &'static str,
+    pub type_name: &'static str,
+}
+
+/// A method exported by the wrapper
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MethodInfo {
+    pub name: &'static str,
+    pub arguments: &'static [ArgumentInfo],
+    pub return_type: &'static str,
+    pub env: EnvRequirement,
+}
+
+impl MethodInfo {
+    /// Find the method called `name` in `methods`
+    pub fn find<'a>(methods: &'a [MethodInfo], name: &str) -> Option<&'a MethodInfo> {
+        methods.iter().find(|method| method.name == name)
+    }
+}
+
+/// Helper for handling `_wrap_abi`: the address of the encoded ABI
+pub fn wrap_abi(abi: &'static [u8]) -> u32 {
+    abi.as_ptr() as u32
+}
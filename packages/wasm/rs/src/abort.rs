@@ -1,3 +1,7 @@
+use crate::{
+    wrap_invoke_method, Context, DecodeError, EncodeError, Read, ReadDecoder, Write, WriteEncoder,
+};
+
 #[link(wasm_import_module = "wrap")]
 extern "C" {
     /// Get Abort Arguments
@@ -10,44 +14,229 @@ extern "C" {
         line: u32,
         column: u32,
     );
+
+    /// Abort with a msgpack encoded `AbortReport`
+    #[cfg(feature = "abort_report")]
+    #[link_name = "__wrap_abort_report"]
+    pub fn __wrap_abort_report(report_ptr: u32, report_len: u32);
+}
+
+/// Structured description of why a wrapper aborted
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AbortReport {
+    pub message: String,
+    /// The error's source chain, outermost first
+    pub source: Vec<String>,
+    pub context_description: Option<String>,
+    /// The active `Context` stack, innermost frame first
+    pub context: Vec<String>,
+    pub method: Option<String>,
+    pub code: Option<u32>,
+    pub file: Option<String>,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl AbortReport {
+    pub fn new(message: &str) -> AbortReport {
+        AbortReport {
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Build a report from an error and its `source()` chain
+    pub fn from_error(error: &(dyn std::error::Error + 'static)) -> AbortReport {
+        let mut source = vec![];
+        let mut next = error.source();
+        while let Some(e) = next {
+            source.push(e.to_string());
+            next = e.source();
+        }
+        AbortReport {
+            message: error.to_string(),
+            source,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_code(mut self, code: u32) -> AbortReport {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_method(mut self, method: &str) -> AbortReport {
+        self.method = Some(method.to_string());
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> AbortReport {
+        self.context_description = Some(context.description.clone());
+        self.context = context.stack();
+        self
+    }
+
+    /// Fill in the method name and the context of the last decoding error,
+    /// when the report doesn't set them
+    pub fn complete(mut self) -> AbortReport {
+        if self.method.is_none() {
+            self.method = wrap_invoke_method();
+        }
+        if self.context.is_empty() {
+            if let Some(context) = Context::take_error_context() {
+                self = self.with_context(&context);
+            }
+        }
+        self
+    }
+
+    /// The message with the report's details, like hosts print reports
+    pub fn describe(&self) -> String {
+        let mut message = self.message.clone();
+        if let Some(method) = &self.method {
+            message.push_str(&format!("\nMethod: {}", method));
+        }
+        if let Some(code) = self.code {
+            message.push_str(&format!("\nCode: {}", code));
+        }
+        for source in &self.source {
+            message.push_str(&format!("\nCaused by: {}", source));
+        }
+        if !self.context.is_empty() {
+            let description = self.context_description.as_deref().unwrap_or_default();
+            message.push_str(&format!("\nContext: {}", description));
+            for frame in &self.context {
+                message.push_str(&format!("\n  {}", frame));
+            }
+        }
+        message
+    }
+
+    pub fn to_buffer(args: &AbortReport) -> Result<Vec<u8>, EncodeError> {
+        let mut encoder_context = Context::new();
+        encoder_context.description = "Serializing (encoding) AbortReport".to_string();
+        let mut encoder = WriteEncoder::new(&[], encoder_context);
+        AbortReport::write(args, &mut encoder)?;
+        Ok(encoder.get_buffer())
+    }
+
+    pub fn from_buffer(args: &[u8]) -> Result<AbortReport, DecodeError> {
+        let mut context = Context::new();
+        context.description = "Deserializing AbortReport".to_string();
+        let mut reader = ReadDecoder::new(args, context);
+        AbortReport::read(&mut reader)
+    }
+
+    pub fn write<W: Write>(args: &AbortReport, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_map_length(&9)?;
+        writer.write_string("message")?;
+        writer.write_string(&args.message)?;
+        writer.write_string("source")?;
+        writer.write_array(&args.source, |writer, item| writer.write_string(item))?;
+        writer.write_string("contextDescription")?;
+        writer.write_optional_string(&args.context_description)?;
+        writer.write_string("context")?;
+        writer.write_array(&args.context, |writer, item| writer.write_string(item))?;
+        writer.write_string("method")?;
+        writer.write_optional_string(&args.method)?;
+        writer.write_string("code")?;
+        writer.write_optional_u32(&args.code)?;
+        writer.write_string("file")?;
+        writer.write_optional_string(&args.file)?;
+        writer.write_string("line")?;
+        writer.write_u32(&args.line)?;
+        writer.write_string("column")?;
+        writer.write_u32(&args.column)?;
+        Ok(())
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<AbortReport, DecodeError> {
+        let mut num_of_fields = reader.read_map_length()?;
+        let mut report = AbortReport::default();
+        let mut message_set = false;
+
+        while num_of_fields > 0 {
+            num_of_fields -= 1;
+            let field = reader.read_string()?;
+
+            match field.as_str() {
+                "message" => {
+                    report.message = reader.read_string()?;
+                    message_set = true;
+                }
+                "source" => report.source = reader.read_array(|reader| reader.read_string())?,
+                "contextDescription" => {
+                    report.context_description = reader.read_optional_string()?
+                }
+                "context" => report.context = reader.read_array(|reader| reader.read_string())?,
+                "method" => report.method = reader.read_optional_string()?,
+                "code" => report.code = reader.read_optional_u32()?,
+                "file" => report.file = reader.read_optional_string()?,
+                "line" => report.line = reader.read_u32()?,
+                "column" => report.column = reader.read_u32()?,
+                err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            }
+        }
+        if !message_set {
+            return Err(DecodeError::MissingField("message: String.".to_string()));
+        }
+        Ok(report)
+    }
+}
+
+/// Abort the current invocation with `report`, without panicking.
+///
+/// The report is [completed](AbortReport::complete) first.
+///
+/// With the `abort_report` feature, the report is sent msgpack encoded
+/// through `__wrap_abort_report`, which older hosts don't provide.
+/// Otherwise it's described in the message of `__wrap_abort`.
+pub fn wrap_abort(report: AbortReport) -> ! {
+    let report = report.complete();
+
+    #[cfg(feature = "abort_report")]
+    if let Ok(buf) = AbortReport::to_buffer(&report) {
+        unsafe { __wrap_abort_report(buf.as_ptr() as u32, buf.len() as u32) };
+        std::process::abort()
+    }
+
+    let message = report.describe();
+    let file = report.file.as_deref().unwrap_or("unknown file");
+    unsafe {
+        __wrap_abort(
+            message.as_ptr() as u32,
+            message.len() as u32,
+            file.as_ptr() as u32,
+            file.len() as u32,
+            report.line,
+            report.column,
+        )
+    };
+    std::process::abort()
 }
 
 /// Helper for aborting
 pub fn wrap_abort_setup() {
     std::panic::set_hook(Box::new(|panic_info| {
         let payload = panic_info.payload();
-        let message = match payload
-            .downcast_ref::<String>()
-            .map(String::as_str)
-            .or_else(|| payload.downcast_ref::<&'static str>().copied())
-        {
-            Some(msg) => msg.to_string(),
-            None => "unknown error".to_string(),
-        };
-        let msg_len = message.len() as u32;
-        let location = panic_info.location();
-        let file = match location {
-            Some(location) => location.file(),
-            None => "unknown file",
-        };
-        let file_len = file.len() as u32;
-        let line = match location {
-            Some(location) => location.line(),
-            None => 0,
-        };
-        let column = match location {
-            Some(location) => location.column(),
-            None => 0,
-        };
-        unsafe {
-            __wrap_abort(
-                message.as_ptr() as u32,
-                msg_len,
-                file.as_ptr() as u32,
-                file_len,
-                line,
-                column,
-            )
+        let mut report = match payload.downcast_ref::<AbortReport>() {
+            Some(report) => report.clone(),
+            None => {
+                let message = payload
+                    .downcast_ref::<String>()
+                    .map(String::as_str)
+                    .or_else(|| payload.downcast_ref::<&'static str>().copied())
+                    .unwrap_or("unknown error");
+                AbortReport::new(message)
+            }
         };
+        if report.file.is_none() {
+            if let Some(location) = panic_info.location() {
+                report.file = Some(location.file().to_string());
+                report.line = location.line();
+                report.column = location.column();
+            }
+        }
+        wrap_abort(report)
     }))
 }
@@ -0,0 +1,388 @@
+//! Recording and replaying what a wrapper asks of its host.
+//!
+//! A [`Cassette`] holds the requests a wrapper made through
+//! [`wrap_subinvoke`](crate::wrap_subinvoke),
+//! [`wrap_subinvoke_with_options`](crate::wrap_subinvoke_with_options),
+//! [`wrap_subinvoke_batch`](crate::wrap_subinvoke_batch),
+//! [`wrap_load_env`](crate::wrap_load_env),
+//! [`wrap_get_implementations`](crate::wrap_get_implementations) and
+//! [`InvocationContext::caller`](crate::InvocationContext::caller), with
+//! the host's responses. After [`wrap_record`], those helpers add what they
+//! send and receive to a cassette. After [`wrap_replay`], they answer from
+//! one and never call the host, panicking at requests it has no answer
+//! for, so wrapper code can be tested natively with `cargo test` against
+//! traffic captured from a real host:
+//!
+//! ```no_run
+//! use polywrap_wasm_rs::{wrap_eject_cassette, wrap_replay, Cassette};
+//!
+//! wrap_replay(Cassette::load("tests/cassettes/resolve.wrap").unwrap());
+//! // ... call the wrapper's methods
+//! assert_eq!(wrap_eject_cassette().unwrap().interactions, vec![]);
+//! ```
+//!
+//! Cassettes are msgpack encoded. Each request of a batch is recorded, and
+//! replayed, as a subinvoke of its own.
+
+use crate::{
+    Context, DecodeError, EncodeError, Read, ReadDecoder, SubinvokeRequest, Write, WriteEncoder,
+};
+use std::cell::RefCell;
+use std::path::Path;
+use thiserror::Error;
+
+/// One request to the host, and its response
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Interaction {
+    Subinvoke {
+        uri: String,
+        method: String,
+        args: Vec<u8>,
+        /// The env the subinvoke was made with, if it overrode the client's
+        env: Option<Vec<u8>>,
+        result: Result<Vec<u8>, String>,
+    },
+    LoadEnv {
+        env: Vec<u8>,
+    },
+    GetImplementations {
+        uri: String,
+        implementations: Vec<String>,
+    },
+    Caller {
+        caller: Option<String>,
+    },
+}
+
+/// Interactions with the host, in the order they happened
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Error)]
+pub enum CassetteError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Encode(#[from] EncodeError),
+    #[error("{0}")]
+    Decode(#[from] DecodeError),
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Record,
+    Replay,
+}
+
+thread_local! {
+    static CASSETTE: RefCell<Option<(Mode, Cassette)>> = const { RefCell::new(None) };
+}
+
+/// Start recording into an empty cassette
+pub fn wrap_record() {
+    CASSETTE.with(|cassette| *cassette.borrow_mut() = Some((Mode::Record, Cassette::default())));
+}
+
+/// Answer from `cassette` instead of calling the host. Each interaction
+/// answers once, and requests without one panic.
+pub fn wrap_replay(cassette: Cassette) {
+    CASSETTE.with(|current| *current.borrow_mut() = Some((Mode::Replay, cassette)));
+}
+
+/// Stop recording or replaying. Returns what was recorded, or the
+/// interactions which weren't replayed.
+pub fn wrap_eject_cassette() -> Option<Cassette> {
+    CASSETTE.with(|cassette| cassette.borrow_mut().take().map(|(_, cassette)| cassette))
+}
+
+impl Interaction {
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        match self {
+            Interaction::Subinvoke {
+                uri,
+                method,
+                args,
+                env,
+                result,
+            } => {
+                writer.write_map_length(&(5 + env.is_some() as u32))?;
+                writer.write_string("kind")?;
+                writer.write_string("subinvoke")?;
+                writer.write_string("uri")?;
+                writer.write_string(uri)?;
+                writer.write_string("method")?;
+                writer.write_string(method)?;
+                writer.write_string("args")?;
+                writer.write_bytes(args)?;
+                if let Some(env) = env {
+                    writer.write_string("env")?;
+                    writer.write_bytes(env)?;
+                }
+                match result {
+                    Ok(result) => {
+                        writer.write_string("result")?;
+                        writer.write_bytes(result)?;
+                    }
+                    Err(error) => {
+                        writer.write_string("error")?;
+                        writer.write_string(error)?;
+                    }
+                }
+            }
+            Interaction::LoadEnv { env } => {
+                writer.write_map_length(&2)?;
+                writer.write_string("kind")?;
+                writer.write_string("loadEnv")?;
+                writer.write_string("env")?;
+                writer.write_bytes(env)?;
+            }
+            Interaction::GetImplementations {
+                uri,
+                implementations,
+            } => {
+                writer.write_map_length(&3)?;
+                writer.write_string("kind")?;
+                writer.write_string("getImplementations")?;
+                writer.write_string("uri")?;
+                writer.write_string(uri)?;
+                writer.write_string("implementations")?;
+                writer.write_array(implementations, |writer, item| writer.write_string(item))?;
+            }
+            Interaction::Caller { caller } => {
+                writer.write_map_length(&(1 + caller.is_some() as u32))?;
+                writer.write_string("kind")?;
+                writer.write_string("caller")?;
+                if let Some(caller) = caller {
+                    writer.write_string("caller")?;
+                    writer.write_string(caller)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<Interaction, DecodeError> {
+        let mut num_of_fields = reader.read_map_length()?;
+        let mut kind = None;
+        let mut uri = None;
+        let mut method = None;
+        let mut args = None;
+        let mut result = None;
+        let mut env = None;
+        let mut implementations = None;
+        let mut caller = None;
+
+        while num_of_fields > 0 {
+            num_of_fields -= 1;
+            let field = reader.read_string()?;
+
+            match field.as_str() {
+                "kind" => kind = Some(reader.read_string()?),
+                "uri" => uri = Some(reader.read_string()?),
+                "method" => method = Some(reader.read_string()?),
+                "args" => args = Some(reader.read_bytes()?),
+                "result" => result = Some(Ok(reader.read_bytes()?)),
+                "error" => result = Some(Err(reader.read_string()?)),
+                "env" => env = Some(reader.read_bytes()?),
+                "implementations" => {
+                    implementations = Some(reader.read_array(|reader| reader.read_string())?)
+                }
+                "caller" => caller = Some(reader.read_string()?),
+                err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            }
+        }
+
+        let missing = |field: &str| DecodeError::MissingField(field.to_string());
+        match kind.as_deref() {
+            Some("subinvoke") => Ok(Interaction::Subinvoke {
+                uri: uri.ok_or_else(|| missing("uri: String."))?,
+                method: method.ok_or_else(|| missing("method: String."))?,
+                args: args.ok_or_else(|| missing("args: Bytes."))?,
+                env,
+                result: result.ok_or_else(|| missing("result: Bytes or error: String."))?,
+            }),
+            Some("loadEnv") => Ok(Interaction::LoadEnv {
+                env: env.ok_or_else(|| missing("env: Bytes."))?,
+            }),
+            Some("getImplementations") => Ok(Interaction::GetImplementations {
+                uri: uri.ok_or_else(|| missing("uri: String."))?,
+                implementations: implementations.ok_or_else(|| missing("implementations: [String]."))?,
+            }),
+            Some("caller") => Ok(Interaction::Caller { caller }),
+            Some(kind) => Err(DecodeError::UnknownFieldName(kind.to_string())),
+            None => Err(missing("kind: String.")),
+        }
+    }
+}
+
+impl Cassette {
+    pub fn to_buffer(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut encoder = WriteEncoder::new(&[], Context::with_description("Serializing Cassette"));
+        encoder.write_array(&self.interactions, |writer, interaction| interaction.write(writer))?;
+        Ok(encoder.get_buffer())
+    }
+
+    pub fn from_buffer(buffer: &[u8]) -> Result<Cassette, DecodeError> {
+        let mut reader = ReadDecoder::new(buffer, Context::with_description("Deserializing Cassette"));
+        Ok(Cassette {
+            interactions: reader.read_array(Interaction::read)?,
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CassetteError> {
+        std::fs::write(path, self.to_buffer()?)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Cassette, CassetteError> {
+        Ok(Cassette::from_buffer(&std::fs::read(path)?)?)
+    }
+}
+
+fn mode() -> Option<Mode> {
+    CASSETTE.with(|cassette| cassette.borrow().as_ref().map(|(mode, _)| *mode))
+}
+
+fn record(interaction: Interaction) {
+    CASSETTE.with(|cassette| {
+        if let Some((_, cassette)) = cassette.borrow_mut().as_mut() {
+            cassette.interactions.push(interaction);
+        }
+    })
+}
+
+/// Take the first interaction which `matches`
+fn replay(matches: impl Fn(&Interaction) -> bool) -> Option<Interaction> {
+    CASSETTE.with(|cassette| {
+        let mut cassette = cassette.borrow_mut();
+        let interactions = &mut cassette.as_mut()?.1.interactions;
+        let index = interactions.iter().position(matches)?;
+        Some(interactions.remove(index))
+    })
+}
+
+/// Subinvoke with `subinvoke`, unless a cassette is replaying
+pub(crate) fn cassette_subinvoke(
+    uri: &str,
+    method: &str,
+    args: &[u8],
+    env: Option<&[u8]>,
+    subinvoke: impl FnOnce() -> Result<Vec<u8>, String>,
+) -> Result<Vec<u8>, String> {
+    match mode() {
+        None => subinvoke(),
+        Some(Mode::Record) => {
+            let result = subinvoke();
+            record(Interaction::Subinvoke {
+                uri: uri.to_string(),
+                method: method.to_string(),
+                args: args.to_vec(),
+                env: env.map(<[u8]>::to_vec),
+                result: result.clone(),
+            });
+            result
+        }
+        Some(Mode::Replay) => replay_subinvoke(uri, method, args, env),
+    }
+}
+
+/// Run a batch with `send`, unless a cassette is replaying
+pub(crate) fn cassette_subinvoke_batch(
+    requests: &[SubinvokeRequest],
+    send: impl FnOnce() -> Vec<Result<Vec<u8>, String>>,
+) -> Vec<Result<Vec<u8>, String>> {
+    match mode() {
+        None => send(),
+        Some(Mode::Record) => {
+            let results = send();
+            for (request, result) in requests.iter().zip(&results) {
+                record(Interaction::Subinvoke {
+                    uri: request.uri.clone(),
+                    method: request.method.clone(),
+                    args: request.args.clone(),
+                    env: request.options.env.clone(),
+                    result: result.clone(),
+                });
+            }
+            results
+        }
+        Some(Mode::Replay) => requests
+            .iter()
+            .map(|request| {
+                let env = request.options.env.as_deref();
+                replay_subinvoke(&request.uri, &request.method, &request.args, env)
+            })
+            .collect(),
+    }
+}
+
+fn replay_subinvoke(uri: &str, method: &str, args: &[u8], env: Option<&[u8]>) -> Result<Vec<u8>, String> {
+    match replay(|interaction| {
+        matches!(interaction, Interaction::Subinvoke { uri: u, method: m, args: a, env: e, .. }
+            if u == uri && m == method && a == args && e.as_deref() == env)
+    }) {
+        Some(Interaction::Subinvoke { result, .. }) => result,
+        _ => panic!("No recorded subinvoke of \"{}\" on {}", method, uri),
+    }
+}
+
+/// Load the env with `load`, unless a cassette is replaying
+pub(crate) fn cassette_load_env(load: impl FnOnce() -> Vec<u8>) -> Vec<u8> {
+    match mode() {
+        None => load(),
+        Some(Mode::Record) => {
+            let env = load();
+            record(Interaction::LoadEnv { env: env.clone() });
+            env
+        }
+        Some(Mode::Replay) => match replay(|interaction| matches!(interaction, Interaction::LoadEnv { .. })) {
+            Some(Interaction::LoadEnv { env }) => env,
+            _ => panic!("No recorded env"),
+        },
+    }
+}
+
+/// Get the implementations of `uri` with `get`, unless a cassette is
+/// replaying. Errors aren't recorded, as they're raised by the wrapper
+/// rather than sent by the host.
+pub(crate) fn cassette_get_implementations(
+    uri: &str,
+    get: impl FnOnce() -> Result<Vec<String>, String>,
+) -> Result<Vec<String>, String> {
+    match mode() {
+        None => get(),
+        Some(Mode::Record) => {
+            let implementations = get()?;
+            record(Interaction::GetImplementations {
+                uri: uri.to_string(),
+                implementations: implementations.clone(),
+            });
+            Ok(implementations)
+        }
+        Some(Mode::Replay) => match replay(|interaction| {
+            matches!(interaction, Interaction::GetImplementations { uri: u, .. } if u == uri)
+        }) {
+            Some(Interaction::GetImplementations { implementations, .. }) => Ok(implementations),
+            _ => panic!("No recorded implementations of {}", uri),
+        },
+    }
+}
+
+/// Get the caller of the invocation with `get`, unless a cassette is
+/// replaying
+pub(crate) fn cassette_caller(get: impl FnOnce() -> Option<String>) -> Option<String> {
+    match mode() {
+        None => get(),
+        Some(Mode::Record) => {
+            let caller = get();
+            record(Interaction::Caller { caller: caller.clone() });
+            caller
+        }
+        Some(Mode::Replay) => match replay(|interaction| matches!(interaction, Interaction::Caller { .. })) {
+            Some(Interaction::Caller { caller }) => caller,
+            _ => panic!("No recorded caller"),
+        },
+    }
+}
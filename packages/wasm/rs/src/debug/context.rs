@@ -1,12 +1,25 @@
 //! Context stores debug information in a stack, and
 //! prints it in a clear format
 
+use std::cell::RefCell;
+
+thread_local! {
+    /// The context of the most recent decoding error
+    static ERROR_CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
 #[derive(Clone, Debug)]
 pub struct Context {
     pub description: String,
     nodes: Vec<Node>,
 }
 
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Context {
     pub fn new() -> Self {
         Self {
@@ -60,6 +73,33 @@ impl Context {
         [message, "\n", &self.print_with_tabs(1, 0)].concat()
     }
 
+    /// Record this context as the one of the most recent decoding error,
+    /// for [`Context::take_error_context`]. Deserializers call it where
+    /// they raise an error, so the abort report shows where it happened.
+    pub fn record_error(&self) {
+        ERROR_CONTEXT.with(|context| *context.borrow_mut() = Some(self.clone()));
+    }
+
+    /// Take the context of the most recent decoding error, recorded where
+    /// the error was raised
+    pub fn take_error_context() -> Option<Context> {
+        ERROR_CONTEXT.with(|context| context.borrow_mut().take())
+    }
+
+    /// The stack's frames, innermost first
+    pub fn stack(&self) -> Vec<String> {
+        self.nodes
+            .iter()
+            .rev()
+            .map(|node| {
+                format!(
+                    "at {} : {} >> {}",
+                    node.node_item, node.node_type, node.node_info
+                )
+            })
+            .collect()
+    }
+
     fn print_with_tabs(&self, tabs: i32, size: i32) -> String {
         let width = (tabs + 1) * size;
         let pad_start = format!("{:width$}", " ", width = width as usize);
@@ -77,14 +117,9 @@ impl Context {
             return result;
         }
 
-        for i in (0..self.get_length() as usize).rev() {
-            let node = &self.nodes[i];
+        for frame in self.stack() {
             result.push_str(&pad_end);
-            let msg = format!(
-                "at {} : {} >> {}",
-                node.node_item, node.node_type, node.node_info
-            );
-            result.push_str(&msg);
+            result.push_str(&frame);
         }
         result
     }
@@ -1,4 +1,9 @@
-use crate::malloc::alloc;
+use crate::{
+    cassette::cassette_load_env, wrap_invocation_context, Context, DecodeError, EncodeError,
+    HostBuffer, Read, ReadDecoder, Write,
+};
+use std::any::Any;
+use std::cell::RefCell;
 
 #[link(wasm_import_module = "wrap")]
 extern "C" {
@@ -7,11 +12,58 @@ extern "C" {
     pub fn __wrap_load_env(environment_ptr: u32);
 }
 
+thread_local! {
+    /// The decoded env of the current invocation
+    static ENV: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+}
+
 pub fn wrap_load_env(env_size: u32) -> Vec<u8> {
-    let env_size_ptr = alloc(env_size as usize);
-    let env_buf =
-        unsafe { Vec::from_raw_parts(env_size_ptr, env_size as usize, env_size as usize) };
-    unsafe { __wrap_load_env(env_buf.as_ptr() as u32) };
-    env_buf
+    cassette_load_env(|| HostBuffer::fill(env_size, |ptr| unsafe { __wrap_load_env(ptr) }).into_bytes())
+}
+
+/// Env types, implemented by the generated `Env` and imported env types
+pub trait WrapEnv: Clone + 'static {
+    fn read<R: Read>(reader: &mut R) -> Result<Self, DecodeError>;
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError>;
+}
+
+/// Wrappers without an env type. Whatever env the client provides is
+/// ignored.
+impl WrapEnv for () {
+    fn read<R: Read>(_reader: &mut R) -> Result<(), DecodeError> {
+        Ok(())
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_nil()
+    }
+}
+
+/// The env of the current invocation, `None` if the client didn't provide
+/// one. It's loaded and decoded the first time it's asked for, and shared
+/// by everything running in the invocation from then on.
+pub fn wrap_env<E: WrapEnv>() -> Result<Option<E>, DecodeError> {
+    let cached = ENV.with(|env| {
+        env.borrow()
+            .as_ref()
+            .and_then(|env| env.downcast_ref::<E>())
+            .cloned()
+    });
+    if let Some(env) = cached {
+        return Ok(Some(env));
+    }
+
+    let buffer = match wrap_invocation_context().and_then(|context| context.env_buffer()) {
+        Some(buffer) => buffer,
+        None => return Ok(None),
+    };
+    let mut reader = ReadDecoder::new(&buffer, Context::with_description("Env"));
+    let decoded = E::read(&mut reader)?;
+    ENV.with(|env| *env.borrow_mut() = Some(Box::new(decoded.clone())));
+    Ok(Some(decoded))
 }
 
+pub(crate) fn clear_env() {
+    ENV.with(|env| *env.borrow_mut() = None);
+}
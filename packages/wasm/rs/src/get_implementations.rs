@@ -1,4 +1,5 @@
-use crate::{malloc::alloc, Context, Read, ReadDecoder};
+use crate::{cassette::cassette_get_implementations, Context, HostBuffer, Read, ReadDecoder, Uri};
+use std::fmt::Display;
 
 #[link(wasm_import_module = "wrap")]
 extern "C" {
@@ -12,27 +13,38 @@ extern "C" {
     pub fn __wrap_getImplementations_result(ptr: u32);
 }
 
-pub fn wrap_get_implementations(uri: &str) -> Vec<String> {
+/// URIs of the implementations of the interface at `uri`. `uri` is a
+/// [`Uri`], or a string which is parsed into one, failing without calling
+/// the host if it's malformed.
+pub fn wrap_get_implementations<U>(uri: U) -> Result<Vec<String>, String>
+where
+    U: TryInto<Uri>,
+    U::Error: Display,
+{
+    let uri = uri.try_into().map_err(|e| e.to_string())?;
+    cassette_get_implementations(uri.uri(), || get_implementations(uri.uri()))
+}
+
+fn get_implementations(uri: &str) -> Result<Vec<String>, String> {
     let success =
         unsafe { __wrap_getImplementations(uri.as_bytes().as_ptr() as u32, uri.len() as u32) };
 
     if !success {
-        return vec![];
+        return Ok(vec![]);
     }
 
     let result_len = unsafe { __wrap_getImplementations_result_len() };
-    let result_len_ptr = alloc(result_len as usize);
-    let result_buffer =
-        unsafe { Vec::from_raw_parts(result_len_ptr, result_len as usize, result_len as usize) };
-    unsafe { __wrap_getImplementations_result(result_buffer.as_ptr() as u32) };
+    let result_buffer = HostBuffer::fill(result_len, |ptr| unsafe {
+        __wrap_getImplementations_result(ptr)
+    });
 
     // deserialize the `msgpack` buffer,
     // which contains a `Vec<String>`
-    let mut decoder = ReadDecoder::new(&result_buffer, Context::new());
+    let mut decoder = ReadDecoder::new(result_buffer.as_bytes(), Context::new());
     decoder.context().push(
         "__wrap_getImplementations_result",
         "Vec<String>",
         "__wrap_getImplementations successful",
     );
-    decoder.read_array(|reader| reader.read_string()).unwrap()
+    decoder.read_array(|reader| reader.read_string()).map_err(|e| e.to_string())
 }
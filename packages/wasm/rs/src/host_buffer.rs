@@ -0,0 +1,81 @@
+//! Memory filled in by the host.
+//!
+//! Host imports which return data do so by writing into a buffer the
+//! module allocated up front. [`HostBuffer`] owns that buffer for its whole
+//! lifetime, so the host is always handed a valid, initialized region of
+//! the requested length, and the bytes it wrote are only ever turned into
+//! a `String` after being validated.
+
+use thiserror::Error;
+
+/// Errors from reading data written by the host
+#[derive(Debug, Error)]
+pub enum HostBufferError {
+    #[error("Host wrote invalid UTF-8 for {name}: {source}")]
+    InvalidUtf8 {
+        name: String,
+        source: std::string::FromUtf8Error,
+    },
+}
+
+/// A zero-initialized buffer the host writes into
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HostBuffer {
+    data: Vec<u8>,
+}
+
+impl HostBuffer {
+    /// Allocate `len` bytes for the host to write into
+    pub fn new(len: u32) -> HostBuffer {
+        HostBuffer {
+            data: vec![0; len as usize],
+        }
+    }
+
+    /// Allocate `len` bytes and hand their offset to `write`, which is
+    /// expected to call the host import filling them in.
+    ///
+    /// `write` is still called for zero-length buffers, as some imports
+    /// also use the call to move their own state forward.
+    pub fn fill(len: u32, write: impl FnOnce(u32)) -> HostBuffer {
+        let mut buffer = HostBuffer::new(len);
+        write(buffer.as_host_ptr());
+        buffer
+    }
+
+    /// Offset of the buffer within the module's linear memory
+    pub fn as_host_ptr(&mut self) -> u32 {
+        self.data.as_mut_ptr() as usize as u32
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Interpret the buffer as UTF-8, where `name` describes what the host
+    /// was asked for and is only used in the error message.
+    pub fn into_string(self, name: &str) -> Result<String, HostBufferError> {
+        String::from_utf8(self.data).map_err(|source| HostBufferError::InvalidUtf8 {
+            name: name.to_string(),
+            source,
+        })
+    }
+}
+
+impl From<Vec<u8>> for HostBuffer {
+    fn from(data: Vec<u8>) -> HostBuffer {
+        HostBuffer { data }
+    }
+}
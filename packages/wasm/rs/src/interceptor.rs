@@ -0,0 +1,78 @@
+//! Middleware run around every method of a wrapper.
+//!
+//! A wrapper returns its interceptors from `Module::interceptors` and the
+//! generated entry point runs them, in order, around the method it
+//! dispatches to. Each interceptor sees the method name, the raw msgpack
+//! args and the decoded env, and gets the encoded result back from
+//! [`Next::run`]. An interceptor can rewrite the args or the result, or
+//! skip the method altogether by not calling `next`. Like methods, it
+//! fails an invocation by panicking, e.g. when the env doesn't authorize
+//! the caller.
+
+use crate::{module::wrap_optional_env, WrapEnv};
+
+/// The invocation an interceptor runs around
+pub struct Invocation<'a, E> {
+    pub method: &'a str,
+    /// The msgpack encoded args
+    pub args: &'a [u8],
+    /// The env, if the client provided one
+    pub env: Option<&'a E>,
+}
+
+pub trait Interceptor<E> {
+    /// Handle `invocation`, usually by running the rest of the chain with
+    /// `next` and returning its result
+    fn intercept(&self, invocation: &Invocation<'_, E>, next: Next<'_, E>) -> Vec<u8>;
+}
+
+/// The rest of an interceptor chain, ending with the method itself
+pub struct Next<'a, E> {
+    interceptors: &'a [Box<dyn Interceptor<E>>],
+    method: &'a dyn Fn(&[u8]) -> Vec<u8>,
+}
+
+impl<E> Next<'_, E> {
+    /// Run the rest of the chain. The method is called with the args of
+    /// `invocation`, which may differ from the ones the interceptor got.
+    pub fn run(self, invocation: &Invocation<'_, E>) -> Vec<u8> {
+        match self.interceptors.split_first() {
+            Some((interceptor, interceptors)) => interceptor.intercept(
+                invocation,
+                Next {
+                    interceptors,
+                    method: self.method,
+                },
+            ),
+            None => (self.method)(invocation.args),
+        }
+    }
+}
+
+/// Run `interceptors` around `method`, which is called with the
+/// (possibly rewritten) args. The env is the one
+/// [`wrap_env`](crate::wrap_env) decodes for the invocation, so methods
+/// asking for it again don't reload it. It's only loaded when there are
+/// interceptors to see it.
+pub fn wrap_intercept<E: WrapEnv>(
+    interceptors: &[Box<dyn Interceptor<E>>],
+    method_name: &str,
+    args: &[u8],
+    method: impl Fn(&[u8]) -> Vec<u8>,
+) -> Vec<u8> {
+    if interceptors.is_empty() {
+        return method(args);
+    }
+
+    let env = wrap_optional_env::<E>();
+    let invocation = Invocation {
+        method: method_name,
+        args,
+        env: env.as_ref(),
+    };
+    Next {
+        interceptors,
+        method: &method,
+    }
+    .run(&invocation)
+}
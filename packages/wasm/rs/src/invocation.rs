@@ -0,0 +1,109 @@
+//! What wrapper code can find out about the invocation it runs in.
+//!
+//! [`wrap_invocation_context`] works anywhere below a method, so helpers
+//! can log or authorize without the env being threaded through to them.
+
+use crate::{
+    cassette::cassette_caller, env::clear_env, subinvoke::clear_memoized_subinvokes, wrap_load_env,
+    DecodeError, HostBuffer, Uri,
+};
+use std::cell::RefCell;
+
+#[link(wasm_import_module = "wrap")]
+extern "C" {
+    /// Get Invoke Caller. Only imported by wrappers asking for their
+    /// caller, so other wrappers run on hosts without it.
+    #[link_name = "__wrap_invoke_caller_len"]
+    pub fn __wrap_invoke_caller_len() -> u32;
+
+    #[link_name = "__wrap_invoke_caller"]
+    pub fn __wrap_invoke_caller(ptr: u32);
+}
+
+thread_local! {
+    static INVOCATION: RefCell<Option<InvocationContext>> = const { RefCell::new(None) };
+    static ENV_BUFFER: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+/// The method being invoked and what it was invoked with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvocationContext {
+    method: String,
+    args_size: u32,
+    env_size: u32,
+}
+
+/// Context of the current invocation, if a method is running
+pub fn wrap_invocation_context() -> Option<InvocationContext> {
+    INVOCATION.with(|invocation| invocation.borrow().clone())
+}
+
+pub(crate) fn begin_invocation_context(method: &str, args_size: u32, env_size: u32) {
+    let context = InvocationContext {
+        method: method.to_string(),
+        args_size,
+        env_size,
+    };
+    INVOCATION.with(|invocation| *invocation.borrow_mut() = Some(context));
+}
+
+pub(crate) fn end_invocation_context() {
+    INVOCATION.with(|invocation| *invocation.borrow_mut() = None);
+    ENV_BUFFER.with(|buffer| *buffer.borrow_mut() = None);
+    clear_env();
+    clear_memoized_subinvokes();
+}
+
+impl InvocationContext {
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Size of the msgpack encoded args
+    pub fn args_size(&self) -> u32 {
+        self.args_size
+    }
+
+    pub fn has_env(&self) -> bool {
+        self.env_size > 0
+    }
+
+    /// The msgpack encoded env, if the client provided one. It's loaded
+    /// from the host the first time it's asked for.
+    pub fn env_buffer(&self) -> Option<Vec<u8>> {
+        if !self.has_env() {
+            return None;
+        }
+        let buffer = ENV_BUFFER.with(|buffer| {
+            buffer
+                .borrow_mut()
+                .get_or_insert_with(|| wrap_load_env(self.env_size))
+                .clone()
+        });
+        Some(buffer)
+    }
+
+    /// Decode the env with `decode`, e.g. `Env::from_buffer`, if the
+    /// client provided one
+    pub fn env<E>(
+        &self,
+        decode: impl FnOnce(&[u8]) -> Result<E, DecodeError>,
+    ) -> Option<Result<E, DecodeError>> {
+        self.env_buffer().map(|buffer| decode(&buffer))
+    }
+
+    /// URI of the wrapper which subinvoked this one, `None` for
+    /// invocations from outside of a wrapper. Asking for it requires a
+    /// host providing `__wrap_invoke_caller`.
+    pub fn caller(&self) -> Option<Uri> {
+        let caller = cassette_caller(|| {
+            let len = unsafe { __wrap_invoke_caller_len() };
+            if len == 0 {
+                return None;
+            }
+            let caller = HostBuffer::fill(len, |ptr| unsafe { __wrap_invoke_caller(ptr) });
+            caller.into_string("invoke caller").ok()
+        })?;
+        Uri::new(&caller).ok()
+    }
+}
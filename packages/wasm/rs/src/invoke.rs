@@ -1,4 +1,8 @@
-use crate::malloc::alloc;
+use crate::{
+    invocation::{begin_invocation_context, end_invocation_context},
+    malloc::{begin_invocation, end_invocation},
+    wrap_abort, wrap_invocation_context, AbortReport, Context, HostBuffer, HostBufferError,
+};
 
 #[link(wasm_import_module = "wrap")]
 extern "C" {
@@ -15,6 +19,11 @@ extern "C" {
     pub fn __wrap_invoke_error(ptr: u32, len: u32);
 }
 
+/// Name of the method currently being invoked, if any
+pub fn wrap_invoke_method() -> Option<String> {
+    wrap_invocation_context().map(|context| context.method().to_string())
+}
+
 /// Keep track of all invokable functions
 pub type InvokeFunction = fn(args_buf: &[u8], env_size: u32) -> Vec<u8>;
 
@@ -23,26 +32,45 @@ pub struct InvokeArgs {
     pub args: Vec<u8>,
 }
 
-/// Helper for fetching invoke args
+/// Helper for fetching invoke args, aborting if the host sent
+/// a method name which isn't valid UTF-8
 pub fn wrap_invoke_args(method_size: u32, args_size: u32) -> InvokeArgs {
-    let method_size_ptr = alloc(method_size as usize);
-    let args_size_ptr = alloc(args_size as usize);
+    begin_invocation();
+
+    match read_invoke_args(method_size, args_size) {
+        Ok(args) => args,
+        Err(error) => wrap_abort(AbortReport::from_error(&error)),
+    }
+}
 
-    unsafe { __wrap_invoke_args(method_size_ptr as u32, args_size_ptr as u32) };
+fn read_invoke_args(method_size: u32, args_size: u32) -> Result<InvokeArgs, HostBufferError> {
+    let mut method = HostBuffer::new(method_size);
+    let mut args = HostBuffer::new(args_size);
 
-    let method = unsafe {
-        String::from_raw_parts(method_size_ptr, method_size as usize, method_size as usize)
-    };
-    let args =
-        unsafe { Vec::from_raw_parts(args_size_ptr, args_size as usize, args_size as usize) };
+    unsafe { __wrap_invoke_args(method.as_host_ptr(), args.as_host_ptr()) };
 
-    InvokeArgs { method, args }
+    Ok(InvokeArgs {
+        method: method.into_string("invoke method")?,
+        args: args.into_bytes(),
+    })
 }
 
 /// Helper for handling `_wrap_invoke`
 pub fn wrap_invoke(args: InvokeArgs, env_size: u32, opt_invoke_func: Option<InvokeFunction>) -> bool {
+    let success = invoke(args, env_size, opt_invoke_func);
+
+    // Release everything scoped to this invocation
+    end_invocation_context();
+    Context::take_error_context();
+    end_invocation();
+    success
+}
+
+fn invoke(args: InvokeArgs, env_size: u32, opt_invoke_func: Option<InvokeFunction>) -> bool {
     match opt_invoke_func {
         Some(func) => {
+            begin_invocation_context(&args.method, args.args.len() as u32, env_size);
+            Context::take_error_context();
             let result = func(args.args.as_slice(), env_size);
             let res_len = result.len() as u32;
             unsafe { __wrap_invoke_result(result.as_ptr() as u32, res_len) };
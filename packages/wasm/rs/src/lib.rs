@@ -2,23 +2,53 @@
 #![deny(unreachable_code)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod abi;
 pub mod abort;
+pub mod cassette;
 pub mod debug;
 pub mod debug_log;
 pub mod env;
 pub mod get_implementations;
+pub mod host_buffer;
+pub mod interceptor;
+pub mod invocation;
 pub mod invoke;
+#[cfg(feature = "logger")]
+pub mod logger;
 pub mod malloc;
+pub mod module;
 pub mod msgpack;
+pub mod redirect;
+pub mod retry;
 pub mod subinvoke;
+pub mod subinvoke_batch;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod uri;
+#[cfg(feature = "manifest")]
+pub mod wrap_manifest;
 
+pub use abi::*;
 pub use abort::*;
+pub use cassette::*;
 pub use debug::*;
 pub use debug_log::*;
 pub use env::*;
 pub use get_implementations::*;
+pub use host_buffer::*;
+pub use interceptor::*;
+pub use invocation::*;
 pub use invoke::*;
+#[cfg(feature = "logger")]
+pub use logger::*;
+pub use module::*;
+pub use redirect::*;
+pub use retry::*;
 pub use subinvoke::*;
+pub use subinvoke_batch::*;
+#[cfg(feature = "tracing")]
+pub use trace::*;
+pub use uri::*;
 
 pub use msgpack::{
     DecodeError, EncodeError, EnumTypeError, Read, ReadDecoder, Write, WriteEncoder,
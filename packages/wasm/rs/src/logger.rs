@@ -0,0 +1,133 @@
+//! A `log` backend which forwards records to the wrapper's host.
+//!
+//! Every record is sent to the implementations of the logger interface
+//! registered with the client. If there are none, or none of them accept
+//! the record, it is written with `__wrap_debug_log` instead.
+//!
+//! Levels can be filtered at compile time through `log`'s `max_level_*`
+//! and `release_max_level_*` features, and at runtime through the
+//! `LevelFilter` passed to [`wrap_logger_setup`].
+
+use crate::{
+    wrap_debug_log, wrap_get_implementations, wrap_subinvoke, Context, DecodeError, EncodeError,
+    Read, ReadDecoder, Write, WriteEncoder,
+};
+use log::{
+    kv::{Error as KvError, Key, Value, VisitSource},
+    Level, LevelFilter, Log, Metadata, Record, SetLoggerError,
+};
+use std::fmt::Write as FmtWrite;
+
+/// URI of the logger interface
+pub const LOGGER_INTERFACE_URI: &str = "wrap://ens/logger.core.polywrap.eth";
+
+/// The logger interface's `LogLevel` enum
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    DEBUG = 0,
+    INFO = 1,
+    WARN = 2,
+    ERROR = 3,
+}
+
+impl From<Level> for LogLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Trace | Level::Debug => LogLevel::DEBUG,
+            Level::Info => LogLevel::INFO,
+            Level::Warn => LogLevel::WARN,
+            Level::Error => LogLevel::ERROR,
+        }
+    }
+}
+
+/// `log::Log` implementation backed by the logger interface
+pub struct WrapLogger;
+
+static LOGGER: WrapLogger = WrapLogger;
+
+/// Install [`WrapLogger`] as the global logger, with `level` as
+/// the runtime maximum level.
+pub fn wrap_logger_setup(level: LevelFilter) -> Result<(), SetLoggerError> {
+    log::set_logger(&LOGGER)?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+impl Log for WrapLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let message = format_record(record);
+        if !log_to_implementations(LogLevel::from(record.level()), &message) {
+            wrap_debug_log(&format!("[{}] {}", record.level(), message));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Render a record as `target: message key=value ...`
+pub fn format_record(record: &Record) -> String {
+    struct Fields<'a>(&'a mut String);
+
+    impl<'kvs> VisitSource<'kvs> for Fields<'_> {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), KvError> {
+            write!(self.0, " {}={}", key, value).map_err(|_| KvError::msg("formatting failed"))
+        }
+    }
+
+    let mut message = format!("{}: {}", record.target(), record.args());
+    let _ = record.key_values().visit(&mut Fields(&mut message));
+    message
+}
+
+/// Send `message` to every logger interface implementation.
+/// Returns `true` if at least one of them logged it.
+fn log_to_implementations(level: LogLevel, message: &str) -> bool {
+    let args = match serialize_log_args(level, message) {
+        Ok(args) => args,
+        Err(_) => return false,
+    };
+
+    let mut logged = false;
+    for uri in wrap_get_implementations(LOGGER_INTERFACE_URI).unwrap_or_default() {
+        let result = wrap_subinvoke(&uri, "log", args.clone());
+        if let Ok(true) = result.map(|buf| deserialize_log_result(&buf)) {
+            logged = true;
+        }
+    }
+    logged
+}
+
+pub fn serialize_log_args(level: LogLevel, message: &str) -> Result<Vec<u8>, EncodeError> {
+    let mut encoder_context = Context::new();
+    encoder_context.description = "Serializing (encoding) imported module-type: log".to_string();
+    let mut encoder = WriteEncoder::new(&[], encoder_context);
+    encoder.write_map_length(&2)?;
+    encoder.write_string("level")?;
+    encoder.context().push("level", "LogLevel", "writing property");
+    encoder.write_i32(&(level as i32))?;
+    encoder.context().pop();
+    encoder.write_string("message")?;
+    encoder.context().push("message", "String", "writing property");
+    encoder.write_string(message)?;
+    encoder.context().pop();
+    Ok(encoder.get_buffer())
+}
+
+fn deserialize_log_result(result: &[u8]) -> bool {
+    let mut context = Context::new();
+    context.description = "Deserializing imported module-type: log".to_string();
+    let mut reader = ReadDecoder::new(result, context);
+    reader.context().push("log", "bool", "reading function output");
+    let res: Result<bool, DecodeError> = reader.read_bool();
+    reader.context().pop();
+    res.unwrap_or(false)
+}
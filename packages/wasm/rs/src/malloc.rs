@@ -1,3 +1,46 @@
+//! Linear memory management.
+//!
+//! Two cargo features change how the module allocates on `wasm32`:
+//! - `wee_alloc` uses `wee_alloc` as the global allocator, trading speed
+//!   for a smaller `.wasm` binary.
+//! - `arena` serves every allocation made while `_wrap_invoke` runs from an
+//!   [`InvocationArena`], and frees it all at once when the invocation ends.
+//!   Anything which has to outlive the invocation (global loggers, caches,
+//!   lazily initialized statics) must be allocated inside [`persist`].
+//!   Native test binaries can run invocations the same way by forwarding
+//!   their own `#[global_allocator]` to [`ALLOCATOR`].
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    cell::UnsafeCell,
+    ptr,
+};
+
+#[cfg(all(target_arch = "wasm32", feature = "wee_alloc"))]
+type Backing = wee_alloc::WeeAlloc<'static>;
+#[cfg(all(target_arch = "wasm32", feature = "wee_alloc"))]
+const BACKING: Backing = wee_alloc::WeeAlloc::INIT;
+
+#[cfg(all(
+    any(target_arch = "wasm32", feature = "arena"),
+    not(all(target_arch = "wasm32", feature = "wee_alloc"))
+))]
+type Backing = std::alloc::System;
+#[cfg(all(
+    any(target_arch = "wasm32", feature = "arena"),
+    not(all(target_arch = "wasm32", feature = "wee_alloc"))
+))]
+const BACKING: Backing = std::alloc::System;
+
+/// The invocation arena of the `arena` feature
+#[cfg(feature = "arena")]
+#[cfg_attr(target_arch = "wasm32", global_allocator)]
+pub static ALLOCATOR: InvocationArena<Backing> = InvocationArena::new(BACKING);
+
+#[cfg(all(target_arch = "wasm32", feature = "wee_alloc", not(feature = "arena")))]
+#[global_allocator]
+static ALLOCATOR: Backing = BACKING;
+
 /// Allocate memory into the module's linear memory
 /// and return the offset to the start of the block.
 #[no_mangle]
@@ -16,8 +59,206 @@ pub fn alloc(len: usize) -> *mut u8 {
     ptr
 }
 
+/// Free a block previously returned by [`alloc`].
+///
+/// # Safety
+///
+/// `ptr` must come from a call to [`alloc`] with the same `size`,
+/// and must not be used after this call.
 #[no_mangle]
 pub unsafe fn dealloc(ptr: *mut u8, size: usize) {
     let data = Vec::from_raw_parts(ptr, size, size);
     std::mem::drop(data);
 }
+
+/// Start serving allocations from the invocation arena, if enabled
+pub fn begin_invocation() {
+    #[cfg(feature = "arena")]
+    ALLOCATOR.begin();
+}
+
+/// Free every allocation made since [`begin_invocation`], if the
+/// invocation arena is enabled. Nothing allocated during the invocation
+/// may be used afterwards.
+pub fn end_invocation() {
+    #[cfg(feature = "arena")]
+    unsafe {
+        ALLOCATOR.reset()
+    };
+}
+
+/// Run `f` with the invocation arena suspended, so that whatever it
+/// allocates outlives the current invocation.
+pub fn persist<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "arena")]
+    return ALLOCATOR.suspend(f);
+    #[cfg(not(feature = "arena"))]
+    f()
+}
+
+const CHUNK_SIZE: usize = 64 * 1024;
+const CHUNK_ALIGN: usize = 16;
+
+struct Chunk {
+    prev: *mut Chunk,
+    size: usize,
+}
+
+struct ArenaState {
+    active: bool,
+    chunk: *mut Chunk,
+    next: usize,
+    end: usize,
+}
+
+/// Bump allocator for invocation-scoped memory.
+///
+/// While active, allocations are carved out of chunks requested from the
+/// backing allocator, and freeing them is a no-op. [`InvocationArena::reset`]
+/// returns every chunk but the first to the backing allocator. Memory which
+/// was allocated outside of the arena is always handled by the backing
+/// allocator, including when it is reallocated during an invocation.
+pub struct InvocationArena<A: GlobalAlloc> {
+    backing: A,
+    state: UnsafeCell<ArenaState>,
+}
+
+// Wasm modules are single threaded.
+unsafe impl<A: GlobalAlloc + Sync> Sync for InvocationArena<A> {}
+
+impl<A: GlobalAlloc> InvocationArena<A> {
+    pub const fn new(backing: A) -> Self {
+        Self {
+            backing,
+            state: UnsafeCell::new(ArenaState {
+                active: false,
+                chunk: ptr::null_mut(),
+                next: 0,
+                end: 0,
+            }),
+        }
+    }
+
+    pub fn begin(&self) {
+        unsafe { (*self.state.get()).active = true };
+    }
+
+    pub fn is_active(&self) -> bool {
+        unsafe { (*self.state.get()).active }
+    }
+
+    /// Deactivate the arena and free everything allocated from it.
+    ///
+    /// # Safety
+    ///
+    /// No pointer handed out by the arena may be used after this call.
+    pub unsafe fn reset(&self) {
+        let state = &mut *self.state.get();
+        state.active = false;
+        if state.chunk.is_null() {
+            return;
+        }
+
+        let mut chunk = state.chunk;
+        while !(*chunk).prev.is_null() {
+            let prev = (*chunk).prev;
+            self.backing
+                .dealloc(chunk as *mut u8, Self::chunk_layout((*chunk).size));
+            chunk = prev;
+        }
+        state.chunk = chunk;
+        state.next = chunk as usize + std::mem::size_of::<Chunk>();
+        state.end = chunk as usize + (*chunk).size;
+    }
+
+    /// Run `f` with the arena deactivated
+    pub fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        let active = self.is_active();
+        unsafe { (*self.state.get()).active = false };
+        let result = f();
+        unsafe { (*self.state.get()).active = active };
+        result
+    }
+
+    /// Whether `ptr` was allocated from one of the arena's chunks
+    pub fn contains(&self, ptr: *const u8) -> bool {
+        let addr = ptr as usize;
+        let mut chunk = unsafe { (*self.state.get()).chunk };
+        while !chunk.is_null() {
+            let start = chunk as usize;
+            let size = unsafe { (*chunk).size };
+            if addr >= start && addr < start + size {
+                return true;
+            }
+            chunk = unsafe { (*chunk).prev };
+        }
+        false
+    }
+
+    fn chunk_layout(size: usize) -> Layout {
+        Layout::from_size_align(size, CHUNK_ALIGN).expect("invalid arena chunk size")
+    }
+
+    unsafe fn bump(&self, layout: Layout) -> *mut u8 {
+        let state = &mut *self.state.get();
+        let start = align_up(state.next, layout.align());
+        if !state.chunk.is_null() && start + layout.size() <= state.end {
+            state.next = start + layout.size();
+            return start as *mut u8;
+        }
+
+        let header = std::mem::size_of::<Chunk>();
+        let size = CHUNK_SIZE.max(header + layout.align() + layout.size());
+        let chunk = self.backing.alloc(Self::chunk_layout(size)) as *mut Chunk;
+        if chunk.is_null() {
+            return ptr::null_mut();
+        }
+        chunk.write(Chunk {
+            prev: state.chunk,
+            size,
+        });
+        state.chunk = chunk;
+        state.end = chunk as usize + size;
+
+        let start = align_up(chunk as usize + header, layout.align());
+        state.next = start + layout.size();
+        start as *mut u8
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for InvocationArena<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if self.is_active() {
+            self.bump(layout)
+        } else {
+            self.backing.alloc(layout)
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if !self.contains(ptr) {
+            self.backing.dealloc(ptr, layout)
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if !self.contains(ptr) {
+            return self.backing.realloc(ptr, layout, new_size);
+        }
+
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        let new_ptr = if self.is_active() {
+            self.bump(new_layout)
+        } else {
+            self.backing.alloc(new_layout)
+        };
+        if !new_ptr.is_null() {
+            ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+        }
+        new_ptr
+    }
+}
+
+fn align_up(addr: usize, align: usize) -> usize {
+    (addr + align - 1) & !(align - 1)
+}
@@ -0,0 +1,118 @@
+//! Building blocks for wrappers written without codegen.
+//!
+//! The [`wrap_module!`](crate::wrap_module) macro expands to the
+//! `_wrap_invoke` export and the method dispatch. The functions here do the
+//! per-method work of loading the env, decoding the arguments, calling the
+//! method and encoding its result. Failures abort the invocation with an
+//! [`AbortReport`] carrying the error's source chain.
+
+use crate::{wrap_env, AbortReport, DecodeError, EncodeError, WrapEnv};
+
+/// The env required by `method`, aborting if the client didn't provide one
+pub fn wrap_required_env<E: WrapEnv>(method: &str) -> E {
+    match wrap_optional_env() {
+        Some(env) => env,
+        None => panic!(
+            "Environment is not set, and it is required by method '{}'",
+            method
+        ),
+    }
+}
+
+/// The env, if the client provided one. It's decoded by [`wrap_env`], so
+/// it's shared with everything else in the invocation.
+pub fn wrap_optional_env<E: WrapEnv>() -> Option<E> {
+    wrap_env().unwrap_or_else(|e| wrap_panic(&e))
+}
+
+/// Decode `args`, call `method` with them and encode its result
+pub fn wrap_invoke_method_with<A, R>(
+    args: &[u8],
+    decode_args: impl FnOnce(&[u8]) -> Result<A, DecodeError>,
+    method: impl FnOnce(A) -> R,
+    encode_result: impl FnOnce(&R) -> Result<Vec<u8>, EncodeError>,
+) -> Vec<u8> {
+    let args = decode_args(args).unwrap_or_else(|e| wrap_panic(&e));
+    let result = method(args);
+    encode_result(&result).unwrap_or_else(|e| wrap_panic(&e))
+}
+
+fn wrap_panic(error: &(dyn std::error::Error + 'static)) -> ! {
+    std::panic::panic_any(AbortReport::from_error(error))
+}
+
+/// Generate the `_wrap_invoke` export for a hand-written wrapper.
+///
+/// Each method maps its WRAP name to a function taking the decoded
+/// arguments (and the env, if declared) and returning the result, along
+/// with the functions used to decode the arguments and encode the result.
+/// `env` makes the env required, `optional_env` passes it as an `Option`.
+/// Env types must implement [`WrapEnv`], as generated env types do.
+///
+/// ```ignore
+/// wrap_module! {
+///     "moduleMethod" => module_method {
+///         args: deserialize_module_method_args,
+///         result: serialize_module_method_result,
+///     },
+///     "objectMethod" => object_method {
+///         args: deserialize_object_method_args,
+///         result: serialize_object_method_result,
+///         env: Env,
+///     },
+/// }
+/// ```
+///
+/// Besides `_wrap_invoke`, the macro defines
+/// `wrap_module_method(name) -> Option<InvokeFunction>`, used for dispatch.
+#[macro_export]
+macro_rules! wrap_module {
+    (@invoke $name:literal, $method:path, $decode_args:path, $encode_result:path, $args:ident) => {
+        $crate::module::wrap_invoke_method_with($args, $decode_args, $method, $encode_result)
+    };
+
+    (@invoke $name:literal, $method:path, $decode_args:path, $encode_result:path, $args:ident, env, $env:ty) => {{
+        let env = $crate::module::wrap_required_env::<$env>($name);
+        $crate::module::wrap_invoke_method_with($args, $decode_args, |args| $method(args, env), $encode_result)
+    }};
+
+    (@invoke $name:literal, $method:path, $decode_args:path, $encode_result:path, $args:ident, optional_env, $env:ty) => {{
+        let env = $crate::module::wrap_optional_env::<$env>();
+        $crate::module::wrap_invoke_method_with($args, $decode_args, |args| $method(args, env), $encode_result)
+    }};
+
+    ($(
+        $name:literal => $method:path {
+            args: $decode_args:path,
+            result: $encode_result:path
+            $(, $env_kind:ident : $env:ty)?
+            $(,)?
+        }
+    ),* $(,)?) => {
+        /// Find the wrapped function handling the WRAP method `name`
+        pub fn wrap_module_method(name: &str) -> Option<$crate::InvokeFunction> {
+            match name {
+                $($name => {
+                    fn wrapped(args: &[u8], _env_size: u32) -> Vec<u8> {
+                        $crate::wrap_module!(
+                            @invoke $name, $method, $decode_args, $encode_result,
+                            args $(, $env_kind, $env)?
+                        )
+                    }
+                    Some(wrapped)
+                })*
+                _ => None,
+            }
+        }
+
+        #[no_mangle]
+        pub extern "C" fn _wrap_invoke(method_size: u32, args_size: u32, env_size: u32) -> bool {
+            // Ensure the abort handler is properly setup
+            $crate::abort::wrap_abort_setup();
+
+            let args: $crate::InvokeArgs = $crate::invoke::wrap_invoke_args(method_size, args_size);
+            let func = wrap_module_method(args.method.as_str());
+            $crate::invoke::wrap_invoke(args, env_size, func)
+        }
+    };
+}
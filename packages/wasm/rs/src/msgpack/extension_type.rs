@@ -34,9 +34,9 @@ impl From<u8> for ExtensionType {
     }
 }
 
-impl Into<u8> for ExtensionType {
+impl From<ExtensionType> for u8 {
     #[inline]
-    fn into(self) -> u8 {
-        self.to_u8()
+    fn from(val: ExtensionType) -> u8 {
+        val.to_u8()
     }
 }
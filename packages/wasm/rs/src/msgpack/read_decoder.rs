@@ -30,6 +30,21 @@ impl ReadDecoder {
         }
     }
 
+    /// The format of the next value, without reading it
+    pub fn peek_format(&mut self) -> Result<Format, DecodeError> {
+        let position = self.view.buffer.position();
+        let format = Format::get_format(self)?;
+        self.view.buffer.set_position(position);
+        Ok(format)
+    }
+
+    /// The message of an error raised at the current context, which is
+    /// recorded for the abort report of the invocation
+    fn error_message(&self, message: &str) -> String {
+        self.context.record_error();
+        self.context.print_with_context(message)
+    }
+
     pub fn read_i64(&mut self) -> Result<i64, DecodeError> {
         let f = Format::get_format(self)?;
         let prefix = f.to_u8();
@@ -53,7 +68,7 @@ impl ReadDecoder {
                     Ok(v as i64)
                   } else {
                     let formatted_err = format!("integer overflow: value = {}; bits = 64", v);
-                    let err_msg = self.context().print_with_context(&formatted_err);
+                    let err_msg = self.error_message(&formatted_err);
                     Err(DecodeError::IntRangeError(err_msg))
                   }
                 },
@@ -62,7 +77,7 @@ impl ReadDecoder {
                       "Property must be of type 'int'. {}",
                       get_error_message(err_f)
                     );
-                    let err_msg = self.context().print_with_context(&formatted_err);
+                    let err_msg = self.error_message(&formatted_err);
                     Err(DecodeError::WrongMsgPackFormat(err_msg))
                 }
             }
@@ -79,7 +94,7 @@ impl ReadDecoder {
               "unsigned integer cannot be negative. {}",
               get_error_message(f)
             );
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
 
             return Err(DecodeError::IntRangeError(err_msg))
         }
@@ -100,7 +115,7 @@ impl ReadDecoder {
                 "unsigned integer cannot be negative. {}",
                 get_error_message(f)
               );
-              let err_msg = self.context().print_with_context(&formatted_err);
+              let err_msg = self.error_message(&formatted_err);
   
               Err(DecodeError::IntRangeError(err_msg))
             },
@@ -115,7 +130,7 @@ impl ReadDecoder {
                 "unsigned integer cannot be negative. {}",
                 get_error_message(f)
               );
-              let err_msg = self.context().print_with_context(&formatted_err);
+              let err_msg = self.error_message(&formatted_err);
   
               Err(DecodeError::IntRangeError(err_msg))
             },
@@ -130,7 +145,7 @@ impl ReadDecoder {
                 "unsigned integer cannot be negative. {}",
                 get_error_message(f)
               );
-              let err_msg = self.context().print_with_context(&formatted_err);
+              let err_msg = self.error_message(&formatted_err);
   
               Err(DecodeError::IntRangeError(err_msg))
             },
@@ -145,7 +160,7 @@ impl ReadDecoder {
                 "unsigned integer cannot be negative. {}",
                 get_error_message(f)
               );
-              let err_msg = self.context().print_with_context(&formatted_err);
+              let err_msg = self.error_message(&formatted_err);
   
               Err(DecodeError::IntRangeError(err_msg))
             },
@@ -155,7 +170,7 @@ impl ReadDecoder {
                   "Property must be of type 'uint'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -179,7 +194,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'bool'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -192,7 +207,7 @@ impl Read for ReadDecoder {
             Ok(v as i8)
         } else {
             let formatted_err = format!("integer overflow: value = {}; bits = 8", v);
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
             Err(DecodeError::IntRangeError(err_msg))
         }
     }
@@ -204,7 +219,7 @@ impl Read for ReadDecoder {
             Ok(v as i16)
         } else {
             let formatted_err = format!("integer overflow: value = {}; bits = 16", v);
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
             Err(DecodeError::IntRangeError(err_msg))
         }
     }
@@ -216,7 +231,7 @@ impl Read for ReadDecoder {
             Ok(v as i32)
         } else {
             let formatted_err = format!("integer overflow: value = {}; bits = 32", v);
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
             Err(DecodeError::IntRangeError(err_msg))
         }
     }
@@ -228,7 +243,7 @@ impl Read for ReadDecoder {
             Ok(v as u8)
         } else {
             let formatted_err = format!("unsigned integer overflow: value = {}; bits = 8", v);
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
             Err(DecodeError::IntRangeError(err_msg))
         }
     }
@@ -240,7 +255,7 @@ impl Read for ReadDecoder {
             Ok(v as u16)
         } else {
             let formatted_err = format!("unsigned integer overflow: value = {}; bits = 16", v);
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
             Err(DecodeError::IntRangeError(err_msg))
         }
     }
@@ -252,7 +267,7 @@ impl Read for ReadDecoder {
             Ok(v as u32)
         } else {
             let formatted_err = format!("unsigned integer overflow: value = {}; bits = 32", v);
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
             Err(DecodeError::IntRangeError(err_msg))
         }
     }
@@ -265,7 +280,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'float32'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -280,7 +295,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'float64'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -303,7 +318,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'string'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -334,7 +349,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'bytes'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -376,7 +391,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'array'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -412,7 +427,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'map'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         }
@@ -440,8 +455,8 @@ impl Read for ReadDecoder {
 
     fn read_ext_generic_map<K, V>(
         &mut self,
-        mut key_reader: impl FnMut(&mut Self) -> Result<K, DecodeError>,
-        mut val_reader: impl FnMut(&mut Self) -> Result<V, DecodeError>,
+        key_reader: impl FnMut(&mut Self) -> Result<K, DecodeError>,
+        val_reader: impl FnMut(&mut Self) -> Result<V, DecodeError>,
     ) -> Result<BTreeMap<K, V>, DecodeError>
     where
         K: Eq + Hash + Ord,
@@ -474,7 +489,7 @@ impl Read for ReadDecoder {
                   "Property must be of type 'ext generic map'. {}",
                   get_error_message(err_f)
                 );
-                let err_msg = self.context().print_with_context(&formatted_err);
+                let err_msg = self.error_message(&formatted_err);
                 return Err(DecodeError::WrongMsgPackFormat(err_msg))
             }
         };
@@ -490,7 +505,7 @@ impl Read for ReadDecoder {
                 "Extension must be of type 'ext generic map'. Found {}",
                 ext_type
             );
-            let err_msg = self.context().print_with_context(&formatted_err);
+            let err_msg = self.error_message(&formatted_err);
             return Err(DecodeError::WrongMsgPackFormat(err_msg))
         }
 
@@ -658,7 +673,7 @@ impl Read for ReadDecoder {
         if self.is_next_nil()? {
             Ok(None)
         } else {
-            match self.read_array(item_reader) {
+            match Read::read_array(self, item_reader) {
                 Ok(array) => Ok(Some(array)),
                 Err(e) => Err(DecodeError::ArrayReadError(e.to_string())),
             }
@@ -702,22 +717,16 @@ impl Read for ReadDecoder {
     }
 
     fn is_next_nil(&mut self) -> Result<bool, DecodeError> {
-        let position = self.view.buffer.position();
-        let format = Format::get_format(self)?;
-        if format == Format::Nil {
+        if self.peek_format()? == Format::Nil {
+            Format::get_format(self)?;
             Ok(true)
         } else {
-            self.view.buffer.set_position(position);
             Ok(false)
         }
     }
 
     fn is_next_string(&mut self) -> Result<bool, DecodeError> {
-        let position = self.view.buffer.position();
-        let format = Format::get_format(self)?;
-        self.view.buffer.set_position(position);
-        
-        match format {
+        match self.peek_format()? {
           Format::FixStr(_) | Format::Str8 | Format::Str16 | Format::Str32 => Ok(true),
           _ => Ok(false)
         }
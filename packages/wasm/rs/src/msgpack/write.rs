@@ -27,6 +27,14 @@ pub trait Write {
         array: &[T],
         item_writer: impl FnMut(&mut Self, &T) -> Result<(), EncodeError>,
     ) -> Result<(), EncodeError>;
+    fn write_array_iter<I>(
+        &mut self,
+        items: I,
+        item_writer: impl FnMut(&mut Self, I::Item) -> Result<(), EncodeError>,
+    ) -> Result<(), EncodeError>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator;
     fn write_map_length(&mut self, length: &u32) -> Result<(), EncodeError>;
     fn write_map<K, V: Clone>(
         &mut self,
@@ -36,6 +44,15 @@ pub trait Write {
     ) -> Result<(), EncodeError>
     where
         K: Clone + Eq + Hash + Ord;
+    fn write_map_iter<I, K, V>(
+        &mut self,
+        entries: I,
+        key_writer: impl FnMut(&mut Self, K) -> Result<(), EncodeError>,
+        val_writer: impl FnMut(&mut Self, V) -> Result<(), EncodeError>,
+    ) -> Result<(), EncodeError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        I::IntoIter: ExactSizeIterator;
     fn write_ext_generic_map<K, V: Clone>(
         &mut self,
         map: &BTreeMap<K, V>,
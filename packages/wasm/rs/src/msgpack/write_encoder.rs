@@ -24,7 +24,7 @@ impl WriteEncoder {
 
     pub fn write_negative_fixed_int(&mut self, value: i8) -> Result<(), EncodeError> {
         // From 0xe0 (0b11100000) taking last 5 bits, to 0xff (0b11111111), taking last 5 bits
-        assert!(-32 <= value && value <= 0);
+        assert!((-32..=0).contains(&value));
         Format::set_format(self, Format::NegativeFixInt(value))
             .map_err(|e| EncodeError::FormatWriteError(e.to_string()))
     }
@@ -55,7 +55,7 @@ impl WriteEncoder {
             Ok(WriteBytesExt::write_u32::<BigEndian>(self, val as u32)?)
         } else {
             Format::set_format(self, Format::Uint64)?;
-            Ok(WriteBytesExt::write_u64::<BigEndian>(self, val as u64)?)
+            Ok(WriteBytesExt::write_u64::<BigEndian>(self, val)?)
         }
     }
 
@@ -68,9 +68,9 @@ impl WriteEncoder {
     pub fn write_i64(&mut self, value: &i64) -> Result<(), EncodeError> {
         let val = *value;
 
-        if val >= 0 && val < 1 << 7 {
+        if (0..1 << 7).contains(&val) {
           Ok(self.write_positive_fixed_int(val as u8)?)
-        } else if val < 0 && val >= -(1 << 5) {
+        } else if (-(1 << 5)..0).contains(&val) {
           Ok(self.write_negative_fixed_int(val as i8)?)
         } else if val <= i8::MAX as i64 && val >= i8::MIN as i64 {
           Format::set_format(self, Format::Int8)?;
@@ -83,7 +83,7 @@ impl WriteEncoder {
           Ok(WriteBytesExt::write_i32::<BigEndian>(self, val as i32)?)
         } else {
           Format::set_format(self, Format::Int64)?;
-          Ok(WriteBytesExt::write_i64::<BigEndian>(self, val as i64)?)
+          Ok(WriteBytesExt::write_i64::<BigEndian>(self, val)?)
         }
     }
 }
@@ -230,11 +230,24 @@ impl Write for WriteEncoder {
     fn write_array<T: Clone>(
         &mut self,
         array: &[T],
-        mut item_writer: impl FnMut(&mut Self, &T) -> Result<(), EncodeError>,
+        item_writer: impl FnMut(&mut Self, &T) -> Result<(), EncodeError>,
     ) -> Result<(), EncodeError> {
-        self.write_array_length(&(array.len() as u32))?;
-        for element in array {
-            item_writer(self, element)?;
+        self.write_array_iter(array, item_writer)
+    }
+
+    fn write_array_iter<I>(
+        &mut self,
+        items: I,
+        mut item_writer: impl FnMut(&mut Self, I::Item) -> Result<(), EncodeError>,
+    ) -> Result<(), EncodeError>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let items = items.into_iter();
+        self.write_array_length(&(items.len() as u32))?;
+        for item in items {
+            item_writer(self, item)?;
         }
         Ok(())
     }
@@ -256,16 +269,28 @@ impl Write for WriteEncoder {
     fn write_map<K, V: Clone>(
         &mut self,
         map: &BTreeMap<K, V>,
-        mut key_writer: impl FnMut(&mut Self, &K) -> Result<(), EncodeError>,
-        mut val_writer: impl FnMut(&mut Self, &V) -> Result<(), EncodeError>,
+        key_writer: impl FnMut(&mut Self, &K) -> Result<(), EncodeError>,
+        val_writer: impl FnMut(&mut Self, &V) -> Result<(), EncodeError>,
     ) -> Result<(), EncodeError>
     where
         K: Clone + Eq + Hash + Ord,
     {
-        self.write_map_length(&(map.len() as u32))?;
-        let keys: Vec<_> = map.keys().into_iter().collect();
-        for key in keys {
-            let value = &map[key];
+        self.write_map_iter(map, key_writer, val_writer)
+    }
+
+    fn write_map_iter<I, K, V>(
+        &mut self,
+        entries: I,
+        mut key_writer: impl FnMut(&mut Self, K) -> Result<(), EncodeError>,
+        mut val_writer: impl FnMut(&mut Self, V) -> Result<(), EncodeError>,
+    ) -> Result<(), EncodeError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let entries = entries.into_iter();
+        self.write_map_length(&(entries.len() as u32))?;
+        for (key, value) in entries {
             key_writer(self, key)?;
             val_writer(self, value)?;
         }
@@ -275,8 +300,8 @@ impl Write for WriteEncoder {
     fn write_ext_generic_map<K, V: Clone>(
         &mut self,
         map: &BTreeMap<K, V>,
-        mut key_writer: impl FnMut(&mut Self, &K) -> Result<(), EncodeError>,
-        mut val_writer: impl FnMut(&mut Self, &V) -> Result<(), EncodeError>,
+        key_writer: impl FnMut(&mut Self, &K) -> Result<(), EncodeError>,
+        val_writer: impl FnMut(&mut Self, &V) -> Result<(), EncodeError>,
     ) -> Result<(), EncodeError>
     where
         K: Clone + Eq + Hash + Ord,
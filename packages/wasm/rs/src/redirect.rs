@@ -0,0 +1,46 @@
+//! Wrapper-wide URI redirects, consulted by [`wrap_subinvoke`](crate::wrap_subinvoke)
+//! before calling the host. This points a dependency at another deployment,
+//! e.g. a staging or local test wrapper, without regenerating bindings.
+//!
+//! The table lives in the wrapper's memory, so it lasts as long as the
+//! wasm instance does. Hosts which instantiate a wrapper per invocation
+//! start every invocation with an empty table. Its entries are allocated
+//! with [`persist`], outside of the invocation arena.
+
+use crate::{malloc::persist, Uri};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+thread_local! {
+    static REDIRECTS: RefCell<BTreeMap<Uri, Uri>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Subinvoke `to` wherever `from` is subinvoked. Redirects aren't
+/// followed transitively, `to` is used as is.
+pub fn wrap_redirect<F, T>(from: F, to: T) -> Result<(), String>
+where
+    F: TryInto<Uri>,
+    F::Error: Display,
+    T: TryInto<Uri>,
+    T::Error: Display,
+{
+    let from = from.try_into().map_err(|e| e.to_string())?;
+    let to = to.try_into().map_err(|e| e.to_string())?;
+    persist(|| REDIRECTS.with(|redirects| redirects.borrow_mut().insert(from.clone(), to.clone())));
+    Ok(())
+}
+
+/// Remove the redirect from `from`, returning where it pointed
+pub fn wrap_remove_redirect(from: &Uri) -> Option<Uri> {
+    REDIRECTS.with(|redirects| redirects.borrow_mut().remove(from))
+}
+
+pub fn wrap_clear_redirects() {
+    REDIRECTS.with(|redirects| redirects.borrow_mut().clear());
+}
+
+/// Where a subinvoke of `uri` goes
+pub fn wrap_resolve_redirect(uri: Uri) -> Uri {
+    REDIRECTS.with(|redirects| redirects.borrow().get(&uri).cloned().unwrap_or(uri))
+}
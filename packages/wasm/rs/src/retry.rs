@@ -0,0 +1,108 @@
+//! Retrying failed subinvokes, and falling back to other URIs.
+//!
+//! A [`Retry`] makes up to `times + 1` attempts at the URI it's given, then
+//! at each of its fallbacks in order, and returns the first success or the
+//! last error. Every failed attempt is logged with [`wrap_debug_log`].
+//! Errors rejected by the `retry_if` predicate are returned right away.
+//!
+//! ```no_run
+//! use polywrap_wasm_rs::Retry;
+//!
+//! let retry = Retry::times(2)
+//!     .fallback("wrap://ens/ipfs-backup.eth")
+//!     .retry_if(|error| !error.contains("not found"));
+//! let result = retry.subinvoke("wrap://ens/ipfs.eth", "cat", vec![0x80]);
+//! ```
+
+use crate::{wrap_debug_log, wrap_subinvoke};
+use std::fmt;
+use std::rc::Rc;
+
+/// Decides whether an error is worth another attempt
+type RetryIf = Rc<dyn Fn(&str) -> bool>;
+
+/// How failed subinvokes are retried
+#[derive(Clone)]
+pub struct Retry {
+    times: u32,
+    fallbacks: Vec<String>,
+    retry_if: Option<RetryIf>,
+}
+
+impl Retry {
+    /// Retry each URI `times` times after its first attempt
+    pub fn times(times: u32) -> Retry {
+        Retry {
+            times,
+            fallbacks: vec![],
+            retry_if: None,
+        }
+    }
+
+    /// Try `uri` once the URIs before it have failed
+    pub fn fallback(mut self, uri: &str) -> Retry {
+        self.fallbacks.push(uri.to_string());
+        self
+    }
+
+    /// Only retry, or fall back, when `predicate` accepts the error
+    pub fn retry_if(mut self, predicate: impl Fn(&str) -> bool + 'static) -> Retry {
+        self.retry_if = Some(Rc::new(predicate));
+        self
+    }
+
+    pub fn fallbacks(&self) -> &[String] {
+        &self.fallbacks
+    }
+
+    /// Run `attempt` with `uri`, then with the fallbacks, until it succeeds.
+    /// `method` is only used for logging.
+    pub fn run<T>(
+        &self,
+        uri: &str,
+        method: &str,
+        mut attempt: impl FnMut(&str) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let attempts = self.times.saturating_add(1);
+        let mut last_error = None;
+        for uri in std::iter::once(uri).chain(self.fallbacks.iter().map(String::as_str)) {
+            for number in 1..=attempts {
+                let error = match attempt(uri) {
+                    Ok(result) => return Ok(result),
+                    Err(error) => error,
+                };
+                wrap_debug_log(&format!(
+                    "{} failed to invoke \"{}\" (attempt {} of {}): {}",
+                    uri, method, number, attempts, error
+                ));
+                if !self.retries(&error) {
+                    return Err(error);
+                }
+                last_error = Some(error);
+            }
+        }
+        Err(last_error.expect("at least one attempt"))
+    }
+
+    /// [`wrap_subinvoke`] with retries and fallbacks
+    pub fn subinvoke(&self, uri: &str, method: &str, args: Vec<u8>) -> Result<Vec<u8>, String> {
+        self.run(uri, method, |uri| wrap_subinvoke(uri, method, args.clone()))
+    }
+
+    fn retries(&self, error: &str) -> bool {
+        match &self.retry_if {
+            Some(retry_if) => retry_if(error),
+            None => true,
+        }
+    }
+}
+
+impl fmt::Debug for Retry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("times", &self.times)
+            .field("fallbacks", &self.fallbacks)
+            .field("retry_if", &self.retry_if.is_some())
+            .finish()
+    }
+}
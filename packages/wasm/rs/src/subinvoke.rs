@@ -1,4 +1,10 @@
-use crate::malloc::alloc;
+use crate::{
+    cassette::cassette_subinvoke, wrap_invocation_context, wrap_resolve_redirect, Context,
+    DecodeError, EncodeError, HostBuffer, Read, ReadDecoder, Uri, WrapEnv, Write, WriteEncoder,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
 
 #[link(wasm_import_module = "wrap")]
 extern "C" {
@@ -26,40 +32,193 @@ extern "C" {
 
     #[link_name = "__wrap_subinvoke_error"]
     pub fn __wrap_subinvoke_error(ptr: u32);
+
+    /// Subinvoke With Options, msgpack encoded. Results and errors are
+    /// read like those of `__wrap_subinvoke`.
+    #[link_name = "__wrap_subinvoke_with_options"]
+    pub fn __wrap_subinvoke_with_options(
+        uri_ptr: u32,
+        uri_len: u32,
+        method_ptr: u32,
+        method_len: u32,
+        args_ptr: u32,
+        args_len: u32,
+        options_ptr: u32,
+        options_len: u32,
+    ) -> bool;
+}
+
+/// URI, method and encoded args of a subinvoke
+type SubinvokeKey = (String, String, Vec<u8>);
+
+thread_local! {
+    /// Results of memoized subinvokes in the current invocation
+    static MEMOIZED: RefCell<HashMap<SubinvokeKey, Vec<u8>>> = RefCell::new(HashMap::new());
+}
+
+/// How a subinvoke deviates from what the client is configured with
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubinvokeOptions {
+    /// The msgpack encoded env to invoke with, instead of the one the
+    /// client has for the URI
+    pub env: Option<Vec<u8>>,
+}
+
+impl SubinvokeOptions {
+    pub fn with_env<E: WrapEnv>(env: &E) -> Result<SubinvokeOptions, EncodeError> {
+        let mut encoder = WriteEncoder::new(&[], Context::with_description("Env"));
+        env.write(&mut encoder)?;
+        Ok(SubinvokeOptions {
+            env: Some(encoder.get_buffer()),
+        })
+    }
+
+    /// Pass on the env of the current invocation, if it has one
+    pub fn forward_env() -> SubinvokeOptions {
+        SubinvokeOptions {
+            env: wrap_invocation_context().and_then(|context| context.env_buffer()),
+        }
+    }
+
+    pub fn to_buffer(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut encoder = WriteEncoder::new(&[], Context::with_description("Serializing SubinvokeOptions"));
+        self.write(&mut encoder)?;
+        Ok(encoder.get_buffer())
+    }
+
+    pub fn from_buffer(buffer: &[u8]) -> Result<SubinvokeOptions, DecodeError> {
+        let mut reader = ReadDecoder::new(buffer, Context::with_description("Deserializing SubinvokeOptions"));
+        SubinvokeOptions::read(&mut reader)
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_map_length(&1)?;
+        writer.write_string("env")?;
+        writer.write_optional_bytes(&self.env)?;
+        Ok(())
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<SubinvokeOptions, DecodeError> {
+        let mut num_of_fields = reader.read_map_length()?;
+        let mut options = SubinvokeOptions::default();
+
+        while num_of_fields > 0 {
+            num_of_fields -= 1;
+            let field = reader.read_string()?;
+
+            match field.as_str() {
+                "env" => options.env = reader.read_optional_bytes()?,
+                err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Subinvoke Interface Helper. `uri` is a [`Uri`], or a string which is
+/// parsed into one, failing without calling the host if it's malformed.
+/// The host is called with the URI's redirect, if one was added with
+/// [`wrap_redirect`](crate::wrap_redirect).
+pub fn wrap_subinvoke<U>(uri: U, method: &str, args: Vec<u8>) -> Result<Vec<u8>, String>
+where
+    U: TryInto<Uri>,
+    U::Error: Display,
+{
+    let uri = wrap_resolve_redirect(uri.try_into().map_err(|e| e.to_string())?);
+    subinvoke(&uri, method, &args)
+}
+
+/// Subinvoke `uri`, which redirects have been resolved for
+fn subinvoke(uri: &Uri, method: &str, args: &[u8]) -> Result<Vec<u8>, String> {
+    cassette_subinvoke(uri.uri(), method, args, None, || {
+        let uri_buf = uri.uri().as_bytes();
+        let method_buf = method.as_bytes();
+
+        let success = unsafe {
+            __wrap_subinvoke(
+                uri_buf.as_ptr() as u32,
+                uri_buf.len() as u32,
+                method_buf.as_ptr() as u32,
+                method_buf.len() as u32,
+                args.as_ptr() as u32,
+                args.len() as u32,
+            )
+        };
+        subinvoke_result(success)
+    })
 }
 
-/// Subinvoke Interface Helper
-pub fn wrap_subinvoke(
-    uri: &str,
+/// [`wrap_subinvoke`], reusing the result of an earlier call with the
+/// same URI, method and args in the current invocation. Only meant for
+/// methods without side effects. Errors aren't kept, so failed calls are
+/// made again.
+pub fn wrap_subinvoke_memoized<U>(uri: U, method: &str, args: Vec<u8>) -> Result<Vec<u8>, String>
+where
+    U: TryInto<Uri>,
+    U::Error: Display,
+{
+    let uri = wrap_resolve_redirect(uri.try_into().map_err(|e| e.to_string())?);
+    let key = (uri.uri().to_string(), method.to_string(), args);
+    if let Some(result) = MEMOIZED.with(|memoized| memoized.borrow().get(&key).cloned()) {
+        return Ok(result);
+    }
+
+    let result = subinvoke(&uri, method, &key.2)?;
+    MEMOIZED.with(|memoized| memoized.borrow_mut().insert(key, result.clone()));
+    Ok(result)
+}
+
+/// Drop the memos of the invocation. The map is replaced rather than
+/// cleared, as its table may have been allocated in the invocation arena.
+pub(crate) fn clear_memoized_subinvokes() {
+    MEMOIZED.with(|memoized| *memoized.borrow_mut() = HashMap::new());
+}
+
+/// [`wrap_subinvoke`], with [`SubinvokeOptions`] overriding the client's
+/// configuration for this call
+pub fn wrap_subinvoke_with_options<U>(
+    uri: U,
     method: &str,
     args: Vec<u8>,
-) -> Result<Vec<u8>, String> {
-    let uri_buf = uri.as_bytes();
-    let method_buf = method.as_bytes();
-
-    let success = unsafe {
-        __wrap_subinvoke(
-            uri_buf.as_ptr() as u32,
-            uri_buf.len() as u32,
-            method_buf.as_ptr() as u32,
-            method_buf.len() as u32,
-            args.as_ptr() as u32,
-            args.len() as u32,
-        )
-    };
+    options: &SubinvokeOptions,
+) -> Result<Vec<u8>, String>
+where
+    U: TryInto<Uri>,
+    U::Error: Display,
+{
+    let uri = wrap_resolve_redirect(uri.try_into().map_err(|e| e.to_string())?);
+    let options_buf = options.to_buffer().map_err(|e| e.to_string())?;
+
+    cassette_subinvoke(uri.uri(), method, &args, options.env.as_deref(), || {
+        let uri_buf = uri.uri().as_bytes();
+        let method_buf = method.as_bytes();
+
+        let success = unsafe {
+            __wrap_subinvoke_with_options(
+                uri_buf.as_ptr() as u32,
+                uri_buf.len() as u32,
+                method_buf.as_ptr() as u32,
+                method_buf.len() as u32,
+                args.as_ptr() as u32,
+                args.len() as u32,
+                options_buf.as_ptr() as u32,
+                options_buf.len() as u32,
+            )
+        };
+        subinvoke_result(success)
+    })
+}
+
+/// Fetch the result or error the host keeps for the last subinvoke
+fn subinvoke_result(success: bool) -> Result<Vec<u8>, String> {
     if !success {
         let error_len = unsafe { __wrap_subinvoke_error_len() };
-        let error_len_ptr = alloc(error_len as usize);
-        unsafe { __wrap_subinvoke_error(error_len_ptr as u32) };
-        let error = unsafe {
-            String::from_raw_parts(error_len_ptr, error_len as usize, error_len as usize)
-        };
-        return Err(error);
+        let error = HostBuffer::fill(error_len, |ptr| unsafe { __wrap_subinvoke_error(ptr) });
+        return Err(error
+            .into_string("subinvoke error")
+            .unwrap_or_else(|e| e.to_string()));
     }
     let result_len = unsafe { __wrap_subinvoke_result_len() };
-    let result_len_ptr = alloc(result_len as usize);
-    unsafe { __wrap_subinvoke_result(result_len_ptr as u32) };
-    let result_buf =
-        unsafe { Vec::from_raw_parts(result_len_ptr, result_len as usize, result_len as usize) };
-    Ok(result_buf)
+    let result = HostBuffer::fill(result_len, |ptr| unsafe { __wrap_subinvoke_result(ptr) });
+    Ok(result.into_bytes())
 }
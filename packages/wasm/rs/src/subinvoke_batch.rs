@@ -0,0 +1,218 @@
+//! Several subinvokes in one host call.
+//!
+//! The requests and their results cross the wasm boundary as msgpack
+//! lists, so the host pays for the crossing once, and can run requests
+//! which don't depend on each other concurrently. Results come back in the
+//! order of the requests.
+
+use crate::{
+    cassette::cassette_subinvoke_batch, wrap_resolve_redirect, Context, DecodeError, EncodeError,
+    HostBuffer, Read, ReadDecoder, SubinvokeOptions, Uri, Write, WriteEncoder,
+};
+use thiserror::Error;
+
+#[link(wasm_import_module = "wrap")]
+extern "C" {
+    /// Subinvoke Batch, a msgpack encoded list of requests
+    #[link_name = "__wrap_subinvoke_batch"]
+    pub fn __wrap_subinvoke_batch(requests_ptr: u32, requests_len: u32);
+
+    /// Subinvoke Batch Result, a msgpack encoded list of results
+    #[link_name = "__wrap_subinvoke_batch_result_len"]
+    pub fn __wrap_subinvoke_batch_result_len() -> u32;
+
+    #[link_name = "__wrap_subinvoke_batch_result"]
+    pub fn __wrap_subinvoke_batch_result(ptr: u32);
+}
+
+/// One subinvoke of a batch
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubinvokeRequest {
+    pub uri: String,
+    pub method: String,
+    /// The msgpack encoded args
+    pub args: Vec<u8>,
+    pub options: SubinvokeOptions,
+}
+
+/// Why a subinvoke of a batch failed
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("{message}")]
+pub struct SubinvokeError {
+    pub uri: String,
+    pub method: String,
+    pub message: String,
+}
+
+impl SubinvokeRequest {
+    pub fn new(uri: &str, method: &str, args: Vec<u8>) -> SubinvokeRequest {
+        SubinvokeRequest {
+            uri: uri.to_string(),
+            method: method.to_string(),
+            args,
+            options: SubinvokeOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: SubinvokeOptions) -> SubinvokeRequest {
+        self.options = options;
+        self
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_map_length(&4)?;
+        writer.write_string("uri")?;
+        writer.write_string(&self.uri)?;
+        writer.write_string("method")?;
+        writer.write_string(&self.method)?;
+        writer.write_string("args")?;
+        writer.write_bytes(&self.args)?;
+        writer.write_string("options")?;
+        self.options.write(writer)?;
+        Ok(())
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<SubinvokeRequest, DecodeError> {
+        let mut num_of_fields = reader.read_map_length()?;
+        let mut request = SubinvokeRequest::default();
+        let mut uri_set = false;
+        let mut method_set = false;
+
+        while num_of_fields > 0 {
+            num_of_fields -= 1;
+            let field = reader.read_string()?;
+
+            match field.as_str() {
+                "uri" => {
+                    request.uri = reader.read_string()?;
+                    uri_set = true;
+                }
+                "method" => {
+                    request.method = reader.read_string()?;
+                    method_set = true;
+                }
+                "args" => request.args = reader.read_bytes()?,
+                "options" => request.options = SubinvokeOptions::read(reader)?,
+                err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+            }
+        }
+        if !uri_set {
+            return Err(DecodeError::MissingField("uri: String.".to_string()));
+        }
+        if !method_set {
+            return Err(DecodeError::MissingField("method: String.".to_string()));
+        }
+        Ok(request)
+    }
+}
+
+pub fn serialize_subinvoke_requests(requests: &[SubinvokeRequest]) -> Result<Vec<u8>, EncodeError> {
+    let mut encoder = WriteEncoder::new(&[], Context::with_description("Serializing subinvoke requests"));
+    encoder.write_array(requests, |writer, request| request.write(writer))?;
+    Ok(encoder.get_buffer())
+}
+
+pub fn deserialize_subinvoke_requests(buffer: &[u8]) -> Result<Vec<SubinvokeRequest>, DecodeError> {
+    let mut reader = ReadDecoder::new(buffer, Context::with_description("Deserializing subinvoke requests"));
+    reader.read_array(SubinvokeRequest::read)
+}
+
+/// Encode the results of a batch, each a map with either a `result` or
+/// an `error`
+pub fn serialize_subinvoke_results(results: &[Result<Vec<u8>, String>]) -> Result<Vec<u8>, EncodeError> {
+    let mut encoder = WriteEncoder::new(&[], Context::with_description("Serializing subinvoke results"));
+    encoder.write_array(results, |writer, result| {
+        writer.write_map_length(&1)?;
+        match result {
+            Ok(result) => {
+                writer.write_string("result")?;
+                writer.write_bytes(result)
+            }
+            Err(error) => {
+                writer.write_string("error")?;
+                writer.write_string(error)
+            }
+        }
+    })?;
+    Ok(encoder.get_buffer())
+}
+
+pub fn deserialize_subinvoke_results(buffer: &[u8]) -> Result<Vec<Result<Vec<u8>, String>>, DecodeError> {
+    let mut reader = ReadDecoder::new(buffer, Context::with_description("Deserializing subinvoke results"));
+    reader.read_array(|reader| {
+        if reader.read_map_length()? != 1 {
+            return Err(DecodeError::MissingField("result or error.".to_string()));
+        }
+        let field = reader.read_string()?;
+        match field.as_str() {
+            "result" => Ok(Ok(reader.read_bytes()?)),
+            "error" => Ok(Err(reader.read_string()?)),
+            err => Err(DecodeError::UnknownFieldName(err.to_string())),
+        }
+    })
+}
+
+/// Run `requests` in one host call, returning their results in order.
+/// Redirects apply to each request, and requests with a malformed URI fail
+/// without reaching the host.
+pub fn wrap_subinvoke_batch(requests: Vec<SubinvokeRequest>) -> Vec<Result<Vec<u8>, SubinvokeError>> {
+    let mut results: Vec<Option<Result<Vec<u8>, SubinvokeError>>> = Vec::with_capacity(requests.len());
+    let mut sent = vec![];
+    for mut request in requests {
+        match resolve(&request.uri) {
+            Ok(uri) => {
+                request.uri = uri;
+                results.push(None);
+                sent.push(request);
+            }
+            Err(message) => results.push(Some(Err(SubinvokeError {
+                uri: request.uri,
+                method: request.method,
+                message,
+            }))),
+        }
+    }
+
+    let host_results = cassette_subinvoke_batch(&sent, || match send(&sent) {
+        Ok(host_results) if host_results.len() == sent.len() => host_results,
+        Ok(host_results) => {
+            let message = format!("expected {} subinvoke results, got {}", sent.len(), host_results.len());
+            vec![Err(message); sent.len()]
+        }
+        Err(message) => vec![Err(message); sent.len()],
+    });
+    let mut host_results = host_results.into_iter();
+    let mut sent = sent.into_iter();
+    results
+        .into_iter()
+        .map(|result| {
+            result.unwrap_or_else(|| {
+                let request = sent.next().expect("a sent request for each result");
+                let result = host_results.next().expect("a host result for each sent request");
+                result.map_err(|message| SubinvokeError {
+                    uri: request.uri,
+                    method: request.method,
+                    message,
+                })
+            })
+        })
+        .collect()
+}
+
+fn resolve(uri: &str) -> Result<String, String> {
+    let uri = Uri::new(uri).map_err(|e| e.to_string())?;
+    Ok(wrap_resolve_redirect(uri).uri().to_string())
+}
+
+fn send(requests: &[SubinvokeRequest]) -> Result<Vec<Result<Vec<u8>, String>>, String> {
+    if requests.is_empty() {
+        return Ok(vec![]);
+    }
+    let buffer = serialize_subinvoke_requests(requests).map_err(|e| e.to_string())?;
+    unsafe { __wrap_subinvoke_batch(buffer.as_ptr() as u32, buffer.len() as u32) };
+
+    let result_len = unsafe { __wrap_subinvoke_batch_result_len() };
+    let result = HostBuffer::fill(result_len, |ptr| unsafe { __wrap_subinvoke_batch_result(ptr) });
+    deserialize_subinvoke_results(result.as_bytes()).map_err(|e| e.to_string())
+}
+
@@ -0,0 +1,210 @@
+//! A `tracing` subscriber which forwards spans and events to the host.
+//!
+//! Each span enter/exit and each event is serialized as a single line of
+//! JSON and written with `__wrap_debug_log`, so it shows up next to the
+//! client's own trace output. Nothing is recorded unless the subscriber
+//! has been installed with [`wrap_tracing_setup`].
+
+use crate::{malloc::persist, wrap_debug_log, JSON};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+use tracing_core::{
+    dispatcher::{self, SetGlobalDefaultError},
+    field::{Field, Visit},
+    span, Dispatch, Event, LevelFilter, Metadata, Subscriber,
+};
+
+/// Function used by [`WrapSubscriber`] to emit serialized records
+pub type TraceWriter = fn(record: &str);
+
+struct SpanData {
+    metadata: &'static Metadata<'static>,
+    fields: JSON::Map<String, JSON::Value>,
+    ref_count: usize,
+}
+
+/// `tracing_core::Subscriber` implementation backed by the host
+pub struct WrapSubscriber {
+    max_level: LevelFilter,
+    writer: TraceWriter,
+    next_id: AtomicU64,
+    spans: Mutex<HashMap<u64, SpanData>>,
+    stack: Mutex<Vec<span::Id>>,
+}
+
+/// Install a [`WrapSubscriber`] as the global default, recording
+/// everything at or above `max_level`.
+pub fn wrap_tracing_setup(max_level: LevelFilter) -> Result<(), SetGlobalDefaultError> {
+    persist(|| dispatcher::set_global_default(Dispatch::new(WrapSubscriber::new(max_level))))
+}
+
+impl WrapSubscriber {
+    pub fn new(max_level: LevelFilter) -> Self {
+        Self::with_writer(max_level, wrap_debug_log)
+    }
+
+    /// Emit records through `writer` instead of `__wrap_debug_log`
+    pub fn with_writer(max_level: LevelFilter, writer: TraceWriter) -> Self {
+        Self {
+            max_level,
+            writer,
+            next_id: AtomicU64::new(1),
+            spans: Mutex::new(HashMap::new()),
+            stack: Mutex::new(vec![]),
+        }
+    }
+
+    fn emit_span(&self, kind: &str, id: &span::Id) {
+        let spans = self.spans.lock().unwrap();
+        if let Some(span) = spans.get(&id.into_u64()) {
+            let record = JSON::json!({
+                "kind": kind,
+                "id": id.into_u64(),
+                "name": span.metadata.name(),
+                "target": span.metadata.target(),
+                "level": span.metadata.level().to_string(),
+                "fields": span.fields,
+            });
+            (self.writer)(&record.to_string());
+        }
+    }
+}
+
+impl Subscriber for WrapSubscriber {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= &self.max_level
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(self.max_level)
+    }
+
+    // Spans and the stack can outlive the invocation they're opened in, so
+    // what they keep is allocated outside of the invocation arena
+    fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        persist(|| {
+            let mut fields = JSON::Map::new();
+            attrs.record(&mut JsonVisitor(&mut fields));
+
+            self.spans.lock().unwrap().insert(
+                id,
+                SpanData {
+                    metadata: attrs.metadata(),
+                    fields,
+                    ref_count: 1,
+                },
+            );
+        });
+        span::Id::from_u64(id)
+    }
+
+    fn record(&self, span: &span::Id, values: &span::Record<'_>) {
+        if let Some(span) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            persist(|| values.record(&mut JsonVisitor(&mut span.fields)));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let metadata = event.metadata();
+        let mut fields = JSON::Map::new();
+        event.record(&mut JsonVisitor(&mut fields));
+
+        let parent = if event.is_contextual() {
+            self.stack.lock().unwrap().last().map(|id| id.into_u64())
+        } else {
+            event.parent().map(|id| id.into_u64())
+        };
+
+        let record = JSON::json!({
+            "kind": "event",
+            "name": metadata.name(),
+            "target": metadata.target(),
+            "level": metadata.level().to_string(),
+            "parent": parent,
+            "fields": fields,
+        });
+        (self.writer)(&record.to_string());
+    }
+
+    fn enter(&self, span: &span::Id) {
+        persist(|| self.stack.lock().unwrap().push(span.clone()));
+        self.emit_span("enter", span);
+    }
+
+    fn exit(&self, span: &span::Id) {
+        self.emit_span("exit", span);
+        let mut stack = self.stack.lock().unwrap();
+        if let Some(position) = stack.iter().rposition(|id| id == span) {
+            stack.remove(position);
+        }
+    }
+
+    fn clone_span(&self, id: &span::Id) -> span::Id {
+        if let Some(span) = self.spans.lock().unwrap().get_mut(&id.into_u64()) {
+            span.ref_count += 1;
+        }
+        id.clone()
+    }
+
+    fn try_close(&self, id: span::Id) -> bool {
+        let mut spans = self.spans.lock().unwrap();
+        match spans.get_mut(&id.into_u64()) {
+            Some(span) if span.ref_count > 1 => {
+                span.ref_count -= 1;
+                false
+            }
+            Some(_) => {
+                spans.remove(&id.into_u64());
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn current_span(&self) -> span::Current {
+        let stack = self.stack.lock().unwrap();
+        let spans = self.spans.lock().unwrap();
+        match stack.last().and_then(|id| Some((id, spans.get(&id.into_u64())?))) {
+            Some((id, span)) => span::Current::new(id.clone(), span.metadata),
+            None => span::Current::none(),
+        }
+    }
+}
+
+struct JsonVisitor<'a>(&'a mut JSON::Map<String, JSON::Value>);
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), JSON::json!(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), JSON::json!(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), JSON::json!(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), JSON::json!(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), JSON::json!(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), JSON::json!(format!("{:?}", value)));
+    }
+}
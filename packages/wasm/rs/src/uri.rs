@@ -0,0 +1,135 @@
+//! Polywrap URIs, e.g. `wrap://ens/domain.eth` or `wrap://ipfs/QmHASH`.
+//!
+//! Parsing follows `Uri.parseUri` of the JS client: leading `/`s are
+//! trimmed and the `wrap://` scheme is added when it's missing, so
+//! `ens/domain.eth` and `wrap://ens/domain.eth` are the same URI.
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+const SCHEME: &str = "wrap://";
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum UriError {
+    #[error("The provided URI is empty")]
+    Empty,
+
+    #[error("The wrap:// scheme must be at the beginning of the URI string")]
+    SchemeNotAtStart,
+
+    #[error(
+        "URI is malformed, here are some examples of valid URIs:\nwrap://ipfs/QmHASH\nwrap://ens/domain.eth\nens/domain.eth\n\nInvalid URI Received: {0}"
+    )]
+    Malformed(String),
+}
+
+/// A validated, normalized URI
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uri {
+    uri: String,
+    /// End of the authority in `uri`, where the `/` before the path is
+    authority_end: usize,
+}
+
+impl Uri {
+    pub fn new(uri: &str) -> Result<Uri, UriError> {
+        if uri.is_empty() {
+            return Err(UriError::Empty);
+        }
+
+        let trimmed = uri.trim_start_matches('/');
+        let uri_string = match trimmed.find(SCHEME) {
+            Some(0) => trimmed.to_string(),
+            Some(_) => return Err(UriError::SchemeNotAtStart),
+            None => format!("{}{}", SCHEME, trimmed),
+        };
+
+        // The authority is a lowercase letter followed by at least one
+        // lowercase letter, digit, `-` or `_`, and the path isn't empty
+        let rest = &uri_string[SCHEME.len()..];
+        let malformed = || UriError::Malformed(uri.to_string());
+        let (authority, path) = rest.split_once('/').ok_or_else(malformed)?;
+        let mut chars = authority.chars();
+        let valid_authority = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+            && authority.len() > 1
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid_authority || path.is_empty() {
+            return Err(malformed());
+        }
+
+        Ok(Uri {
+            authority_end: SCHEME.len() + authority.len(),
+            uri: uri_string,
+        })
+    }
+
+    pub fn is_valid(uri: &str) -> bool {
+        Uri::new(uri).is_ok()
+    }
+
+    /// The full URI, including its `wrap://` scheme
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// The authority which resolves the URI, e.g. `ens`
+    pub fn authority(&self) -> &str {
+        &self.uri[SCHEME.len()..self.authority_end]
+    }
+
+    /// Where the wrapper is, for the authority, e.g. `domain.eth`
+    pub fn path(&self) -> &str {
+        &self.uri[self.authority_end + 1..]
+    }
+}
+
+impl fmt::Display for Uri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.uri)
+    }
+}
+
+impl FromStr for Uri {
+    type Err = UriError;
+
+    fn from_str(uri: &str) -> Result<Uri, UriError> {
+        Uri::new(uri)
+    }
+}
+
+impl TryFrom<&str> for Uri {
+    type Error = UriError;
+
+    fn try_from(uri: &str) -> Result<Uri, UriError> {
+        Uri::new(uri)
+    }
+}
+
+impl TryFrom<&String> for Uri {
+    type Error = UriError;
+
+    fn try_from(uri: &String) -> Result<Uri, UriError> {
+        Uri::new(uri)
+    }
+}
+
+impl TryFrom<String> for Uri {
+    type Error = UriError;
+
+    fn try_from(uri: String) -> Result<Uri, UriError> {
+        Uri::new(&uri)
+    }
+}
+
+impl From<&Uri> for Uri {
+    fn from(uri: &Uri) -> Uri {
+        uri.clone()
+    }
+}
+
+impl AsRef<str> for Uri {
+    fn as_ref(&self) -> &str {
+        &self.uri
+    }
+}
@@ -0,0 +1,809 @@
+//! Definitions making up a wrapper's ABI, version 0.1.
+//!
+//! Definitions which extend others in the JSON schema embed them instead,
+//! e.g. [`ImportedObjectDefinition`] holds an [`ObjectDefinition`] and an
+//! [`ImportedDefinition`]. All of their fields are still read from and
+//! written to a single msgpack map.
+
+use super::fields::{
+    count, read_object, read_objects, write_object, write_objects, write_optional_bool,
+    write_optional_object, write_optional_string, Fields,
+};
+use crate::{DecodeError, EncodeError, Read, Write};
+
+/// Bit flags describing what a definition is, matching `DefinitionKind`
+/// in `@polywrap/schema-parse`
+pub struct DefinitionKind;
+
+impl DefinitionKind {
+    pub const GENERIC: u32 = 0;
+    pub const OBJECT: u32 = 1 << 0;
+    pub const ANY: u32 = 1 << 1;
+    pub const SCALAR: u32 = 1 << 2;
+    pub const ENUM: u32 = 1 << 3;
+    pub const ARRAY: u32 = (1 << 4) | Self::ANY;
+    pub const PROPERTY: u32 = (1 << 5) | Self::ANY;
+    pub const METHOD: u32 = 1 << 6;
+    pub const MODULE: u32 = 1 << 7;
+    pub const IMPORTED_MODULE: u32 = 1 << 8;
+    pub const IMPORTED_ENUM: u32 = (1 << 9) | Self::ENUM;
+    pub const IMPORTED_OBJECT: u32 = (1 << 10) | Self::OBJECT;
+    pub const INTERFACE_IMPLEMENTED: u32 = 1 << 11;
+    pub const UNRESOLVED_OBJECT_OR_ENUM: u32 = 1 << 12;
+    pub const OBJECT_REF: u32 = 1 << 13;
+    pub const ENUM_REF: u32 = 1 << 14;
+    pub const INTERFACE: u32 = 1 << 15;
+    pub const ENV: u32 = 1 << 16;
+    pub const MAP_KEY: u32 = 1 << 17;
+    pub const MAP: u32 = (1 << 18) | Self::ANY;
+    pub const IMPORTED_ENV: u32 = 1 << 19;
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GenericDefinition {
+    pub kind: u32,
+    pub _type: String,
+    pub name: Option<String>,
+    pub required: Option<bool>,
+}
+
+impl GenericDefinition {
+    pub fn is_kind(&self, kind: u32) -> bool {
+        self.kind & kind == kind
+    }
+}
+
+impl Fields for GenericDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "kind" => self.kind = reader.read_u32()?,
+            "type" => self._type = reader.read_string()?,
+            "name" => self.name = reader.read_optional_string()?,
+            "required" => self.required = reader.read_optional_bool()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        2 + count(&[self.name.is_some(), self.required.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_string("kind")?;
+        writer.write_u32(&self.kind)?;
+        writer.write_string("type")?;
+        writer.write_string(&self._type)?;
+        write_optional_string("name", &self.name, writer)?;
+        write_optional_bool("required", &self.required, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        vec![("kind", "Number"), ("type", "String")]
+    }
+}
+
+/// Scalar, object and enum references, and implemented interfaces
+pub type ScalarDefinition = GenericDefinition;
+pub type ObjectRef = GenericDefinition;
+pub type EnumRef = GenericDefinition;
+pub type UnresolvedObjectOrEnumRef = GenericDefinition;
+pub type InterfaceImplementedDefinition = GenericDefinition;
+
+/// A type, which is one of an array, scalar, map, object or enum
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnyDefinition {
+    pub generic: GenericDefinition,
+    pub array: Option<Box<ArrayDefinition>>,
+    pub scalar: Option<ScalarDefinition>,
+    pub map: Option<Box<MapDefinition>>,
+    pub object: Option<ObjectRef>,
+    pub _enum: Option<EnumRef>,
+    pub unresolved_object_or_enum: Option<UnresolvedObjectOrEnumRef>,
+}
+
+impl Fields for AnyDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "array" => self.array = Some(Box::new(read_object(reader)?)),
+            "scalar" => self.scalar = Some(read_object(reader)?),
+            "map" => self.map = Some(Box::new(read_object(reader)?)),
+            "object" => self.object = Some(read_object(reader)?),
+            "enum" => self._enum = Some(read_object(reader)?),
+            "unresolvedObjectOrEnum" => self.unresolved_object_or_enum = Some(read_object(reader)?),
+            _ => return self.generic.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.generic.field_count()
+            + count(&[
+                self.array.is_some(),
+                self.scalar.is_some(),
+                self.map.is_some(),
+                self.object.is_some(),
+                self._enum.is_some(),
+                self.unresolved_object_or_enum.is_some(),
+            ])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.generic.write_fields(writer)?;
+        write_optional_object("array", self.array.as_deref(), writer)?;
+        write_optional_object("scalar", self.scalar.as_ref(), writer)?;
+        write_optional_object("map", self.map.as_deref(), writer)?;
+        write_optional_object("object", self.object.as_ref(), writer)?;
+        write_optional_object("enum", self._enum.as_ref(), writer)?;
+        write_optional_object(
+            "unresolvedObjectOrEnum",
+            self.unresolved_object_or_enum.as_ref(),
+            writer,
+        )
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        GenericDefinition::required_fields()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArrayDefinition {
+    pub any: AnyDefinition,
+    pub item: Option<Box<NestedDefinition>>,
+}
+
+impl Fields for ArrayDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "item" => self.item = Some(Box::new(read_object(reader)?)),
+            _ => return self.any.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.any.field_count() + count(&[self.item.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.any.write_fields(writer)?;
+        write_optional_object("item", self.item.as_deref(), writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        AnyDefinition::required_fields()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MapDefinition {
+    pub any: AnyDefinition,
+    pub comment: Option<String>,
+    pub key: Option<Box<NestedDefinition>>,
+    pub value: Option<Box<NestedDefinition>>,
+}
+
+impl Fields for MapDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            "key" => self.key = Some(Box::new(read_object(reader)?)),
+            "value" => self.value = Some(Box::new(read_object(reader)?)),
+            _ => return self.any.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.any.field_count()
+            + count(&[self.comment.is_some(), self.key.is_some(), self.value.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.any.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)?;
+        write_optional_object("key", self.key.as_deref(), writer)?;
+        write_optional_object("value", self.value.as_deref(), writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        AnyDefinition::required_fields()
+    }
+}
+
+/// The item of an array, or the key or value of a map.
+///
+/// These are written as whichever definition the type is, so an array of
+/// arrays has an `item` with its own `item`. This carries the fields of
+/// both [`ArrayDefinition`] and [`MapDefinition`] to cover all of them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NestedDefinition {
+    pub any: AnyDefinition,
+    pub comment: Option<String>,
+    pub item: Option<Box<NestedDefinition>>,
+    pub key: Option<Box<NestedDefinition>>,
+    pub value: Option<Box<NestedDefinition>>,
+}
+
+impl Fields for NestedDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            "item" => self.item = Some(Box::new(read_object(reader)?)),
+            "key" => self.key = Some(Box::new(read_object(reader)?)),
+            "value" => self.value = Some(Box::new(read_object(reader)?)),
+            _ => return self.any.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.any.field_count()
+            + count(&[
+                self.comment.is_some(),
+                self.item.is_some(),
+                self.key.is_some(),
+                self.value.is_some(),
+            ])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.any.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)?;
+        write_optional_object("item", self.item.as_deref(), writer)?;
+        write_optional_object("key", self.key.as_deref(), writer)?;
+        write_optional_object("value", self.value.as_deref(), writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        AnyDefinition::required_fields()
+    }
+}
+
+/// An object property, method argument or method return type
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PropertyDefinition {
+    pub any: AnyDefinition,
+    pub comment: Option<String>,
+}
+
+impl Fields for PropertyDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            _ => return self.any.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.any.field_count() + count(&[self.comment.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.any.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        AnyDefinition::required_fields()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjectDefinition {
+    pub generic: GenericDefinition,
+    pub comment: Option<String>,
+    pub properties: Vec<PropertyDefinition>,
+    pub interfaces: Vec<InterfaceImplementedDefinition>,
+}
+
+impl Fields for ObjectDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            "properties" => self.properties = read_objects(reader)?,
+            "interfaces" => self.interfaces = read_objects(reader)?,
+            _ => return self.generic.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.generic.field_count()
+            + count(&[
+                self.comment.is_some(),
+                !self.properties.is_empty(),
+                !self.interfaces.is_empty(),
+            ])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.generic.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)?;
+        write_objects("properties", &self.properties, writer)?;
+        write_objects("interfaces", &self.interfaces, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        GenericDefinition::required_fields()
+    }
+}
+
+pub type EnvDefinition = ObjectDefinition;
+
+/// Env requirements of a method
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MethodEnv {
+    pub required: Option<bool>,
+}
+
+impl Fields for MethodEnv {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "required" => self.required = reader.read_optional_bool()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        count(&[self.required.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        write_optional_bool("required", &self.required, writer)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MethodDefinition {
+    pub generic: GenericDefinition,
+    pub comment: Option<String>,
+    pub arguments: Vec<PropertyDefinition>,
+    pub env: Option<MethodEnv>,
+    pub _return: PropertyDefinition,
+}
+
+impl Fields for MethodDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            "arguments" => self.arguments = read_objects(reader)?,
+            "env" => self.env = Some(read_object(reader)?),
+            "return" => self._return = read_object(reader)?,
+            _ => return self.generic.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.generic.field_count()
+            + 1
+            + count(&[
+                self.comment.is_some(),
+                !self.arguments.is_empty(),
+                self.env.is_some(),
+            ])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.generic.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)?;
+        write_objects("arguments", &self.arguments, writer)?;
+        write_optional_object("env", self.env.as_ref(), writer)?;
+        writer.write_string("return")?;
+        write_object(&self._return, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        let mut fields = GenericDefinition::required_fields();
+        fields.push(("return", "PropertyDefinition"));
+        fields
+    }
+}
+
+/// Reference to a module imported by the wrapper's own module
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportedModuleRef {
+    pub _type: Option<String>,
+}
+
+impl Fields for ImportedModuleRef {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "type" => self._type = reader.read_optional_string()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        count(&[self._type.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        write_optional_string("type", &self._type, writer)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleDefinition {
+    pub generic: GenericDefinition,
+    pub comment: Option<String>,
+    pub methods: Vec<MethodDefinition>,
+    pub imports: Vec<ImportedModuleRef>,
+    pub interfaces: Vec<InterfaceImplementedDefinition>,
+}
+
+impl Fields for ModuleDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            "methods" => self.methods = read_objects(reader)?,
+            "imports" => self.imports = read_objects(reader)?,
+            "interfaces" => self.interfaces = read_objects(reader)?,
+            _ => return self.generic.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.generic.field_count()
+            + count(&[
+                self.comment.is_some(),
+                !self.methods.is_empty(),
+                !self.imports.is_empty(),
+                !self.interfaces.is_empty(),
+            ])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.generic.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)?;
+        write_objects("methods", &self.methods, writer)?;
+        write_objects("imports", &self.imports, writer)?;
+        write_objects("interfaces", &self.interfaces, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        GenericDefinition::required_fields()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EnumDefinition {
+    pub generic: GenericDefinition,
+    pub comment: Option<String>,
+    pub constants: Vec<String>,
+}
+
+impl Fields for EnumDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            "constants" => self.constants = reader.read_array(|reader| reader.read_string())?,
+            _ => return self.generic.read_field(field, reader),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.generic.field_count() + 1 + count(&[self.comment.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.generic.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)?;
+        writer.write_string("constants")?;
+        writer.write_array(&self.constants, |writer, constant| writer.write_string(constant))
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        let mut fields = GenericDefinition::required_fields();
+        fields.push(("constants", "[String]"));
+        fields
+    }
+}
+
+/// Where an imported definition comes from
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportedDefinition {
+    pub uri: String,
+    pub namespace: String,
+    pub native_type: String,
+}
+
+impl Fields for ImportedDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "uri" => self.uri = reader.read_string()?,
+            "namespace" => self.namespace = reader.read_string()?,
+            "nativeType" => self.native_type = reader.read_string()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        3
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_string("uri")?;
+        writer.write_string(&self.uri)?;
+        writer.write_string("namespace")?;
+        writer.write_string(&self.namespace)?;
+        writer.write_string("nativeType")?;
+        writer.write_string(&self.native_type)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        vec![("uri", "String"), ("namespace", "String"), ("nativeType", "String")]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GetImplementationsCapability {
+    pub enabled: bool,
+}
+
+impl Fields for GetImplementationsCapability {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "enabled" => self.enabled = reader.read_bool()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        1
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_string("enabled")?;
+        writer.write_bool(&self.enabled)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        vec![("enabled", "Boolean")]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CapabilityDefinition {
+    pub get_implementations: Option<GetImplementationsCapability>,
+}
+
+impl Fields for CapabilityDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "getImplementations" => self.get_implementations = Some(read_object(reader)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        count(&[self.get_implementations.is_some()])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        write_optional_object("getImplementations", self.get_implementations.as_ref(), writer)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InterfaceDefinition {
+    pub generic: GenericDefinition,
+    pub imported: ImportedDefinition,
+    pub capabilities: CapabilityDefinition,
+}
+
+impl Fields for InterfaceDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "capabilities" => self.capabilities = read_object(reader)?,
+            _ => {
+                return Ok(self.generic.read_field(field, reader)?
+                    || self.imported.read_field(field, reader)?)
+            }
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.generic.field_count() + self.imported.field_count() + 1
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.generic.write_fields(writer)?;
+        self.imported.write_fields(writer)?;
+        writer.write_string("capabilities")?;
+        write_object(&self.capabilities, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        let mut fields = GenericDefinition::required_fields();
+        fields.extend(ImportedDefinition::required_fields());
+        fields.push(("capabilities", "CapabilityDefinition"));
+        fields
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportedObjectDefinition {
+    pub object: ObjectDefinition,
+    pub imported: ImportedDefinition,
+}
+
+impl Fields for ImportedObjectDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        Ok(self.object.read_field(field, reader)? || self.imported.read_field(field, reader)?)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.object.field_count() + self.imported.field_count()
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.object.write_fields(writer)?;
+        self.imported.write_fields(writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        let mut fields = ObjectDefinition::required_fields();
+        fields.extend(ImportedDefinition::required_fields());
+        fields
+    }
+}
+
+pub type ImportedEnvDefinition = ImportedObjectDefinition;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportedModuleDefinition {
+    pub generic: GenericDefinition,
+    pub imported: ImportedDefinition,
+    pub comment: Option<String>,
+    pub methods: Vec<MethodDefinition>,
+    pub is_interface: Option<bool>,
+}
+
+impl Fields for ImportedModuleDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "comment" => self.comment = reader.read_optional_string()?,
+            "methods" => self.methods = read_objects(reader)?,
+            "isInterface" => self.is_interface = reader.read_optional_bool()?,
+            _ => {
+                return Ok(self.generic.read_field(field, reader)?
+                    || self.imported.read_field(field, reader)?)
+            }
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        self.generic.field_count()
+            + self.imported.field_count()
+            + count(&[
+                self.comment.is_some(),
+                !self.methods.is_empty(),
+                self.is_interface.is_some(),
+            ])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self.generic.write_fields(writer)?;
+        self.imported.write_fields(writer)?;
+        write_optional_string("comment", &self.comment, writer)?;
+        write_objects("methods", &self.methods, writer)?;
+        write_optional_bool("isInterface", &self.is_interface, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        let mut fields = GenericDefinition::required_fields();
+        fields.extend(ImportedDefinition::required_fields());
+        fields
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportedEnumDefinition {
+    pub _enum: EnumDefinition,
+    pub imported: ImportedDefinition,
+}
+
+impl Fields for ImportedEnumDefinition {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        Ok(self._enum.read_field(field, reader)? || self.imported.read_field(field, reader)?)
+    }
+
+    fn field_count(&self) -> u32 {
+        self._enum.field_count() + self.imported.field_count()
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        self._enum.write_fields(writer)?;
+        self.imported.write_fields(writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        let mut fields = EnumDefinition::required_fields();
+        fields.extend(ImportedDefinition::required_fields());
+        fields
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WrapAbi {
+    pub version: Option<String>,
+    pub object_types: Vec<ObjectDefinition>,
+    pub module_type: Option<ModuleDefinition>,
+    pub enum_types: Vec<EnumDefinition>,
+    pub interface_types: Vec<InterfaceDefinition>,
+    pub imported_object_types: Vec<ImportedObjectDefinition>,
+    pub imported_module_types: Vec<ImportedModuleDefinition>,
+    pub imported_enum_types: Vec<ImportedEnumDefinition>,
+    pub imported_env_types: Vec<ImportedEnvDefinition>,
+    pub env_type: Option<EnvDefinition>,
+}
+
+impl WrapAbi {
+    pub fn write<W: Write>(args: &WrapAbi, writer: &mut W) -> Result<(), EncodeError> {
+        write_object(args, writer)
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<WrapAbi, DecodeError> {
+        read_object(reader)
+    }
+}
+
+impl Fields for WrapAbi {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "version" => self.version = reader.read_optional_string()?,
+            "objectTypes" => self.object_types = read_objects(reader)?,
+            "moduleType" => self.module_type = Some(read_object(reader)?),
+            "enumTypes" => self.enum_types = read_objects(reader)?,
+            "interfaceTypes" => self.interface_types = read_objects(reader)?,
+            "importedObjectTypes" => self.imported_object_types = read_objects(reader)?,
+            "importedModuleTypes" => self.imported_module_types = read_objects(reader)?,
+            "importedEnumTypes" => self.imported_enum_types = read_objects(reader)?,
+            "importedEnvTypes" => self.imported_env_types = read_objects(reader)?,
+            "envType" => self.env_type = Some(read_object(reader)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        count(&[
+            self.version.is_some(),
+            !self.object_types.is_empty(),
+            self.module_type.is_some(),
+            !self.enum_types.is_empty(),
+            !self.interface_types.is_empty(),
+            !self.imported_object_types.is_empty(),
+            !self.imported_module_types.is_empty(),
+            !self.imported_enum_types.is_empty(),
+            !self.imported_env_types.is_empty(),
+            self.env_type.is_some(),
+        ])
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        write_optional_string("version", &self.version, writer)?;
+        write_objects("objectTypes", &self.object_types, writer)?;
+        write_optional_object("moduleType", self.module_type.as_ref(), writer)?;
+        write_objects("enumTypes", &self.enum_types, writer)?;
+        write_objects("interfaceTypes", &self.interface_types, writer)?;
+        write_objects("importedObjectTypes", &self.imported_object_types, writer)?;
+        write_objects("importedModuleTypes", &self.imported_module_types, writer)?;
+        write_objects("importedEnumTypes", &self.imported_enum_types, writer)?;
+        write_objects("importedEnvTypes", &self.imported_env_types, writer)?;
+        write_optional_object("envType", self.env_type.as_ref(), writer)
+    }
+}
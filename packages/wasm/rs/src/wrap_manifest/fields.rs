@@ -0,0 +1,117 @@
+//! Shared (de)serialization of the manifest's msgpack maps.
+//!
+//! ABI definitions are composed the same way as in the JSON schema
+//! (`allOf`), e.g. an imported object is an object definition plus the
+//! imported fields. Each part reads and writes its own fields, and the
+//! helpers here put them together into a single map.
+
+use crate::{DecodeError, EncodeError, Read, Write};
+
+pub(crate) trait Fields: Default {
+    /// Read the value of `field` into `self`, or return `false` if
+    /// the field doesn't belong to this definition
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError>;
+
+    /// Number of fields `write_fields` will write
+    fn field_count(&self) -> u32;
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError>;
+
+    /// Fields which have to be present, as `(name, type)`
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        vec![]
+    }
+}
+
+pub(crate) fn read_object<T: Fields, R: Read>(reader: &mut R) -> Result<T, DecodeError> {
+    let mut num_of_fields = reader.read_map_length()?;
+    let mut object = T::default();
+    let mut found = vec![];
+
+    while num_of_fields > 0 {
+        num_of_fields -= 1;
+        let field = reader.read_string()?;
+
+        reader.context().push(&field, "unknown", "searching for property type");
+        if !object.read_field(&field, reader)? {
+            return Err(DecodeError::UnknownFieldName(field));
+        }
+        reader.context().pop();
+        found.push(field);
+    }
+
+    for (field, field_type) in T::required_fields() {
+        if !found.iter().any(|f| f == field) {
+            return Err(DecodeError::MissingField(format!("{}: {}.", field, field_type)));
+        }
+    }
+    Ok(object)
+}
+
+pub(crate) fn read_objects<T: Fields, R: Read>(reader: &mut R) -> Result<Vec<T>, DecodeError> {
+    reader.read_array(|reader| read_object(reader))
+}
+
+pub(crate) fn write_object<T: Fields, W: Write>(object: &T, writer: &mut W) -> Result<(), EncodeError> {
+    writer.write_map_length(&object.field_count())?;
+    object.write_fields(writer)
+}
+
+pub(crate) fn write_objects<T: Fields, W: Write>(
+    field: &str,
+    objects: &[T],
+    writer: &mut W,
+) -> Result<(), EncodeError> {
+    if objects.is_empty() {
+        return Ok(());
+    }
+    writer.write_string(field)?;
+    writer.write_array_iter(objects, |writer, object| write_object(object, writer))
+}
+
+pub(crate) fn write_optional_object<T: Fields, W: Write>(
+    field: &str,
+    object: Option<&T>,
+    writer: &mut W,
+) -> Result<(), EncodeError> {
+    match object {
+        Some(object) => {
+            writer.write_string(field)?;
+            write_object(object, writer)
+        }
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn write_optional_string<W: Write>(
+    field: &str,
+    value: &Option<String>,
+    writer: &mut W,
+) -> Result<(), EncodeError> {
+    match value {
+        Some(value) => {
+            writer.write_string(field)?;
+            writer.write_string(value)
+        }
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn write_optional_bool<W: Write>(
+    field: &str,
+    value: &Option<bool>,
+    writer: &mut W,
+) -> Result<(), EncodeError> {
+    match value {
+        Some(value) => {
+            writer.write_string(field)?;
+            writer.write_bool(value)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Count the optional fields which are set
+pub(crate) fn count(present: &[bool]) -> u32 {
+    present.iter().filter(|present| **present).count() as u32
+}
@@ -0,0 +1,178 @@
+//! The `wrap.info` manifest, describing a built wrapper and its ABI.
+//!
+//! The manifest is a msgpack map, encoded with the same `ReadDecoder` and
+//! `WriteEncoder` as invocation arguments. [`deserialize_wrap_manifest`]
+//! and [`serialize_wrap_manifest`] also validate the manifest, the same
+//! way `@polywrap/wrap-manifest-types-js` does.
+
+pub mod abi;
+mod fields;
+
+pub use abi::*;
+
+use fields::{read_object, write_object, Fields};
+use crate::{Context, DecodeError, EncodeError, Read, ReadDecoder, Write, WriteEncoder};
+use thiserror::Error;
+
+/// Manifest versions this crate can read
+pub const WRAP_MANIFEST_VERSIONS: &[&str] = &["0.1.0", "0.1"];
+pub const LATEST_WRAP_MANIFEST_VERSION: &str = "0.1";
+pub const LATEST_WRAP_ABI_VERSION: &str = "0.1";
+
+/// Errors from reading or validating a manifest
+#[derive(Debug, Error)]
+pub enum WrapManifestError {
+    #[error("Unable to parse WrapManifest: {0}")]
+    DecodeError(#[from] DecodeError),
+
+    #[error("Unable to serialize WrapManifest: {0}")]
+    EncodeError(#[from] EncodeError),
+
+    #[error("Unrecognized WrapManifest schema version \"{0}\"")]
+    UnsupportedVersion(String),
+
+    #[error("Unrecognized ABI version \"{0}\"")]
+    UnsupportedAbiVersion(String),
+
+    #[error("Invalid wrapper name \"{0}\", it may only contain letters, digits, '-' and '_'")]
+    InvalidName(String),
+}
+
+/// Wrapper package type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WrapType {
+    #[default]
+    Wasm,
+    Interface,
+    Plugin,
+}
+
+impl WrapType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WrapType::Wasm => "wasm",
+            WrapType::Interface => "interface",
+            WrapType::Plugin => "plugin",
+        }
+    }
+}
+
+impl std::str::FromStr for WrapType {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<WrapType, DecodeError> {
+        match s {
+            "wasm" => Ok(WrapType::Wasm),
+            "interface" => Ok(WrapType::Interface),
+            "plugin" => Ok(WrapType::Plugin),
+            _ => Err(DecodeError::EnumReadError(format!(
+                "Invalid value for enum 'WrapType': {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WrapManifest {
+    pub version: String,
+    pub _type: WrapType,
+    pub name: String,
+    pub abi: WrapAbi,
+}
+
+impl WrapManifest {
+    pub fn to_buffer(args: &WrapManifest) -> Result<Vec<u8>, EncodeError> {
+        let mut encoder_context = Context::new();
+        encoder_context.description = "Serializing (encoding) WrapManifest".to_string();
+        let mut encoder = WriteEncoder::new(&[], encoder_context);
+        WrapManifest::write(args, &mut encoder)?;
+        Ok(encoder.get_buffer())
+    }
+
+    pub fn from_buffer(args: &[u8]) -> Result<WrapManifest, DecodeError> {
+        let mut context = Context::new();
+        context.description = "Deserializing WrapManifest".to_string();
+        let mut reader = ReadDecoder::new(args, context);
+        WrapManifest::read(&mut reader)
+    }
+
+    pub fn write<W: Write>(args: &WrapManifest, writer: &mut W) -> Result<(), EncodeError> {
+        write_object(args, writer)
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<WrapManifest, DecodeError> {
+        read_object(reader)
+    }
+
+    /// Check the manifest's version, ABI version and name
+    pub fn validate(&self) -> Result<(), WrapManifestError> {
+        if !WRAP_MANIFEST_VERSIONS.contains(&self.version.as_str()) {
+            return Err(WrapManifestError::UnsupportedVersion(self.version.clone()));
+        }
+        if let Some(version) = &self.abi.version {
+            if version != LATEST_WRAP_ABI_VERSION {
+                return Err(WrapManifestError::UnsupportedAbiVersion(version.clone()));
+            }
+        }
+        let valid_name = !self.name.is_empty()
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_name {
+            return Err(WrapManifestError::InvalidName(self.name.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl Fields for WrapManifest {
+    fn read_field<R: Read>(&mut self, field: &str, reader: &mut R) -> Result<bool, DecodeError> {
+        match field {
+            "version" => self.version = reader.read_string()?,
+            "type" => self._type = reader.read_string()?.parse()?,
+            "name" => self.name = reader.read_string()?,
+            "abi" => self.abi = WrapAbi::read(reader)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn field_count(&self) -> u32 {
+        4
+    }
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_string("version")?;
+        writer.write_string(&self.version)?;
+        writer.write_string("type")?;
+        writer.write_string(self._type.as_str())?;
+        writer.write_string("name")?;
+        writer.write_string(&self.name)?;
+        writer.write_string("abi")?;
+        WrapAbi::write(&self.abi, writer)
+    }
+
+    fn required_fields() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("version", "String"),
+            ("type", "String"),
+            ("name", "String"),
+            ("abi", "Abi"),
+        ]
+    }
+}
+
+/// Decode and validate a `wrap.info` manifest
+pub fn deserialize_wrap_manifest(manifest: &[u8]) -> Result<WrapManifest, WrapManifestError> {
+    let manifest = WrapManifest::from_buffer(manifest)?;
+    manifest.validate()?;
+    Ok(manifest)
+}
+
+/// Validate and encode a `wrap.info` manifest
+pub fn serialize_wrap_manifest(manifest: &WrapManifest) -> Result<Vec<u8>, WrapManifestError> {
+    manifest.validate()?;
+    Ok(WrapManifest::to_buffer(manifest)?)
+}
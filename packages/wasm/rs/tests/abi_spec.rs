@@ -0,0 +1,32 @@
+use polywrap_wasm_rs::{ArgumentInfo, EnvRequirement, MethodInfo};
+
+const METHODS: &[MethodInfo] = &[
+    MethodInfo {
+        name: "moduleMethod",
+        arguments: &[
+            ArgumentInfo {
+                name: "str",
+                type_name: "String!",
+            },
+            ArgumentInfo {
+                name: "enumArray",
+                type_name: "[CustomEnum!]!",
+            },
+        ],
+        return_type: "Int!",
+        env: EnvRequirement::None,
+    },
+    MethodInfo {
+        name: "objectMethod",
+        arguments: &[],
+        return_type: "AnotherType",
+        env: EnvRequirement::Required,
+    },
+];
+
+#[test]
+fn finds_methods_by_name() {
+    let method = MethodInfo::find(METHODS, "objectMethod").unwrap();
+    assert_eq!(method.env, EnvRequirement::Required);
+    assert!(MethodInfo::find(METHODS, "unknown").is_none());
+}
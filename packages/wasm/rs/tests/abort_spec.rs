@@ -0,0 +1,145 @@
+use polywrap_wasm_rs::{AbortReport, Context, DecodeError, Read, ReadDecoder};
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to resolve config")]
+struct ConfigError {
+    #[source]
+    source: std::io::Error,
+}
+
+#[test]
+fn collects_error_source_chain() {
+    let error = ConfigError {
+        source: std::io::Error::new(std::io::ErrorKind::NotFound, "config.json not found"),
+    };
+    let report = AbortReport::from_error(&error).with_code(404);
+
+    assert_eq!(report.message, "failed to resolve config");
+    assert_eq!(report.source, vec!["config.json not found".to_string()]);
+    assert_eq!(report.code, Some(404));
+}
+
+#[test]
+fn captures_context_stack() {
+    let mut context = Context::with_description("Deserializing module-type: moduleMethod");
+    context.push("map", "Map<String, i32>", "type found, reading argument");
+    context.push("map[", "0", "]");
+
+    let report = AbortReport::new("integer overflow").with_context(&context);
+    assert_eq!(
+        report.context_description,
+        Some("Deserializing module-type: moduleMethod".to_string())
+    );
+    assert_eq!(
+        report.context,
+        vec![
+            "at map[ : 0 >> ]".to_string(),
+            "at map : Map<String, i32> >> type found, reading argument".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn records_context_of_last_decode_error() {
+    Context::take_error_context();
+    let mut reader = ReadDecoder::new(&[204, 255], Context::with_description("decoding"));
+    reader.context().push("prop", "i8", "type found, reading property");
+    assert!(reader.read_i8().is_err());
+
+    let context = Context::take_error_context().unwrap();
+    assert_eq!(context.description, "decoding");
+    assert_eq!(context.stack(), vec!["at prop : i8 >> type found, reading property"]);
+    assert!(Context::take_error_context().is_none());
+}
+
+#[test]
+fn formats_without_recording_context() {
+    Context::take_error_context();
+    let context = Context::with_description("decoding");
+    context.print_with_context("Invalid length");
+    assert!(Context::take_error_context().is_none());
+}
+
+#[test]
+fn describes_reports() {
+    let mut context = Context::with_description("Deserializing module-type: moduleMethod");
+    context.push("str", "String", "type found, reading argument");
+    let report = AbortReport::new("failed to resolve config")
+        .with_method("moduleMethod")
+        .with_code(404)
+        .with_context(&context);
+    let report = AbortReport {
+        source: vec!["config.json not found".to_string()],
+        ..report
+    };
+
+    assert_eq!(
+        report.describe(),
+        "failed to resolve config\nMethod: moduleMethod\nCode: 404\nCaused by: config.json not found\nContext: Deserializing module-type: moduleMethod\n  at str : String >> type found, reading argument"
+    );
+    assert_eq!(AbortReport::new("failed").describe(), "failed");
+}
+
+/// Deserializes `{ value: Int! }` like the generated args deserializers
+fn deserialize_args(args: &[u8]) -> Result<i32, DecodeError> {
+    let context = Context::with_description("Deserializing module-type: double");
+    let mut reader = ReadDecoder::new(args, context);
+    let mut num_of_fields = reader.read_map_length()?;
+    let mut value = None;
+
+    while num_of_fields > 0 {
+        num_of_fields -= 1;
+        let field = reader.read_string()?;
+        match field.as_str() {
+            "value" => {
+                reader.context().push(&field, "i32", "type found, reading argument");
+                value = Some(reader.read_i32()?);
+                reader.context().pop();
+            }
+            err => {
+                reader.context().record_error();
+                return Err(DecodeError::UnknownFieldName(err.to_string()));
+            }
+        }
+    }
+    match value {
+        Some(value) => Ok(value),
+        None => {
+            reader.context().push("value", "i32", "missing required argument");
+            reader.context().record_error();
+            Err(DecodeError::MissingField("value: Int.".to_string()))
+        }
+    }
+}
+
+#[test]
+fn reports_the_context_of_missing_fields() {
+    Context::take_error_context();
+    let error = deserialize_args(&[0x80]).unwrap_err();
+    let report = AbortReport::from_error(&error).complete();
+
+    assert_eq!(report.message, "Missing required field: 'value: Int.'");
+    assert_eq!(
+        report.context_description.as_deref(),
+        Some("Deserializing module-type: double")
+    );
+    assert_eq!(report.context, vec!["at value : i32 >> missing required argument"]);
+    assert!(Context::take_error_context().is_none());
+}
+
+#[test]
+fn round_trips_msgpack() {
+    let report = AbortReport {
+        message: "Missing required field: 'str: String.'".to_string(),
+        source: vec!["inner".to_string()],
+        context_description: Some("Deserializing module-type: moduleMethod".to_string()),
+        context: vec!["at str : String >> type found, reading argument".to_string()],
+        method: Some("moduleMethod".to_string()),
+        code: None,
+        file: Some("src/wrap/module/wrapped.rs".to_string()),
+        line: 42,
+        column: 13,
+    };
+    let buf = AbortReport::to_buffer(&report).unwrap();
+    assert_eq!(AbortReport::from_buffer(&buf).unwrap(), report);
+}
@@ -0,0 +1,86 @@
+use polywrap_wasm_rs::malloc::InvocationArena;
+use std::alloc::{GlobalAlloc, Layout, System};
+
+#[test]
+fn serves_allocations_from_chunks_while_active() {
+    let arena = InvocationArena::new(System);
+    let layout = Layout::from_size_align(24, 8).unwrap();
+
+    unsafe {
+        let outside = arena.alloc(layout);
+        assert!(!arena.contains(outside));
+
+        arena.begin();
+        let a = arena.alloc(layout);
+        let b = arena.alloc(layout);
+        assert!(arena.contains(a) && arena.contains(b));
+        assert_eq!(a as usize % 8, 0);
+        assert_eq!(b as usize - a as usize, 24);
+
+        // freeing arena memory is a no-op, freeing backing memory is not
+        arena.dealloc(a, layout);
+        arena.dealloc(outside, layout);
+        arena.reset();
+        assert!(!arena.is_active());
+    }
+}
+
+#[test]
+fn reuses_first_chunk_after_reset() {
+    let arena = InvocationArena::new(System);
+    let small = Layout::from_size_align(16, 16).unwrap();
+    let large = Layout::from_size_align(256 * 1024, 8).unwrap();
+
+    unsafe {
+        arena.begin();
+        let first = arena.alloc(small);
+        let big = arena.alloc(large);
+        assert!(arena.contains(big));
+        arena.reset();
+        assert!(!arena.contains(big));
+
+        arena.begin();
+        assert_eq!(arena.alloc(small), first);
+        arena.reset();
+    }
+}
+
+#[test]
+fn keeps_backing_memory_in_backing_allocator() {
+    let arena = InvocationArena::new(System);
+    let layout = Layout::from_size_align(8, 8).unwrap();
+
+    unsafe {
+        let long_lived = arena.alloc(layout);
+        long_lived.write(42);
+
+        arena.begin();
+        let grown = arena.realloc(long_lived, layout, 64);
+        assert!(!arena.contains(grown));
+        assert_eq!(*grown, 42);
+
+        let persisted = arena.suspend(|| arena.alloc(layout));
+        assert!(!arena.contains(persisted));
+        assert!(arena.is_active());
+        arena.reset();
+
+        arena.dealloc(grown, Layout::from_size_align(64, 8).unwrap());
+        arena.dealloc(persisted, layout);
+    }
+}
+
+#[test]
+fn moves_grown_arena_memory_within_arena() {
+    let arena = InvocationArena::new(System);
+    let layout = Layout::from_size_align(4, 4).unwrap();
+
+    unsafe {
+        arena.begin();
+        let ptr = arena.alloc(layout);
+        ptr.copy_from_nonoverlapping([1u8, 2, 3, 4].as_ptr(), 4);
+        let grown = arena.realloc(ptr, layout, 128);
+        assert!(arena.contains(grown));
+        assert_eq!(std::slice::from_raw_parts(grown, 4), [1, 2, 3, 4]);
+        arena.reset();
+    }
+}
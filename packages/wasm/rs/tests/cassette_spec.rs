@@ -0,0 +1,116 @@
+use polywrap_wasm_rs::{
+    wrap_eject_cassette, wrap_get_implementations, wrap_invocation_context, wrap_invoke,
+    wrap_load_env, wrap_record, wrap_replay, wrap_subinvoke, Cassette, Interaction, InvokeArgs, Uri,
+};
+
+mod support;
+
+fn cassette() -> Cassette {
+    Cassette {
+        interactions: vec![
+            Interaction::Subinvoke {
+                uri: "wrap://ens/a.eth".to_string(),
+                method: "method".to_string(),
+                args: vec![1],
+                env: None,
+                result: Ok(vec![2]),
+            },
+            Interaction::Subinvoke {
+                uri: "wrap://ens/a.eth".to_string(),
+                method: "method".to_string(),
+                args: vec![3],
+                env: None,
+                result: Err("failed".to_string()),
+            },
+            Interaction::LoadEnv { env: vec![0x80] },
+            Interaction::GetImplementations {
+                uri: "wrap://ens/interface.eth".to_string(),
+                implementations: vec!["wrap://ens/b.eth".to_string()],
+            },
+            Interaction::Caller {
+                caller: Some("wrap://ens/caller.eth".to_string()),
+            },
+        ],
+    }
+}
+
+fn asks_for_its_caller(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    let caller = wrap_invocation_context().unwrap().caller();
+    assert_eq!(caller, Some(Uri::new("ens/caller.eth").unwrap()));
+    vec![]
+}
+
+#[test]
+fn saves_and_loads_cassettes() {
+    let mut cassette = cassette();
+    cassette.interactions.push(Interaction::Subinvoke {
+        uri: "wrap://ens/a.eth".to_string(),
+        method: "method".to_string(),
+        args: vec![],
+        env: Some(vec![0x90]),
+        result: Ok(vec![]),
+    });
+    let path = std::env::temp_dir().join(format!("cassette-{}.wrap", std::process::id()));
+    cassette.save(&path).unwrap();
+    let loaded = Cassette::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), cassette);
+}
+
+#[test]
+fn replays_without_the_host() {
+    wrap_replay(cassette());
+
+    // Interactions are matched by their request, not their order
+    assert_eq!(wrap_get_implementations("ens/interface.eth").unwrap(), vec!["wrap://ens/b.eth"]);
+    assert_eq!(wrap_subinvoke("ens/a.eth", "method", vec![3]), Err("failed".to_string()));
+    assert_eq!(wrap_load_env(1), vec![0x80]);
+    let args = InvokeArgs {
+        method: "method".to_string(),
+        args: vec![],
+    };
+    assert!(wrap_invoke(args, 0, Some(asks_for_its_caller)));
+
+    // What wasn't replayed stays on the cassette
+    let rest = wrap_eject_cassette().unwrap();
+    assert_eq!(rest.interactions, cassette().interactions[..1]);
+    for import in [
+        "__wrap_getImplementations",
+        "__wrap_subinvoke",
+        "__wrap_load_env",
+        "__wrap_invoke_caller_len",
+    ] {
+        assert_eq!(support::calls(import), 0, "{}", import);
+    }
+}
+
+#[test]
+#[should_panic(expected = "No recorded subinvoke of \"other\" on wrap://ens/a.eth")]
+fn panics_at_requests_it_has_not_recorded() {
+    wrap_replay(cassette());
+    let _ = wrap_subinvoke("ens/a.eth", "other", vec![1]);
+}
+
+#[test]
+fn records_host_interactions() {
+    wrap_record();
+    assert_eq!(wrap_subinvoke("ens/a.eth", "method", vec![1]), Ok(vec![]));
+    assert_eq!(wrap_get_implementations("ens/interface.eth").unwrap(), Vec::<String>::new());
+    assert_eq!(
+        wrap_eject_cassette().unwrap().interactions,
+        vec![
+            Interaction::Subinvoke {
+                uri: "wrap://ens/a.eth".to_string(),
+                method: "method".to_string(),
+                args: vec![1],
+                env: None,
+                result: Ok(vec![]),
+            },
+            Interaction::GetImplementations {
+                uri: "wrap://ens/interface.eth".to_string(),
+                implementations: vec![],
+            },
+        ]
+    );
+    assert_eq!(wrap_eject_cassette(), None);
+}
@@ -0,0 +1,48 @@
+use polywrap_wasm_rs::{
+    wrap_env, wrap_invoke, DecodeError, EncodeError, InvokeArgs, Read, WrapEnv, Write,
+};
+use std::cell::RefCell;
+
+mod support;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Env {
+    prop: String,
+}
+
+impl WrapEnv for Env {
+    fn read<R: Read>(reader: &mut R) -> Result<Env, DecodeError> {
+        Ok(Env {
+            prop: reader.read_string()?,
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_string(&self.prop)
+    }
+}
+
+thread_local! {
+    static SEEN: RefCell<Option<Option<Env>>> = const { RefCell::new(None) };
+}
+
+fn method(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    SEEN.with(|seen| *seen.borrow_mut() = Some(wrap_env::<Env>().unwrap()));
+    vec![]
+}
+
+#[test]
+fn has_no_env_outside_of_invocations() {
+    assert_eq!(wrap_env::<Env>().unwrap(), None);
+}
+
+#[test]
+fn has_no_env_when_the_client_provided_none() {
+    let args = InvokeArgs {
+        method: "method".to_string(),
+        args: vec![],
+    };
+    assert!(wrap_invoke(args, 0, Some(method)));
+    assert_eq!(SEEN.with(|seen| seen.borrow_mut().take()), Some(None));
+    assert_eq!(support::calls("__wrap_load_env"), 0);
+}
@@ -0,0 +1,16 @@
+use polywrap_wasm_rs::wrap_get_implementations;
+
+mod support;
+
+#[test]
+fn returns_no_implementations() {
+    assert_eq!(wrap_get_implementations("ens/interface.eth").unwrap(), Vec::<String>::new());
+    assert_eq!(support::calls("__wrap_getImplementations"), 1);
+}
+
+#[test]
+fn fails_on_undecodable_implementations() {
+    support::find_implementations(true);
+    assert!(wrap_get_implementations("ens/interface.eth").is_err());
+    assert_eq!(support::calls("__wrap_getImplementations_result"), 1);
+}
@@ -0,0 +1,35 @@
+use polywrap_wasm_rs::{HostBuffer, HostBufferError};
+
+#[test]
+fn hands_out_a_zeroed_region_of_the_requested_length() {
+    let mut requested = None;
+    let buffer = HostBuffer::fill(4, |ptr| requested = Some(ptr));
+
+    assert!(requested.is_some());
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.as_bytes(), &[0, 0, 0, 0]);
+}
+
+#[test]
+fn still_calls_the_host_for_empty_buffers() {
+    let mut called = false;
+    let buffer = HostBuffer::fill(0, |_| called = true);
+
+    assert!(called);
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.clone().into_bytes(), Vec::<u8>::new());
+    assert_eq!(buffer.into_string("method").unwrap(), "");
+}
+
+#[test]
+fn validates_utf8() {
+    let valid = HostBuffer::from("getData".as_bytes().to_vec());
+    assert_eq!(valid.into_string("method").unwrap(), "getData");
+
+    let invalid = HostBuffer::from(vec![0x67, 0xff, 0xfe]);
+    let error = invalid.into_string("invoke method").unwrap_err();
+    assert!(matches!(error, HostBufferError::InvalidUtf8 { ref name, .. } if name == "invoke method"));
+    assert!(error
+        .to_string()
+        .starts_with("Host wrote invalid UTF-8 for invoke method"));
+}
@@ -0,0 +1,131 @@
+use polywrap_wasm_rs::{
+    wrap_eject_cassette, wrap_env, wrap_intercept, wrap_invoke, wrap_replay, Cassette, DecodeError,
+    EncodeError, Interaction, Interceptor, Invocation, InvokeArgs, Next, Read, WrapEnv, Write,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod support;
+
+/// Records the invocations it sees
+struct Recorder(Rc<RefCell<Vec<String>>>);
+
+impl Interceptor<()> for Recorder {
+    fn intercept(&self, invocation: &Invocation<'_, ()>, next: Next<'_, ()>) -> Vec<u8> {
+        self.0
+            .borrow_mut()
+            .push(format!("{} {:?}", invocation.method, invocation.args));
+        let result = next.run(invocation);
+        self.0.borrow_mut().push(format!("result {:?}", result));
+        result
+    }
+}
+
+/// Doubles the args on the way in and appends to the result on the way out
+struct Rewriter;
+
+impl Interceptor<()> for Rewriter {
+    fn intercept(&self, invocation: &Invocation<'_, ()>, next: Next<'_, ()>) -> Vec<u8> {
+        let args: Vec<u8> = invocation.args.iter().map(|arg| arg * 2).collect();
+        let mut result = next.run(&Invocation {
+            args: &args,
+            ..*invocation
+        });
+        result.push(0);
+        result
+    }
+}
+
+/// Answers without running the method
+struct ShortCircuit;
+
+impl Interceptor<()> for ShortCircuit {
+    fn intercept(&self, _invocation: &Invocation<'_, ()>, _next: Next<'_, ()>) -> Vec<u8> {
+        vec![42]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Env {
+    user: String,
+}
+
+impl WrapEnv for Env {
+    fn read<R: Read>(reader: &mut R) -> Result<Env, DecodeError> {
+        Ok(Env {
+            user: reader.read_string()?,
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_string(&self.user)
+    }
+}
+
+/// Answers with the user of the env, refusing invocations without one
+struct Authorize;
+
+impl Interceptor<Env> for Authorize {
+    fn intercept(&self, invocation: &Invocation<'_, Env>, next: Next<'_, Env>) -> Vec<u8> {
+        match invocation.env {
+            Some(env) => {
+                let mut result = env.user.as_bytes().to_vec();
+                result.extend(next.run(invocation));
+                result
+            }
+            None => panic!("Unauthorized"),
+        }
+    }
+}
+
+fn authorized_method(args: &[u8], _env_size: u32) -> Vec<u8> {
+    let interceptors: Vec<Box<dyn Interceptor<Env>>> = vec![Box::new(Authorize)];
+    wrap_intercept(&interceptors, "method", args, |_| {
+        wrap_env::<Env>().unwrap().unwrap().user.into_bytes()
+    })
+}
+
+fn invoke(interceptors: &[Box<dyn Interceptor<()>>], args: &[u8]) -> Vec<u8> {
+    wrap_intercept(interceptors, "method", args, |args| args.to_vec())
+}
+
+#[test]
+fn runs_methods_without_interceptors() {
+    assert_eq!(invoke(&[], &[1, 2]), vec![1, 2]);
+    // These invocations have no env to load
+    assert_eq!(support::calls("__wrap_load_env"), 0);
+}
+
+#[test]
+fn runs_interceptors_in_order() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let interceptors: Vec<Box<dyn Interceptor<()>>> =
+        vec![Box::new(Recorder(log.clone())), Box::new(Rewriter)];
+
+    assert_eq!(invoke(&interceptors, &[1, 2]), vec![2, 4, 0]);
+    assert_eq!(*log.borrow(), vec!["method [1, 2]", "result [2, 4, 0]"]);
+}
+
+#[test]
+fn interceptors_can_skip_the_method() {
+    let interceptors: Vec<Box<dyn Interceptor<()>>> = vec![Box::new(ShortCircuit), Box::new(Rewriter)];
+    assert_eq!(invoke(&interceptors, &[1, 2]), vec![42]);
+}
+
+#[test]
+fn shares_the_env_with_the_method() {
+    // "ab", msgpack encoded
+    let env = vec![0xa2, b'a', b'b'];
+    wrap_replay(Cassette {
+        interactions: vec![Interaction::LoadEnv { env: env.clone() }],
+    });
+
+    let args = InvokeArgs {
+        method: "method".to_string(),
+        args: vec![],
+    };
+    assert!(wrap_invoke(args, env.len() as u32, Some(authorized_method)));
+    // The env was loaded once, for the interceptor and the method
+    assert_eq!(wrap_eject_cassette(), Some(Cassette::default()));
+    assert_eq!(support::calls("__wrap_load_env"), 0);
+}
@@ -0,0 +1,34 @@
+use polywrap_wasm_rs::{wrap_invocation_context, wrap_invoke, InvocationContext, InvokeArgs};
+use std::cell::RefCell;
+
+mod support;
+
+thread_local! {
+    static SEEN: RefCell<Option<InvocationContext>> = const { RefCell::new(None) };
+}
+
+fn method(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    SEEN.with(|seen| *seen.borrow_mut() = wrap_invocation_context());
+    vec![]
+}
+
+#[test]
+fn exposes_the_current_invocation() {
+    assert_eq!(wrap_invocation_context(), None);
+
+    let args = InvokeArgs {
+        method: "method".to_string(),
+        args: vec![0x81, 0xa1, 0x61, 0x01],
+    };
+    assert!(wrap_invoke(args, 0, Some(method)));
+
+    let context = SEEN.with(|seen| seen.borrow_mut().take()).unwrap();
+    assert_eq!(context.method(), "method");
+    assert_eq!(context.args_size(), 4);
+    assert!(!context.has_env());
+    assert_eq!(context.env_buffer(), None);
+    assert_eq!(support::calls("__wrap_load_env"), 0);
+
+    // Nothing outlives the invocation
+    assert_eq!(wrap_invocation_context(), None);
+}
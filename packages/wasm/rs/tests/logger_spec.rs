@@ -0,0 +1,112 @@
+#![cfg(feature = "logger")]
+
+use log::{Level, LevelFilter};
+use polywrap_wasm_rs::{
+    format_record, serialize_log_args, wrap_eject_cassette, wrap_logger_setup, wrap_replay,
+    Cassette, Context, Interaction, LogLevel, Read, ReadDecoder, LOGGER_INTERFACE_URI,
+};
+use std::sync::Once;
+
+mod support;
+
+/// Install the logger once for the whole test binary, at `Info`
+fn setup() {
+    static SETUP: Once = Once::new();
+    SETUP.call_once(|| wrap_logger_setup(LevelFilter::Info).unwrap());
+}
+
+/// A logger implementation at `wrap://ens/logger.eth`, answering `log`
+/// for `message` at `level` with `logged`
+fn logger_implementation(level: LogLevel, message: &str, logged: bool) -> Cassette {
+    Cassette {
+        interactions: vec![
+            Interaction::GetImplementations {
+                uri: LOGGER_INTERFACE_URI.to_string(),
+                implementations: vec!["wrap://ens/logger.eth".to_string()],
+            },
+            Interaction::Subinvoke {
+                uri: "wrap://ens/logger.eth".to_string(),
+                method: "log".to_string(),
+                args: serialize_log_args(level, message).unwrap(),
+                env: None,
+                result: Ok(vec![if logged { 0xc3 } else { 0xc2 }]),
+            },
+        ],
+    }
+}
+
+#[test]
+fn maps_log_levels() {
+    assert_eq!(LogLevel::from(Level::Trace), LogLevel::DEBUG);
+    assert_eq!(LogLevel::from(Level::Debug), LogLevel::DEBUG);
+    assert_eq!(LogLevel::from(Level::Info), LogLevel::INFO);
+    assert_eq!(LogLevel::from(Level::Warn), LogLevel::WARN);
+    assert_eq!(LogLevel::from(Level::Error), LogLevel::ERROR);
+}
+
+#[test]
+fn formats_target_message_and_fields() {
+    let fields = [("uri", "wrap://ens/foo.eth"), ("attempt", "2")];
+    let record = log::Record::builder()
+        .level(Level::Info)
+        .target("my_wrapper::module")
+        .args(format_args!("subinvoke failed"))
+        .key_values(&fields)
+        .build();
+
+    assert_eq!(
+        format_record(&record),
+        "my_wrapper::module: subinvoke failed uri=wrap://ens/foo.eth attempt=2"
+    );
+}
+
+#[test]
+fn serializes_log_args() {
+    let buf = serialize_log_args(LogLevel::WARN, "hello").unwrap();
+    let mut reader = ReadDecoder::new(&buf, Context::new());
+    assert_eq!(reader.read_map_length().unwrap(), 2);
+    assert_eq!(reader.read_string().unwrap(), "level");
+    assert_eq!(reader.read_i32().unwrap(), 2);
+    assert_eq!(reader.read_string().unwrap(), "message");
+    assert_eq!(reader.read_string().unwrap(), "hello");
+}
+
+#[test]
+fn forwards_records_to_logger_implementations() {
+    setup();
+    wrap_replay(logger_implementation(LogLevel::WARN, "logger_spec: disk almost full", true));
+    log::warn!(target: "logger_spec", "disk almost full");
+
+    // The subinvoke is only replayed for the args it was recorded with
+    assert_eq!(wrap_eject_cassette().unwrap().interactions, vec![]);
+    assert_eq!(support::calls("__wrap_debug_log"), 0);
+}
+
+#[test]
+fn falls_back_to_debug_log_without_implementations() {
+    setup();
+    log::error!(target: "logger_spec", "no logger");
+
+    assert_eq!(support::calls("__wrap_getImplementations"), 1);
+    assert_eq!(support::calls("__wrap_subinvoke"), 0);
+    assert_eq!(support::calls("__wrap_debug_log"), 1);
+}
+
+#[test]
+fn falls_back_to_debug_log_when_implementations_decline() {
+    setup();
+    wrap_replay(logger_implementation(LogLevel::INFO, "logger_spec: declined", false));
+    log::info!(target: "logger_spec", "declined");
+
+    assert_eq!(wrap_eject_cassette().unwrap().interactions, vec![]);
+    assert_eq!(support::calls("__wrap_debug_log"), 1);
+}
+
+#[test]
+fn skips_records_below_the_max_level() {
+    setup();
+    log::debug!(target: "logger_spec", "filtered");
+
+    assert_eq!(support::calls("__wrap_getImplementations"), 0);
+    assert_eq!(support::calls("__wrap_debug_log"), 0);
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "arena")]
+
+use polywrap_wasm_rs::wrap_subinvoke_memoized;
+
+mod support;
+#[path = "support/arena.rs"]
+mod arena;
+
+fn memoizes(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    // Takes the arena memory the last invocation's memos were in
+    let filler = vec![0xaa_u8; 4096];
+    wrap_subinvoke_memoized("wrap://ens/a.eth", "method", vec![1]).unwrap();
+    wrap_subinvoke_memoized("wrap://ens/b.eth", "method", vec![2]).unwrap();
+    wrap_subinvoke_memoized("wrap://ens/a.eth", "method", vec![1]).unwrap();
+    assert!(filler.iter().all(|byte| *byte == 0xaa));
+    vec![]
+}
+
+#[test]
+fn memoizes_in_consecutive_invocations() {
+    support::invoke(memoizes);
+    support::invoke(memoizes);
+    assert_eq!(support::calls("__wrap_subinvoke"), 4);
+}
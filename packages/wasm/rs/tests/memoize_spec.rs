@@ -0,0 +1,41 @@
+use polywrap_wasm_rs::wrap_subinvoke_memoized;
+
+mod support;
+
+fn subinvokes() -> usize {
+    support::calls("__wrap_subinvoke")
+}
+
+fn calls_twice(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    wrap_subinvoke_memoized("wrap://ens/a.eth", "method", vec![1]).unwrap();
+    wrap_subinvoke_memoized("wrap://ens/a.eth", "method", vec![1]).unwrap();
+    // Other args, methods or URIs aren't memoized with the first call
+    wrap_subinvoke_memoized("wrap://ens/a.eth", "method", vec![2]).unwrap();
+    wrap_subinvoke_memoized("wrap://ens/a.eth", "other", vec![1]).unwrap();
+    wrap_subinvoke_memoized("wrap://ens/b.eth", "method", vec![1]).unwrap();
+    vec![]
+}
+
+fn fails_then_succeeds(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    support::fail_subinvokes(true);
+    assert!(wrap_subinvoke_memoized("wrap://ens/a.eth", "method", vec![]).is_err());
+    support::fail_subinvokes(false);
+    assert!(wrap_subinvoke_memoized("wrap://ens/a.eth", "method", vec![]).is_ok());
+    vec![]
+}
+
+#[test]
+fn memoizes_within_an_invocation() {
+    support::invoke(calls_twice);
+    assert_eq!(subinvokes(), 4);
+
+    // Results don't outlive their invocation
+    support::invoke(calls_twice);
+    assert_eq!(subinvokes(), 8);
+}
+
+#[test]
+fn does_not_memoize_errors() {
+    support::invoke(fails_then_succeeds);
+    assert_eq!(subinvokes(), 2);
+}
@@ -393,7 +393,7 @@ fn convert_from_buffer(sanity: &mut Sanity, buffer: &[u8]) -> Result<Sanity, Dec
     let mut context = Context::new();
     context.description = "Deserialize sanity (from buffer)...".to_string();
     let mut decoder = ReadDecoder::new(buffer, context);
-    Ok(deserialize_sanity(&mut decoder, sanity)?)
+    deserialize_sanity(&mut decoder, sanity)
 }
 
 fn from_buffer_with_invalid_types(
@@ -403,14 +403,14 @@ fn from_buffer_with_invalid_types(
     let mut context = Context::new();
     context.description = "Deserialize sanity (from buffer with invalid types)...".to_string();
     let mut decoder = ReadDecoder::new(buffer, context);
-    Ok(deserialize_with_invalid_types(&mut decoder, sanity)?)
+    deserialize_with_invalid_types(&mut decoder, sanity)
 }
 
 fn from_buffer_with_overflows(sanity: &mut Sanity, buffer: &[u8]) -> Result<Sanity, DecodeError> {
     let mut context = Context::new();
     context.description = "Deserialize sanity (from buffer with overflows)...".to_string();
     let mut decoder = ReadDecoder::new(buffer, context);
-    Ok(deserialize_with_overflow(&mut decoder, sanity)?)
+    deserialize_with_overflow(&mut decoder, sanity)
 }
 
 #[test]
@@ -0,0 +1,27 @@
+#![cfg(feature = "arena")]
+
+use polywrap_wasm_rs::{wrap_redirect, wrap_resolve_redirect, Uri};
+
+mod support;
+#[path = "support/arena.rs"]
+mod arena;
+
+fn redirects(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    wrap_redirect("ens/wrapper.eth", format!("ens/{}.wrapper.eth", "staging")).unwrap();
+    vec![]
+}
+
+fn resolves(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    // Takes the arena memory the redirect was added in
+    let filler = vec![0xaa_u8; 4096];
+    let uri = wrap_resolve_redirect(Uri::new("ens/wrapper.eth").unwrap());
+    assert_eq!(uri.uri(), "wrap://ens/staging.wrapper.eth");
+    assert!(filler.iter().all(|byte| *byte == 0xaa));
+    vec![]
+}
+
+#[test]
+fn keeps_redirects_across_invocations() {
+    support::invoke(redirects);
+    support::invoke(resolves);
+}
@@ -0,0 +1,31 @@
+use polywrap_wasm_rs::{
+    wrap_clear_redirects, wrap_redirect, wrap_remove_redirect, wrap_resolve_redirect, Uri,
+};
+
+fn resolve(uri: &str) -> String {
+    wrap_resolve_redirect(Uri::new(uri).unwrap()).to_string()
+}
+
+#[test]
+fn redirects_uris() {
+    wrap_redirect("ens/wrapper.eth", "wrap://ens/staging.wrapper.eth").unwrap();
+    wrap_redirect("ens/staging.wrapper.eth", "fs/wrapper").unwrap();
+
+    // Shorthands resolve the same, and redirects aren't transitive
+    assert_eq!(resolve("wrap://ens/wrapper.eth"), "wrap://ens/staging.wrapper.eth");
+    assert_eq!(resolve("ens/staging.wrapper.eth"), "wrap://fs/wrapper");
+    assert_eq!(resolve("ens/other.eth"), "wrap://ens/other.eth");
+
+    let from = Uri::new("ens/wrapper.eth").unwrap();
+    assert_eq!(wrap_remove_redirect(&from), Some(Uri::new("ens/staging.wrapper.eth").unwrap()));
+    assert_eq!(resolve("ens/wrapper.eth"), "wrap://ens/wrapper.eth");
+
+    wrap_clear_redirects();
+    assert_eq!(resolve("ens/staging.wrapper.eth"), "wrap://ens/staging.wrapper.eth");
+}
+
+#[test]
+fn rejects_malformed_redirects() {
+    let error = wrap_redirect("ens/wrapper.eth", "wrap://").unwrap_err();
+    assert!(error.starts_with("URI is malformed"), "{}", error);
+}
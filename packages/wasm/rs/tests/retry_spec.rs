@@ -0,0 +1,66 @@
+use polywrap_wasm_rs::Retry;
+
+mod support;
+
+fn logs() -> usize {
+    support::calls("__wrap_debug_log")
+}
+
+/// Fails the first `failures` attempts, recording the URIs attempted
+fn flaky(failures: usize, attempts: &mut Vec<String>) -> impl FnMut(&str) -> Result<u32, String> + '_ {
+    move |uri| {
+        attempts.push(uri.to_string());
+        if attempts.len() > failures {
+            Ok(attempts.len() as u32)
+        } else {
+            Err(format!("error {}", attempts.len()))
+        }
+    }
+}
+
+#[test]
+fn retries_until_success() {
+    let mut attempts = vec![];
+    assert_eq!(Retry::times(2).run("wrap://a", "method", flaky(2, &mut attempts)), Ok(3));
+    assert_eq!(attempts, vec!["wrap://a"; 3]);
+    // Each failed attempt is logged
+    assert_eq!(logs(), 2);
+}
+
+#[test]
+fn falls_back_to_other_uris() {
+    let retry = Retry::times(1).fallback("wrap://b").fallback("wrap://c");
+
+    let mut attempts = vec![];
+    assert_eq!(retry.run("wrap://a", "method", flaky(3, &mut attempts)), Ok(4));
+    assert_eq!(attempts, vec!["wrap://a", "wrap://a", "wrap://b", "wrap://b"]);
+
+    // Once every URI has failed, the last error is returned
+    let mut attempts = vec![];
+    assert_eq!(retry.run("wrap://a", "method", flaky(6, &mut attempts)), Err("error 6".to_string()));
+    assert_eq!(logs(), 3 + 6);
+}
+
+#[test]
+fn stops_at_errors_it_does_not_retry() {
+    let retry = Retry::times(3)
+        .fallback("wrap://b")
+        .retry_if(|error| error != "error 2");
+
+    let mut attempts = vec![];
+    assert_eq!(retry.run("wrap://a", "method", flaky(5, &mut attempts)), Err("error 2".to_string()));
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(logs(), 2);
+}
+
+#[test]
+fn subinvokes_until_success() {
+    support::fail_subinvokes(true);
+    let retry = Retry::times(1).fallback("wrap://ens/b.eth");
+    assert!(retry.subinvoke("wrap://ens/a.eth", "method", vec![]).is_err());
+    assert_eq!(support::calls("__wrap_subinvoke"), 4);
+
+    support::fail_subinvokes(false);
+    assert_eq!(retry.subinvoke("wrap://ens/a.eth", "method", vec![]), Ok(vec![]));
+    assert_eq!(support::calls("__wrap_subinvoke"), 5);
+}
@@ -0,0 +1,89 @@
+use polywrap_wasm_rs::{
+    deserialize_subinvoke_requests, deserialize_subinvoke_results, serialize_subinvoke_requests,
+    serialize_subinvoke_results, wrap_eject_cassette, wrap_record, wrap_replay,
+    wrap_subinvoke_batch, Cassette, Interaction, SubinvokeError, SubinvokeOptions,
+    SubinvokeRequest,
+};
+
+mod support;
+
+#[test]
+fn encodes_requests_and_results() {
+    let requests = vec![
+        SubinvokeRequest::new("wrap://ens/a.eth", "method", vec![0x80]),
+        SubinvokeRequest::new("wrap://ens/b.eth", "method", vec![]).with_options(SubinvokeOptions {
+            env: Some(vec![0x90]),
+        }),
+    ];
+    let buffer = serialize_subinvoke_requests(&requests).unwrap();
+    assert_eq!(deserialize_subinvoke_requests(&buffer).unwrap(), requests);
+
+    let results = vec![Ok(vec![1, 2]), Err("failed".to_string())];
+    let buffer = serialize_subinvoke_results(&results).unwrap();
+    assert_eq!(deserialize_subinvoke_results(&buffer).unwrap(), results);
+}
+
+#[test]
+fn skips_the_host_for_empty_batches() {
+    assert_eq!(wrap_subinvoke_batch(vec![]), vec![]);
+    assert_eq!(support::calls("__wrap_subinvoke_batch"), 0);
+}
+
+#[test]
+fn fails_malformed_uris_without_subinvoking() {
+    let results = wrap_subinvoke_batch(vec![SubinvokeRequest::new("", "method", vec![])]);
+    match results.as_slice() {
+        [Err(SubinvokeError { uri, method, .. })] => {
+            assert_eq!(uri, "");
+            assert_eq!(method, "method");
+        }
+        results => panic!("unexpected results {:?}", results),
+    }
+    assert_eq!(support::calls("__wrap_subinvoke_batch"), 0);
+}
+
+#[test]
+fn records_each_request() {
+    wrap_record();
+    let request = SubinvokeRequest::new("ens/a.eth", "method", vec![1]).with_options(SubinvokeOptions {
+        env: Some(vec![0x90]),
+    });
+    let results = wrap_subinvoke_batch(vec![request]);
+    assert_eq!(support::calls("__wrap_subinvoke_batch"), 1);
+
+    // The mock host can't answer, so the request is recorded with its error
+    let message = results[0].as_ref().unwrap_err().message.clone();
+    assert_eq!(
+        wrap_eject_cassette().unwrap().interactions,
+        vec![Interaction::Subinvoke {
+            uri: "wrap://ens/a.eth".to_string(),
+            method: "method".to_string(),
+            args: vec![1],
+            env: Some(vec![0x90]),
+            result: Err(message),
+        }]
+    );
+}
+
+#[test]
+fn replays_requests_without_the_host() {
+    let subinvoke = |args: Vec<u8>, result| Interaction::Subinvoke {
+        uri: "wrap://ens/a.eth".to_string(),
+        method: "method".to_string(),
+        args,
+        env: None,
+        result,
+    };
+    wrap_replay(Cassette {
+        interactions: vec![subinvoke(vec![2], Err("failed".to_string())), subinvoke(vec![1], Ok(vec![3]))],
+    });
+
+    let results = wrap_subinvoke_batch(vec![
+        SubinvokeRequest::new("ens/a.eth", "method", vec![1]),
+        SubinvokeRequest::new("ens/a.eth", "method", vec![2]),
+    ]);
+    assert_eq!(results[0], Ok(vec![3]));
+    assert_eq!(results[1].as_ref().unwrap_err().message, "failed");
+    assert_eq!(support::calls("__wrap_subinvoke_batch"), 0);
+    assert_eq!(wrap_eject_cassette(), Some(Cassette::default()));
+}
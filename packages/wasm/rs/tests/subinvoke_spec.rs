@@ -0,0 +1,80 @@
+use polywrap_wasm_rs::{
+    wrap_eject_cassette, wrap_record, wrap_replay, wrap_subinvoke_with_options, DecodeError,
+    EncodeError, Interaction, Read, SubinvokeOptions, WrapEnv, Write,
+};
+
+mod support;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Env {
+    prop: String,
+}
+
+impl WrapEnv for Env {
+    fn read<R: Read>(reader: &mut R) -> Result<Env, DecodeError> {
+        Ok(Env {
+            prop: reader.read_string()?,
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_string(&self.prop)
+    }
+}
+
+#[test]
+fn encodes_options() {
+    let options = SubinvokeOptions::with_env(&Env {
+        prop: "value".to_string(),
+    })
+    .unwrap();
+    assert_eq!(options.env, Some(vec![0xa5, b'v', b'a', b'l', b'u', b'e']));
+
+    let buffer = options.to_buffer().unwrap();
+    assert_eq!(SubinvokeOptions::from_buffer(&buffer).unwrap(), options);
+
+    let default = SubinvokeOptions::default();
+    assert_eq!(SubinvokeOptions::from_buffer(&default.to_buffer().unwrap()).unwrap(), default);
+}
+
+#[test]
+fn forwards_no_env_outside_of_invocations() {
+    assert_eq!(SubinvokeOptions::forward_env(), SubinvokeOptions::default());
+}
+
+#[test]
+fn rejects_invalid_uris_without_subinvoking() {
+    let result = wrap_subinvoke_with_options("", "method", vec![], &SubinvokeOptions::default());
+    assert!(result.is_err());
+    assert_eq!(support::calls("__wrap_subinvoke_with_options"), 0);
+}
+
+#[test]
+fn records_and_replays_the_env() {
+    let options = SubinvokeOptions {
+        env: Some(vec![0x90]),
+    };
+    wrap_record();
+    assert_eq!(wrap_subinvoke_with_options("ens/a.eth", "method", vec![1], &options), Ok(vec![]));
+    let cassette = wrap_eject_cassette().unwrap();
+    assert_eq!(
+        cassette.interactions,
+        vec![Interaction::Subinvoke {
+            uri: "wrap://ens/a.eth".to_string(),
+            method: "method".to_string(),
+            args: vec![1],
+            env: Some(vec![0x90]),
+            result: Ok(vec![]),
+        }]
+    );
+
+    // Replays don't reach the host, and only match subinvokes with the same env
+    wrap_replay(cassette.clone());
+    let other_env = std::panic::catch_unwind(|| {
+        wrap_subinvoke_with_options("ens/a.eth", "method", vec![1], &SubinvokeOptions::default())
+    });
+    assert!(other_env.is_err());
+    wrap_replay(cassette);
+    assert_eq!(wrap_subinvoke_with_options("ens/a.eth", "method", vec![1], &options), Ok(vec![]));
+    assert_eq!(support::calls("__wrap_subinvoke_with_options"), 1);
+}
@@ -0,0 +1,25 @@
+//! Serves the allocations of a test binary from the runtime's invocation
+//! arena, as the `arena` feature does in wrappers. The arena isn't thread
+//! safe, so binaries using it keep to a single test.
+
+use polywrap_wasm_rs::malloc::ALLOCATOR;
+use std::alloc::{GlobalAlloc, Layout};
+
+struct Arena;
+
+unsafe impl GlobalAlloc for Arena {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATOR.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATOR.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATOR.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Arena = Arena;
@@ -0,0 +1,218 @@
+//! A mock of the host, for tests linking the runtime natively.
+//!
+//! Natively there is no host to import from, and pointers don't fit in the
+//! `u32`s crossing the boundary, so the mock can't read what it's sent or
+//! write back into the wrapper's memory. It counts the calls to each import
+//! instead, and answers like an empty host: subinvokes succeed with an empty
+//! result, there are no implementations, no caller and no env.
+#![allow(dead_code)]
+
+use polywrap_wasm_rs::{malloc::begin_invocation, wrap_invoke, InvokeArgs};
+use std::cell::{Cell, RefCell};
+
+const IMPORTS: [&str; 21] = [
+    "__wrap_abort",
+    "__wrap_abort_report",
+    "__wrap_debug_log",
+    "__wrap_getImplementations",
+    "__wrap_getImplementations_result",
+    "__wrap_getImplementations_result_len",
+    "__wrap_invoke_args",
+    "__wrap_invoke_caller",
+    "__wrap_invoke_caller_len",
+    "__wrap_invoke_error",
+    "__wrap_invoke_result",
+    "__wrap_load_env",
+    "__wrap_subinvoke",
+    "__wrap_subinvoke_batch",
+    "__wrap_subinvoke_batch_result",
+    "__wrap_subinvoke_batch_result_len",
+    "__wrap_subinvoke_error",
+    "__wrap_subinvoke_error_len",
+    "__wrap_subinvoke_result",
+    "__wrap_subinvoke_result_len",
+    "__wrap_subinvoke_with_options",
+];
+
+thread_local! {
+    // A fixed size, so counting never allocates inside an invocation arena
+    static CALLS: RefCell<[usize; IMPORTS.len()]> = const { RefCell::new([0; IMPORTS.len()]) };
+    static SUBINVOKES_FAIL: Cell<bool> = const { Cell::new(false) };
+    static IMPLEMENTATIONS_FOUND: Cell<bool> = const { Cell::new(false) };
+}
+
+fn index(import: &str) -> usize {
+    IMPORTS
+        .iter()
+        .position(|name| *name == import)
+        .unwrap_or_else(|| panic!("{} isn't a host import", import))
+}
+
+fn call(import: &str) {
+    CALLS.with(|calls| calls.borrow_mut()[index(import)] += 1);
+}
+
+/// Number of calls to `import` on this thread
+pub fn calls(import: &str) -> usize {
+    CALLS.with(|calls| calls.borrow()[index(import)])
+}
+
+/// Fail subinvokes with an empty error from now on, or succeed again
+pub fn fail_subinvokes(fail: bool) {
+    SUBINVOKES_FAIL.with(|subinvokes_fail| subinvokes_fail.set(fail));
+}
+
+/// Report implementations from now on, or none again. The mock can't
+/// write their list, so it fails to decode.
+pub fn find_implementations(found: bool) {
+    IMPLEMENTATIONS_FOUND.with(|implementations_found| implementations_found.set(found));
+}
+
+/// Run `method` as a whole invocation, without args or env
+pub fn invoke(method: fn(&[u8], u32) -> Vec<u8>) {
+    let args = InvokeArgs {
+        method: "method".to_string(),
+        args: vec![],
+    };
+    // Wrappers begin the invocation when they fetch its args
+    begin_invocation();
+    assert!(wrap_invoke(args, 0, Some(method)));
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_abort(
+    _msg_ptr: u32,
+    _msg_len: u32,
+    _file_ptr: u32,
+    _file_len: u32,
+    _line: u32,
+    _column: u32,
+) {
+    call("__wrap_abort");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_abort_report(_report_ptr: u32, _report_len: u32) {
+    call("__wrap_abort_report");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_debug_log(_ptr: u32, _len: u32) {
+    call("__wrap_debug_log");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_getImplementations(_uri_ptr: u32, _uri_len: u32) -> bool {
+    call("__wrap_getImplementations");
+    IMPLEMENTATIONS_FOUND.with(Cell::get)
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_getImplementations_result_len() -> u32 {
+    call("__wrap_getImplementations_result_len");
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_getImplementations_result(_ptr: u32) {
+    call("__wrap_getImplementations_result");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_invoke_args(_method_ptr: u32, _args_ptr: u32) {
+    call("__wrap_invoke_args");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_invoke_caller_len() -> u32 {
+    call("__wrap_invoke_caller_len");
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_invoke_caller(_ptr: u32) {
+    call("__wrap_invoke_caller");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_invoke_result(_ptr: u32, _len: u32) {
+    call("__wrap_invoke_result");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_invoke_error(_ptr: u32, _len: u32) {
+    call("__wrap_invoke_error");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_load_env(_environment_ptr: u32) {
+    call("__wrap_load_env");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke(
+    _uri_ptr: u32,
+    _uri_len: u32,
+    _method_ptr: u32,
+    _method_len: u32,
+    _args_ptr: u32,
+    _args_len: u32,
+) -> bool {
+    call("__wrap_subinvoke");
+    !SUBINVOKES_FAIL.with(Cell::get)
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn __wrap_subinvoke_with_options(
+    _uri_ptr: u32,
+    _uri_len: u32,
+    _method_ptr: u32,
+    _method_len: u32,
+    _args_ptr: u32,
+    _args_len: u32,
+    _options_ptr: u32,
+    _options_len: u32,
+) -> bool {
+    call("__wrap_subinvoke_with_options");
+    !SUBINVOKES_FAIL.with(Cell::get)
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke_result_len() -> u32 {
+    call("__wrap_subinvoke_result_len");
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke_result(_ptr: u32) {
+    call("__wrap_subinvoke_result");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke_error_len() -> u32 {
+    call("__wrap_subinvoke_error_len");
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke_error(_ptr: u32) {
+    call("__wrap_subinvoke_error");
+}
+
+// The mock can't write a list of results, so batches fail to decode
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke_batch(_requests_ptr: u32, _requests_len: u32) {
+    call("__wrap_subinvoke_batch");
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke_batch_result_len() -> u32 {
+    call("__wrap_subinvoke_batch_result_len");
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn __wrap_subinvoke_batch_result(_ptr: u32) {
+    call("__wrap_subinvoke_batch_result");
+}
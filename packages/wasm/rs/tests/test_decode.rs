@@ -1,3 +1,4 @@
+use polywrap_wasm_rs::msgpack::Format;
 use polywrap_wasm_rs::{BigInt, Context, Read, ReadDecoder, JSON};
 use serde::{Deserialize, Serialize};
 
@@ -127,6 +128,13 @@ fn test_read_u64() {
     assert_eq!(u64::MAX, reader.read_u64().unwrap());
 }
 
+#[test]
+fn test_peek_format() {
+    let mut reader = ReadDecoder::new(&[206, 255, 255, 255, 255], Context::new());
+    assert_eq!(Format::Uint32, reader.peek_format().unwrap());
+    assert_eq!(u32::MAX, reader.read_u32().unwrap());
+}
+
 // #[test]
 // fn test_read_i64() {
 //     let mut reader = ReadDecoder::new(&[207, 128, 0, 0, 0, 0, 0, 0, 0], Context::new());
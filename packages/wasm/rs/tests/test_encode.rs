@@ -2,6 +2,7 @@ use polywrap_wasm_rs::{BigInt, BigNumber, Context, Write, WriteEncoder, JSON};
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+#[allow(dead_code)]
 #[derive(Default, Debug)]
 struct Case<T> {
     name: String,
@@ -216,7 +217,7 @@ fn test_write_f32() {
 fn test_write_f64() {
     let cases = [Case::new(
         "64-bit float",
-        3.141592653589793,
+        std::f64::consts::PI,
         &[203, 64, 9, 33, 251, 84, 68, 45, 24],
     )];
 
@@ -270,7 +271,7 @@ fn test_write_string() {
 
     for case in cases {
         let mut writer = WriteEncoder::new(&[], Context::new());
-        writer.write_string(&case.input).unwrap();
+        writer.write_string(case.input).unwrap();
         assert_eq!(case.want, writer.get_buffer().as_slice());
     }
 }
@@ -378,11 +379,50 @@ fn test_write_array() {
 
     for case in cases {
         let mut writer = WriteEncoder::new(&[], Context::new());
-        writer.write_array(&case.input, |writer, item| writer.write_i32(item));
+        writer.write_array(&case.input, |writer, item| writer.write_i32(item)).unwrap();
         assert_eq!(case.want, writer.get_buffer().as_slice());
     }
 }
 
+#[test]
+fn test_write_array_iter() {
+    struct NotClone(i32);
+
+    let items = [NotClone(1), NotClone(2), NotClone(545345)];
+    let mut writer = WriteEncoder::new(&[], Context::new());
+    writer
+        .write_array_iter(items.iter(), |writer, item| writer.write_i32(&item.0))
+        .unwrap();
+    assert_eq!([147, 1, 2, 210, 0, 8, 82, 65], writer.get_buffer().as_slice());
+
+    let mut writer = WriteEncoder::new(&[], Context::new());
+    writer
+        .write_array_iter((1..37).map(|i| i * 2), |writer, item| writer.write_i32(&item))
+        .unwrap();
+    let mut want = vec![220, 0, 36];
+    want.extend((1..37).map(|i| (i * 2) as u8));
+    assert_eq!(want, writer.get_buffer());
+}
+
+#[test]
+fn test_write_map_iter() {
+    let mut writer = WriteEncoder::new(&[], Context::new());
+    writer
+        .write_map_iter(
+            ["Polywrap", "Rust"].into_iter().zip([vec![3, 5, 9], vec![1, 4, 7]]),
+            |writer, key| writer.write_string(key),
+            |writer, value| writer.write_array_iter(value, |writer, item| writer.write_i32(&item)),
+        )
+        .unwrap();
+    assert_eq!(
+        [
+            130, 168, 80, 111, 108, 121, 119, 114, 97, 112, 147, 3, 5, 9, 164, 82, 117, 115, 116,
+            147, 1, 4, 7,
+        ],
+        writer.get_buffer().as_slice()
+    );
+}
+
 #[test]
 fn test_write_map() {
     let mut map1: BTreeMap<String, Vec<i32>> = BTreeMap::new();
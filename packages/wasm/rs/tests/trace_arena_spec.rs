@@ -0,0 +1,48 @@
+#![cfg(all(feature = "arena", feature = "tracing"))]
+
+use polywrap_wasm_rs::{malloc::persist, WrapSubscriber, JSON};
+use std::cell::RefCell;
+use tracing::{dispatcher, Dispatch, Level};
+use tracing_core::LevelFilter;
+
+mod support;
+#[path = "support/arena.rs"]
+mod arena;
+
+thread_local! {
+    static RECORDS: RefCell<Vec<JSON::Value>> = const { RefCell::new(vec![]) };
+}
+
+fn collect(record: &str) {
+    persist(|| RECORDS.with(|records| records.borrow_mut().push(JSON::from_str(record).unwrap())));
+}
+
+fn traces(_args: &[u8], _env_size: u32) -> Vec<u8> {
+    // Takes the arena memory the last invocation's spans were in
+    let filler = vec![0xaa_u8; 4096];
+    for method in ["a", "b", "c"] {
+        let span = tracing::span!(Level::INFO, "invoke", method = method);
+        let _guard = span.enter();
+        tracing::info!("subinvoking");
+    }
+    assert!(filler.iter().all(|byte| *byte == 0xaa));
+    vec![]
+}
+
+#[test]
+fn keeps_spans_across_invocations() {
+    let subscriber = WrapSubscriber::with_writer(LevelFilter::INFO, collect);
+    dispatcher::with_default(&Dispatch::new(subscriber), || {
+        support::invoke(traces);
+        support::invoke(traces);
+    });
+
+    let records = RECORDS.with(|records| records.borrow_mut().split_off(0));
+    assert_eq!(records.len(), 18);
+    let methods: Vec<&JSON::Value> = records
+        .iter()
+        .filter(|record| record["kind"] == "enter")
+        .map(|record| &record["fields"]["method"])
+        .collect();
+    assert_eq!(methods, vec!["a", "b", "c", "a", "b", "c"]);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "tracing")]
+
+use polywrap_wasm_rs::{WrapSubscriber, JSON};
+use std::cell::RefCell;
+use tracing::{dispatcher, Dispatch, Level};
+use tracing_core::LevelFilter;
+
+thread_local! {
+    static RECORDS: RefCell<Vec<JSON::Value>> = const { RefCell::new(vec![]) };
+}
+
+fn collect(record: &str) {
+    RECORDS.with(|records| records.borrow_mut().push(JSON::from_str(record).unwrap()));
+}
+
+fn take_records() -> Vec<JSON::Value> {
+    RECORDS.with(|records| records.borrow_mut().drain(..).collect())
+}
+
+#[test]
+fn forwards_spans_and_events() {
+    let subscriber = WrapSubscriber::with_writer(LevelFilter::DEBUG, collect);
+    dispatcher::with_default(&Dispatch::new(subscriber), || {
+        let span = tracing::span!(Level::INFO, "invoke", method = "moduleMethod");
+        let _guard = span.enter();
+        tracing::debug!(attempt = 2, ok = false, "subinvoke failed");
+    });
+
+    let records = take_records();
+    assert_eq!(records.len(), 3);
+
+    assert_eq!(records[0]["kind"], "enter");
+    assert_eq!(records[0]["name"], "invoke");
+    assert_eq!(records[0]["level"], "INFO");
+    assert_eq!(records[0]["fields"]["method"], "moduleMethod");
+
+    assert_eq!(records[1]["kind"], "event");
+    assert_eq!(records[1]["level"], "DEBUG");
+    assert_eq!(records[1]["parent"], records[0]["id"]);
+    assert_eq!(records[1]["fields"]["message"], "subinvoke failed");
+    assert_eq!(records[1]["fields"]["attempt"], 2);
+    assert_eq!(records[1]["fields"]["ok"], false);
+
+    assert_eq!(records[2]["kind"], "exit");
+    assert_eq!(records[2]["id"], records[0]["id"]);
+}
+
+#[test]
+fn filters_by_level() {
+    let subscriber = WrapSubscriber::with_writer(LevelFilter::WARN, collect);
+    dispatcher::with_default(&Dispatch::new(subscriber), || {
+        tracing::info!("ignored");
+        tracing::warn!("kept");
+    });
+
+    let records = take_records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["fields"]["message"], "kept");
+}
@@ -0,0 +1,42 @@
+use polywrap_wasm_rs::{wrap_get_implementations, wrap_subinvoke, Uri, UriError};
+
+mod support;
+
+#[test]
+fn inserts_the_scheme() {
+    let uri = Uri::new("/authority-v2/path.to.thing.root/sub/path").unwrap();
+    assert_eq!(uri.uri(), "wrap://authority-v2/path.to.thing.root/sub/path");
+    assert_eq!(uri.authority(), "authority-v2");
+    assert_eq!(uri.path(), "path.to.thing.root/sub/path");
+
+    let shorthand: Uri = "ens/domain.eth".parse().unwrap();
+    assert_eq!(shorthand, Uri::new("wrap://ens/domain.eth").unwrap());
+    assert_eq!(shorthand.to_string(), "wrap://ens/domain.eth");
+}
+
+#[test]
+fn rejects_malformed_uris() {
+    assert_eq!(Uri::new(""), Err(UriError::Empty));
+    assert_eq!(Uri::new("path/wrap://something"), Err(UriError::SchemeNotAtStart));
+
+    // No authority, no path, invalid authorities
+    for uri in ["wrap://path", "wrap://authority/", "wrap://.....", "wrap://a/path", "wrap://Ens/path"] {
+        assert_eq!(Uri::new(uri), Err(UriError::Malformed(uri.to_string())), "{}", uri);
+    }
+    assert!(Uri::is_valid("wrap://valid/uri"));
+    assert!(!Uri::is_valid("wrap://....."));
+}
+
+#[test]
+fn subinvoke_rejects_malformed_uris() {
+    let error = wrap_subinvoke("wrap://path", "method", vec![]).unwrap_err();
+    assert!(error.starts_with("URI is malformed"), "{}", error);
+    assert_eq!(support::calls("__wrap_subinvoke"), 0);
+}
+
+#[test]
+fn get_implementations_rejects_malformed_uris() {
+    let error = wrap_get_implementations("wrap://path").unwrap_err();
+    assert!(error.starts_with("URI is malformed"), "{}", error);
+    assert_eq!(support::calls("__wrap_getImplementations"), 0);
+}
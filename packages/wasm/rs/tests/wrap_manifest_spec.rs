@@ -0,0 +1,150 @@
+#![cfg(feature = "manifest")]
+
+use polywrap_wasm_rs::wrap_manifest::{
+    deserialize_wrap_manifest, serialize_wrap_manifest, AnyDefinition, ArrayDefinition,
+    DefinitionKind, GenericDefinition, NestedDefinition, ObjectDefinition, PropertyDefinition,
+    WrapManifest, WrapManifestError, WrapType,
+};
+use polywrap_wasm_rs::DecodeError;
+
+const ENS_WRAP_INFO: &[u8] = include_bytes!("../../../js/test-env/src/wrappers/ens/wrap.info");
+const PLUGIN_WRAP_INFO: &[u8] = include_bytes!(
+    "../../../test-cases/cases/cli/plugin/codegen/001-sanity/expected/build-artifacts/wrap.info"
+);
+
+#[test]
+fn decodes_wasm_wrapper_manifest() {
+    let manifest = deserialize_wrap_manifest(ENS_WRAP_INFO).unwrap();
+    assert_eq!(manifest._type, WrapType::Wasm);
+
+    let module = manifest.abi.module_type.as_ref().unwrap();
+    assert!(module.generic.is_kind(DefinitionKind::MODULE));
+    assert!(!module.methods.is_empty());
+    for method in &module.methods {
+        assert!(method.generic.is_kind(DefinitionKind::METHOD));
+        assert!(method.generic.name.is_some());
+    }
+}
+
+#[test]
+fn decodes_plugin_manifest() {
+    let manifest = deserialize_wrap_manifest(PLUGIN_WRAP_INFO).unwrap();
+    assert_eq!(manifest._type, WrapType::Plugin);
+    assert!(!manifest.abi.imported_module_types.is_empty());
+}
+
+#[test]
+fn round_trips_manifests() {
+    for wrap_info in [ENS_WRAP_INFO, PLUGIN_WRAP_INFO] {
+        let manifest = deserialize_wrap_manifest(wrap_info).unwrap();
+        let buffer = serialize_wrap_manifest(&manifest).unwrap();
+        assert_eq!(deserialize_wrap_manifest(&buffer).unwrap(), manifest);
+    }
+}
+
+#[test]
+fn validates_versions_and_name() {
+    let manifest = WrapManifest {
+        version: "0.1".to_string(),
+        name: "test-wrapper".to_string(),
+        ..Default::default()
+    };
+    assert!(manifest.validate().is_ok());
+
+    let unknown_version = WrapManifest {
+        version: "0.2".to_string(),
+        ..manifest.clone()
+    };
+    assert!(matches!(
+        deserialize_wrap_manifest(&WrapManifest::to_buffer(&unknown_version).unwrap()),
+        Err(WrapManifestError::UnsupportedVersion(version)) if version == "0.2"
+    ));
+
+    let mut unknown_abi = manifest.clone();
+    unknown_abi.abi.version = Some("1.0".to_string());
+    assert!(matches!(
+        unknown_abi.validate(),
+        Err(WrapManifestError::UnsupportedAbiVersion(_))
+    ));
+
+    let invalid_name = WrapManifest {
+        name: "test wrapper".to_string(),
+        ..manifest
+    };
+    assert!(matches!(
+        serialize_wrap_manifest(&invalid_name),
+        Err(WrapManifestError::InvalidName(_))
+    ));
+}
+
+#[test]
+fn rejects_missing_fields() {
+    let manifest = WrapManifest::from_buffer(&[0x80]).unwrap_err();
+    assert!(matches!(manifest, DecodeError::MissingField(field) if field == "version: String."));
+}
+
+#[test]
+fn round_trips_nested_array_items() {
+    let string = NestedDefinition {
+        any: AnyDefinition {
+            generic: GenericDefinition {
+                kind: DefinitionKind::SCALAR,
+                _type: "String".to_string(),
+                required: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let inner = NestedDefinition {
+        any: AnyDefinition {
+            generic: GenericDefinition {
+                kind: DefinitionKind::ARRAY,
+                _type: "[String]".to_string(),
+                required: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        item: Some(Box::new(string)),
+        ..Default::default()
+    };
+    let matrix = ArrayDefinition {
+        any: AnyDefinition {
+            generic: GenericDefinition {
+                kind: DefinitionKind::ARRAY,
+                _type: "[[String]]".to_string(),
+                name: Some("matrix".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        item: Some(Box::new(inner)),
+    };
+
+    let mut manifest = WrapManifest {
+        version: "0.1".to_string(),
+        name: "matrix".to_string(),
+        ..Default::default()
+    };
+    manifest.abi.object_types.push(ObjectDefinition {
+        generic: GenericDefinition {
+            kind: DefinitionKind::OBJECT,
+            _type: "Matrix".to_string(),
+            ..Default::default()
+        },
+        properties: vec![PropertyDefinition {
+            any: AnyDefinition {
+                generic: matrix.any.generic.clone(),
+                array: Some(Box::new(matrix)),
+                ..Default::default()
+            },
+            comment: None,
+        }],
+        ..Default::default()
+    });
+
+    let buffer = serialize_wrap_manifest(&manifest).unwrap();
+    assert_eq!(deserialize_wrap_manifest(&buffer).unwrap(), manifest);
+}
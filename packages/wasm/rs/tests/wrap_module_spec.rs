@@ -0,0 +1,165 @@
+use polywrap_wasm_rs::{
+    wrap_eject_cassette, wrap_invoke, wrap_invoke_method, wrap_module, wrap_replay, AbortReport,
+    Cassette, Context, DecodeError, EncodeError, Interaction, InvokeArgs, Read, ReadDecoder,
+    WrapEnv, Write, WriteEncoder,
+};
+use std::cell::RefCell;
+
+mod support;
+
+pub struct ArgsDouble {
+    pub value: i32,
+}
+
+pub fn deserialize_double_args(args: &[u8]) -> Result<ArgsDouble, DecodeError> {
+    let mut reader = ReadDecoder::new(args, Context::new());
+    let mut num_of_fields = reader.read_map_length()?;
+    let mut value: Option<i32> = None;
+
+    while num_of_fields > 0 {
+        num_of_fields -= 1;
+        let field = reader.read_string()?;
+        match field.as_str() {
+            "value" => value = Some(reader.read_i32()?),
+            err => return Err(DecodeError::UnknownFieldName(err.to_string())),
+        }
+    }
+    match value {
+        Some(value) => Ok(ArgsDouble { value }),
+        None => Err(DecodeError::MissingField("value: Int.".to_string())),
+    }
+}
+
+pub fn serialize_double_result(result: &i32) -> Result<Vec<u8>, EncodeError> {
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_i32(result)?;
+    Ok(encoder.get_buffer())
+}
+
+#[derive(Clone)]
+pub struct Env {
+    pub factor: i32,
+}
+
+impl WrapEnv for Env {
+    fn read<R: Read>(reader: &mut R) -> Result<Env, DecodeError> {
+        Ok(Env {
+            factor: reader.read_i32()?,
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), EncodeError> {
+        writer.write_i32(&self.factor)
+    }
+}
+
+pub fn double(args: ArgsDouble) -> i32 {
+    args.value * 2
+}
+
+pub fn scale(args: ArgsDouble, env: Option<Env>) -> i32 {
+    args.value * env.map_or(1, |env| env.factor)
+}
+
+pub fn multiply(args: ArgsDouble, env: Env) -> i32 {
+    args.value * env.factor
+}
+
+wrap_module! {
+    "double" => double {
+        args: deserialize_double_args,
+        result: serialize_double_result,
+    },
+    "scale" => scale {
+        args: deserialize_double_args,
+        result: serialize_double_result,
+        optional_env: Env,
+    },
+    "multiply" => multiply {
+        args: deserialize_double_args,
+        result: serialize_double_result,
+        env: Env,
+    },
+}
+
+thread_local! {
+    static RESULT: RefCell<Vec<u8>> = const { RefCell::new(vec![]) };
+}
+
+/// Invoke `method` with `env`, which the host sends through a cassette
+fn invoke_with_env(method: &str, value: i32, env: i32) -> i32 {
+    fn keep_result(args: &[u8], env_size: u32) -> Vec<u8> {
+        let method = wrap_invoke_method().unwrap();
+        let result = wrap_module_method(&method).unwrap()(args, env_size);
+        RESULT.with(|kept| *kept.borrow_mut() = result.clone());
+        result
+    }
+
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_i32(&env).unwrap();
+    let env = encoder.get_buffer();
+    wrap_replay(Cassette {
+        interactions: vec![Interaction::LoadEnv { env: env.clone() }],
+    });
+
+    let args = InvokeArgs {
+        method: method.to_string(),
+        args: encode_args(value),
+    };
+    assert!(wrap_invoke(args, env.len() as u32, Some(keep_result)));
+    assert_eq!(wrap_eject_cassette(), Some(Cassette::default()));
+    decode_result(&RESULT.with(|kept| kept.borrow_mut().split_off(0)))
+}
+
+fn encode_args(value: i32) -> Vec<u8> {
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_map_length(&1).unwrap();
+    encoder.write_string("value").unwrap();
+    encoder.write_i32(&value).unwrap();
+    encoder.get_buffer()
+}
+
+fn decode_result(result: &[u8]) -> i32 {
+    ReadDecoder::new(result, Context::new()).read_i32().unwrap()
+}
+
+#[test]
+fn dispatches_by_method_name() {
+    let double = wrap_module_method("double").unwrap();
+    assert_eq!(decode_result(&double(&encode_args(21), 0)), 42);
+
+    let scale = wrap_module_method("scale").unwrap();
+    assert_eq!(decode_result(&scale(&encode_args(21), 0)), 21);
+    // `scale` only loads the env when one was provided
+    assert_eq!(support::calls("__wrap_load_env"), 0);
+
+    assert!(wrap_module_method("unknown").is_none());
+}
+
+#[test]
+fn passes_the_env_of_the_invocation() {
+    assert_eq!(invoke_with_env("scale", 21, 3), 63);
+    assert_eq!(invoke_with_env("multiply", 21, 2), 42);
+    assert_eq!(support::calls("__wrap_load_env"), 0);
+}
+
+#[test]
+fn panics_without_a_required_env() {
+    let multiply = wrap_module_method("multiply").unwrap();
+    let panic = std::panic::catch_unwind(|| multiply(&encode_args(21), 0)).unwrap_err();
+    assert_eq!(
+        panic.downcast_ref::<String>().unwrap(),
+        "Environment is not set, and it is required by method 'multiply'"
+    );
+}
+
+#[test]
+fn panics_with_abort_report_on_invalid_args() {
+    let double = wrap_module_method("double").unwrap();
+    let mut encoder = WriteEncoder::new(&[], Context::new());
+    encoder.write_map_length(&0).unwrap();
+
+    let panic = std::panic::catch_unwind(|| double(&encoder.get_buffer(), 0)).unwrap_err();
+    let report = panic.downcast_ref::<AbortReport>().unwrap();
+    assert_eq!(report.message, "Missing required field: 'value: Int.'");
+}